@@ -0,0 +1,58 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Feeds arbitrary bytes through identifier creation and decoding.
+//!
+//! Identifiers reach the chain as SCALE-encoded call arguments, so both the
+//! decoded form and the type prefix parsing must reject bad input without
+//! panicking. Identifiers created from valid input must report the type they
+//! were created with.
+
+#![no_main]
+
+use codec::Decode;
+use identifier::{CordIdentifierType, IdentifierCreator, IdentifierType, Ss58Identifier};
+use libfuzzer_sys::fuzz_target;
+
+const TYPES: [IdentifierType; 13] = [
+	IdentifierType::Authorization,
+	IdentifierType::Space,
+	IdentifierType::Schema,
+	IdentifierType::Statement,
+	IdentifierType::Entity,
+	IdentifierType::Template,
+	IdentifierType::Asset,
+	IdentifierType::AssetInstance,
+	IdentifierType::Rating,
+	IdentifierType::Registries,
+	IdentifierType::Entries,
+	IdentifierType::RegistryAuthorization,
+	IdentifierType::SchemaAccounts,
+];
+
+fuzz_target!(|data: &[u8]| {
+	if let Ok(identifier) = Ss58Identifier::decode(&mut &data[..]) {
+		let _ = identifier.get_type();
+	}
+
+	let Some((selector, payload)) = data.split_first() else { return };
+	let id_type = TYPES[*selector as usize % TYPES.len()].clone();
+	if let Ok(identifier) = Ss58Identifier::create_identifier(payload, id_type.clone()) {
+		assert_eq!(identifier.get_type(), Ok(id_type));
+	}
+});
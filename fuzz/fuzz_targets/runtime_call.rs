@@ -0,0 +1,40 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Feeds arbitrary bytes into SCALE decoding of runtime calls and extrinsics.
+//!
+//! Decoding uses the same nesting limit as the transaction pool, and must
+//! fail cleanly on malformed input.
+
+#![no_main]
+
+use codec::{DecodeLimit, Encode};
+use cord_braid_runtime::{RuntimeCall, UncheckedExtrinsic};
+use libfuzzer_sys::fuzz_target;
+use sp_api::MAX_EXTRINSIC_DEPTH;
+
+fuzz_target!(|data: &[u8]| {
+	if let Ok(call) = RuntimeCall::decode_with_depth_limit(MAX_EXTRINSIC_DEPTH, &mut &data[..]) {
+		let encoded = call.encode();
+		let decoded = RuntimeCall::decode_with_depth_limit(MAX_EXTRINSIC_DEPTH, &mut &encoded[..])
+			.expect("re-encoded call decodes");
+		assert_eq!(decoded, call);
+	}
+
+	let _ = UncheckedExtrinsic::decode_with_depth_limit(MAX_EXTRINSIC_DEPTH, &mut &data[..]);
+});
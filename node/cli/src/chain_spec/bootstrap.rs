@@ -26,7 +26,7 @@ use cord_braid_runtime::SessionKeys as BraidSessionKeys;
 use cord_loom_runtime::SessionKeys as LoomSessionKeys;
 use cord_weave_runtime::SessionKeys as WeaveSessionKeys;
 
-pub use cord_primitives::{AccountId, Balance, NodeId, Signature};
+pub use cord_primitives::{AccountId, Balance, Hash, NodeId, Signature};
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use sc_consensus_grandpa::AuthorityId as GrandpaId;
 use sc_service::ChainType;
@@ -54,6 +54,8 @@ pub struct ChainParams {
 	pub council_members: Vec<String>,
 	pub tech_committee_members: Vec<String>,
 	pub sudo_key: String,
+	pub spaces: Vec<(String, String, u64)>,
+	pub schemas: Vec<(String, String, String)>,
 }
 
 impl ChainParams {
@@ -138,11 +140,38 @@ fn cord_braid_custom_config_genesis(config: ChainParams) -> serde_json::Value {
 			.collect();
 
 	let initial_sudo_key: AccountId = array_bytes::hex_n_into_unchecked(&config.sudo_key);
+
+	let initial_spaces: Vec<(Hash, AccountId, u64)> = config
+		.spaces
+		.iter()
+		.map(|(code, creator, capacity)| {
+			(
+				array_bytes::hex_n_into_unchecked::<_, Hash, 32>(code),
+				array_bytes::hex_n_into_unchecked(creator),
+				*capacity,
+			)
+		})
+		.collect();
+
+	let initial_schemas: Vec<(Hash, AccountId, Vec<u8>)> = config
+		.schemas
+		.iter()
+		.map(|(space_code, creator, schema)| {
+			(
+				array_bytes::hex_n_into_unchecked::<_, Hash, 32>(space_code),
+				array_bytes::hex_n_into_unchecked(creator),
+				schema.as_bytes().to_vec(),
+			)
+		})
+		.collect();
+
 	cord_braid_custom_genesis(
 		initial_network_members,
 		initial_well_known_nodes,
 		initial_authorities,
 		initial_sudo_key,
+		initial_spaces,
+		initial_schemas,
 	)
 }
 
@@ -175,12 +204,50 @@ fn cord_loom_custom_config_genesis(config: ChainParams) -> serde_json::Value {
 			})
 			.collect();
 
+	let initial_council_members: Vec<AccountId> =
+		config.council_members.iter().map(array_bytes::hex_n_into_unchecked).collect();
+
+	let initial_tech_committee_members: Vec<AccountId> = config
+		.tech_committee_members
+		.iter()
+		.map(array_bytes::hex_n_into_unchecked)
+		.collect();
+
 	let initial_sudo_key: AccountId = array_bytes::hex_n_into_unchecked(&config.sudo_key);
+
+	let initial_spaces: Vec<(Hash, AccountId, u64)> = config
+		.spaces
+		.iter()
+		.map(|(code, creator, capacity)| {
+			(
+				array_bytes::hex_n_into_unchecked::<_, Hash, 32>(code),
+				array_bytes::hex_n_into_unchecked(creator),
+				*capacity,
+			)
+		})
+		.collect();
+
+	let initial_schemas: Vec<(Hash, AccountId, Vec<u8>)> = config
+		.schemas
+		.iter()
+		.map(|(space_code, creator, schema)| {
+			(
+				array_bytes::hex_n_into_unchecked::<_, Hash, 32>(space_code),
+				array_bytes::hex_n_into_unchecked(creator),
+				schema.as_bytes().to_vec(),
+			)
+		})
+		.collect();
+
 	cord_loom_custom_genesis(
 		initial_network_members,
 		initial_well_known_nodes,
 		initial_authorities,
+		initial_council_members,
+		initial_tech_committee_members,
 		initial_sudo_key,
+		initial_spaces,
+		initial_schemas,
 	)
 }
 
@@ -213,12 +280,50 @@ fn cord_weave_custom_config_genesis(config: ChainParams) -> serde_json::Value {
 			})
 			.collect();
 
+	let initial_council_members: Vec<AccountId> =
+		config.council_members.iter().map(array_bytes::hex_n_into_unchecked).collect();
+
+	let initial_tech_committee_members: Vec<AccountId> = config
+		.tech_committee_members
+		.iter()
+		.map(array_bytes::hex_n_into_unchecked)
+		.collect();
+
 	let initial_sudo_key: AccountId = array_bytes::hex_n_into_unchecked(&config.sudo_key);
+
+	let initial_spaces: Vec<(Hash, AccountId, u64)> = config
+		.spaces
+		.iter()
+		.map(|(code, creator, capacity)| {
+			(
+				array_bytes::hex_n_into_unchecked::<_, Hash, 32>(code),
+				array_bytes::hex_n_into_unchecked(creator),
+				*capacity,
+			)
+		})
+		.collect();
+
+	let initial_schemas: Vec<(Hash, AccountId, Vec<u8>)> = config
+		.schemas
+		.iter()
+		.map(|(space_code, creator, schema)| {
+			(
+				array_bytes::hex_n_into_unchecked::<_, Hash, 32>(space_code),
+				array_bytes::hex_n_into_unchecked(creator),
+				schema.as_bytes().to_vec(),
+			)
+		})
+		.collect();
+
 	cord_weave_custom_genesis(
 		initial_network_members,
 		initial_well_known_nodes,
 		initial_authorities,
+		initial_council_members,
+		initial_tech_committee_members,
 		initial_sudo_key,
+		initial_spaces,
+		initial_schemas,
 	)
 }
 pub fn cord_custom_config(config: ChainParams) -> Result<CordChainSpec, String> {
@@ -291,6 +396,8 @@ fn cord_braid_custom_genesis(
 	initial_well_known_nodes: Vec<(NodeId, AccountId)>,
 	initial_authorities: Vec<(AccountId, BabeId, GrandpaId, ImOnlineId, AuthorityDiscoveryId)>,
 	root_key: AccountId,
+	initial_spaces: Vec<(Hash, AccountId, u64)>,
+	initial_schemas: Vec<(Hash, AccountId, Vec<u8>)>,
 ) -> serde_json::Value {
 	const ENDOWMENT: Balance = 10_000_000 * BRAID_UNITS;
 
@@ -332,6 +439,8 @@ fn cord_braid_custom_genesis(
 			"epochConfig": Some(cord_braid_runtime::BABE_GENESIS_EPOCH_CONFIG),
 		},
 		"sudo": { "key": Some(root_key) },
+		"chainSpace": { "spaces": initial_spaces },
+		"schema": { "schemas": initial_schemas },
 	})
 }
 
@@ -339,7 +448,11 @@ fn cord_loom_custom_genesis(
 	initial_network_members: Vec<AccountId>,
 	initial_well_known_nodes: Vec<(NodeId, AccountId)>,
 	initial_authorities: Vec<(AccountId, BabeId, GrandpaId, ImOnlineId, AuthorityDiscoveryId)>,
+	initial_council_members: Vec<AccountId>,
+	initial_tech_committee_members: Vec<AccountId>,
 	root_key: AccountId,
+	initial_spaces: Vec<(Hash, AccountId, u64)>,
+	initial_schemas: Vec<(Hash, AccountId, Vec<u8>)>,
 ) -> serde_json::Value {
 	const ENDOWMENT: Balance = 10_000_000 * LOOM_UNITS;
 
@@ -381,18 +494,14 @@ fn cord_loom_custom_genesis(
 			"epochConfig": Some(cord_loom_runtime::BABE_GENESIS_EPOCH_CONFIG),
 		},
 		"councilMembership":  {
-			"members": initial_authorities
-				.iter()
-				.map(|x| x.0.clone())
-				.collect::<Vec<_>>(),
+			"members": initial_council_members,
 		},
 		"technicalMembership":  {
-			"members": initial_authorities
-				.iter()
-				.map(|x| x.0.clone())
-				.collect::<Vec<_>>(),
+			"members": initial_tech_committee_members,
 		},
 		"sudo": { "key": Some(root_key) },
+		"chainSpace": { "spaces": initial_spaces },
+		"schema": { "schemas": initial_schemas },
 	})
 }
 
@@ -400,7 +509,11 @@ fn cord_weave_custom_genesis(
 	initial_network_members: Vec<AccountId>,
 	initial_well_known_nodes: Vec<(NodeId, AccountId)>,
 	initial_authorities: Vec<(AccountId, BabeId, GrandpaId, ImOnlineId, AuthorityDiscoveryId)>,
+	initial_council_members: Vec<AccountId>,
+	initial_tech_committee_members: Vec<AccountId>,
 	root_key: AccountId,
+	initial_spaces: Vec<(Hash, AccountId, u64)>,
+	initial_schemas: Vec<(Hash, AccountId, Vec<u8>)>,
 ) -> serde_json::Value {
 	const ENDOWMENT: Balance = 100_000_000_000 * WAY;
 
@@ -441,6 +554,14 @@ fn cord_weave_custom_genesis(
 		"babe":  {
 			"epochConfig": Some(cord_loom_runtime::BABE_GENESIS_EPOCH_CONFIG),
 		},
+		"councilMembership":  {
+			"members": initial_council_members,
+		},
+		"technicalMembership":  {
+			"members": initial_tech_committee_members,
+		},
 		"sudo": { "key": Some(root_key) },
+		"chainSpace": { "spaces": initial_spaces },
+		"schema": { "schemas": initial_schemas },
 	})
 }
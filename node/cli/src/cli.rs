@@ -23,7 +23,7 @@ use crate::command::gen_key::KeySubcommand;
 
 use clap::Parser;
 
-use crate::chain_setup::BootstrapChainCmd;
+use crate::{chain_setup::BootstrapChainCmd, light_spec::ExportLightSpecCmd};
 
 #[allow(missing_docs)]
 #[derive(Debug, Parser)]
@@ -64,6 +64,10 @@ pub enum Subcommand {
 	/// Export the state of a given block into a chain spec.
 	ExportState(sc_cli::ExportStateCmd),
 
+	/// Export a chain spec with a light client sync checkpoint of the
+	/// latest finalized block.
+	ExportLightSpec(ExportLightSpecCmd),
+
 	/// Import blocks.
 	ImportBlocks(sc_cli::ImportBlocksCmd),
 
@@ -40,6 +40,30 @@ pub struct ChainConfigParams {
 	pub council_members: Option<Vec<String>>,
 	pub tech_committee_members: Option<Vec<String>>,
 	pub sudo_key: Option<String>,
+	pub spaces: Option<Vec<SpaceConfigParams>>,
+	pub schemas: Option<Vec<SchemaConfigParams>>,
+}
+
+/// A chain space anchored and approved at genesis.
+#[derive(Debug, Deserialize)]
+pub struct SpaceConfigParams {
+	/// Hex encoded 32 byte space code.
+	pub code: String,
+	/// Hex encoded account of the space creator.
+	pub creator: String,
+	/// Transaction capacity approved for the space.
+	pub txn_capacity: u64,
+}
+
+/// A schema anchored at genesis in one of the genesis spaces.
+#[derive(Debug, Deserialize)]
+pub struct SchemaConfigParams {
+	/// Hex encoded 32 byte code of the genesis space.
+	pub space_code: String,
+	/// Hex encoded account of the space creator, who also creates the schema.
+	pub creator: String,
+	/// The schema document.
+	pub schema: String,
 }
 
 #[derive(Debug, Args)]
@@ -128,6 +152,20 @@ impl BootstrapChainCmd {
 				.expect("No authorities provided; cannot set sudo_key")
 		});
 
+		let initial_spaces: Vec<(String, String, u64)> = config
+			.spaces
+			.unwrap_or_default()
+			.into_iter()
+			.map(|space| (space.code, space.creator, space.txn_capacity))
+			.collect();
+
+		let initial_schemas: Vec<(String, String, String)> = config
+			.schemas
+			.unwrap_or_default()
+			.into_iter()
+			.map(|schema| (schema.space_code, schema.creator, schema.schema))
+			.collect();
+
 		let chain_params = ChainParams {
 			chain_name,
 			chain_type,
@@ -138,6 +176,8 @@ impl BootstrapChainCmd {
 			council_members: initial_council_members,
 			tech_committee_members: initial_tech_committee_members,
 			sudo_key: initial_sudo_key,
+			spaces: initial_spaces,
+			schemas: initial_schemas,
 		};
 
 		let chain_spec = match cord_custom_config(chain_params) {
@@ -0,0 +1,120 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Export of chain specs carrying a light client sync checkpoint.
+
+use cord_primitives::Block;
+use sc_cli::{
+	clap::{self, Parser},
+	CliConfiguration, DatabaseParams, Error, PruningParams, SharedParams,
+};
+use sc_service::{Configuration, PartialComponents};
+use sc_sync_state_rpc::{LightSyncState, LightSyncStateExtension};
+use sp_blockchain::HeaderBackend;
+use std::{fs, io::Write, path::PathBuf};
+
+use crate::service::new_partial;
+
+/// The `export-light-spec` command used to generate a chain spec for light
+/// clients.
+///
+/// The chain spec is extended with a checkpoint of the latest finalized
+/// block known to the local database: its header, the BABE epoch changes
+/// and the GRANDPA authority set. Light clients such as smoldot start
+/// syncing from this checkpoint instead of from genesis.
+#[derive(Debug, Clone, Parser)]
+pub struct ExportLightSpecCmd {
+	/// Export the genesis of the chain spec in raw storage format.
+	#[arg(long)]
+	pub raw: bool,
+
+	/// Write the chain spec to the given file instead of stdout.
+	#[arg(long, value_name = "PATH")]
+	pub output: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl ExportLightSpecCmd {
+	/// Run the command
+	pub fn run(&self, config: Configuration) -> Result<(), Error> {
+		let PartialComponents { client, other: (_, (_, grandpa_link, babe_link), _, _), .. } =
+			new_partial(&config)?;
+
+		let finalized_hash = client.info().finalized_hash;
+		let finalized_block_header = client.header(finalized_hash)?.ok_or_else(|| {
+			Error::Input(format!("Header of finalized block {finalized_hash:?} not found"))
+		})?;
+		let babe_finalized_block_weight =
+			sc_consensus_babe::aux_schema::load_block_weight(&*client, finalized_hash)?
+				.ok_or_else(|| {
+					Error::Input(format!(
+						"BABE weight of finalized block {finalized_hash:?} not found"
+					))
+				})?;
+
+		let sync_state = LightSyncState::<Block> {
+			finalized_block_header,
+			babe_epoch_changes: babe_link.epoch_changes().shared_data().clone(),
+			babe_finalized_block_weight,
+			grandpa_authority_set: grandpa_link.shared_authority_set().clone_inner(),
+		};
+
+		let mut chain_spec = config.chain_spec;
+		let extension = sc_chain_spec::get_extension_mut::<LightSyncStateExtension>(
+			chain_spec.extensions_mut(),
+		)
+		.ok_or_else(|| {
+			Error::Input("Chain spec does not support the light sync state extension".into())
+		})?;
+		*extension =
+			Some(serde_json::to_value(&sync_state).map_err(|e| Error::Input(e.to_string()))?);
+
+		let json = chain_spec.as_json(self.raw).map_err(Error::Input)?;
+		match &self.output {
+			Some(path) => fs::write(path, json)?,
+			None => std::io::stdout().write_all(json.as_bytes())?,
+		}
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ExportLightSpecCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
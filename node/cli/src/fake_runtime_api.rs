@@ -22,7 +22,7 @@
 //! the native runtimes.
 #![allow(missing_docs)]
 
-use cord_primitives::{AccountId, Balance, Block, Nonce};
+use cord_primitives::{AccountId, Balance, Block, BlockNumber, DidIdentifier, Hash, Nonce};
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 pub use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
@@ -213,6 +213,126 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_did_runtime_api::DidApi<
+		Block,
+		DidIdentifier,
+		AccountId,
+		Hash,
+		BlockNumber,
+	> for Runtime {
+		fn query_by_name(
+			_: Vec<u8>,
+		) -> Option<
+			pallet_did_runtime_api::RawDidLinkedInfo<DidIdentifier, AccountId, Hash, BlockNumber>,
+		> {
+			unimplemented!()
+		}
+		fn query(
+			_: DidIdentifier,
+		) -> Option<
+			pallet_did_runtime_api::RawDidLinkedInfo<DidIdentifier, AccountId, Hash, BlockNumber>,
+		> {
+			unimplemented!()
+		}
+	}
+
+	impl pallet_schema_runtime_api::SchemaApi<
+		Block,
+		pallet_schema_runtime_api::SchemaIdOf,
+		Hash,
+		pallet_schema_runtime_api::SchemaInfoOf,
+	> for Runtime {
+		fn schema(
+			_: pallet_schema_runtime_api::SchemaIdOf,
+		) -> Option<pallet_schema_runtime_api::SchemaInfoOf> {
+			unimplemented!()
+		}
+		fn schema_by_hash(_: Hash) -> Vec<pallet_schema_runtime_api::SchemaInfoOf> {
+			unimplemented!()
+		}
+	}
+
+	impl pallet_transaction_sponsor_runtime_api::TransactionSponsorApi<
+		Block,
+		AccountId,
+		Balance,
+		pallet_transaction_sponsor_runtime_api::SpaceIdOf,
+	> for Runtime {
+		fn estimate_fee(
+			_: <Block as BlockT>::Extrinsic,
+			_: u32,
+		) -> Option<
+			pallet_transaction_sponsor_runtime_api::FeeEstimate<
+				AccountId,
+				Balance,
+				pallet_transaction_sponsor_runtime_api::SpaceIdOf,
+			>,
+		> {
+			unimplemented!()
+		}
+	}
+
+	impl pallet_statement_runtime_api::StatementApi<
+		Block,
+		pallet_statement_runtime_api::StatementIdOf,
+		Hash,
+		pallet_statement_runtime_api::SpaceIdOf,
+		pallet_statement_runtime_api::StatementInfoOf,
+		pallet_statement_runtime_api::AnchorStateOf,
+		pallet_statement_runtime_api::ReceiptOf,
+	> for Runtime {
+		fn query(
+			_: pallet_statement_runtime_api::StatementIdOf,
+		) -> Option<pallet_statement_runtime_api::StatementInfoOf> {
+			unimplemented!()
+		}
+		fn query_by_digest(
+			_: Hash,
+			_: pallet_statement_runtime_api::SpaceIdOf,
+		) -> Option<pallet_statement_runtime_api::StatementInfoOf> {
+			unimplemented!()
+		}
+		fn status_at(_: pallet_statement_runtime_api::StatementIdOf, _: u32) -> Option<bool> {
+			unimplemented!()
+		}
+		fn verify_leaf(
+			_: pallet_statement_runtime_api::StatementIdOf,
+			_: Hash,
+			_: Vec<Hash>,
+		) -> bool {
+			unimplemented!()
+		}
+		fn anchor_state(
+			_: Hash,
+			_: pallet_statement_runtime_api::SpaceIdOf,
+		) -> Option<pallet_statement_runtime_api::AnchorStateOf> {
+			unimplemented!()
+		}
+		fn verify_batch(
+			_: Vec<Hash>,
+		) -> Vec<
+			Vec<(
+				pallet_statement_runtime_api::SpaceIdOf,
+				pallet_statement_runtime_api::AnchorStateOf,
+			)>,
+		> {
+			unimplemented!()
+		}
+		fn receipt(
+			_: pallet_statement_runtime_api::StatementIdOf,
+		) -> Option<pallet_statement_runtime_api::ReceiptOf> {
+			unimplemented!()
+		}
+		fn anchoring_stats(
+			_: Option<pallet_statement_runtime_api::SchemaIdOf>,
+		) -> pallet_statement_runtime_api::AnchoringStats {
+			unimplemented!()
+		}
+		fn period_stats(_: BlockNumber) -> Option<pallet_statement_runtime_api::AnchoringStats> {
+			unimplemented!()
+		}
+	}
+
 	impl crate::fake_runtime_api::GetLastTimestamp<Block> for Runtime {
 		fn get_last_timestamp() -> u64 {
 			unimplemented!()
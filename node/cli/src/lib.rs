@@ -23,6 +23,8 @@
 pub mod benchmarking;
 pub mod chain_spec;
 pub mod fake_runtime_api;
+#[cfg(feature = "full-node")]
+pub mod metrics;
 pub mod service;
 
 #[cfg(feature = "cli")]
@@ -0,0 +1,384 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Prometheus metrics and telemetry describing CORD pallet activity.
+//!
+//! The observer follows the best chain, decodes `System::Events` of every
+//! newly imported best block with the native runtime types and feeds the
+//! statement, schema and chain space events into a small set of counters and
+//! gauges. Blocks which are later retracted by a re-org are not subtracted
+//! again, so the counters describe activity seen by this node rather than the
+//! canonical chain.
+//!
+//! Every [`HEALTH_REPORT_PERIOD`] best blocks the observer also samples the
+//! number of active spaces and network members and reports them, together with
+//! the anchoring rate since the previous report, as a `cord.health` telemetry
+//! message.
+
+use crate::service::{Chain, FullClient};
+use codec::Decode;
+use cord_primitives::Block;
+use frame_support::traits::PalletInfo;
+use futures::StreamExt;
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sc_service::SpawnTaskHandle;
+use sc_telemetry::{telemetry, TelemetryHandle, SUBSTRATE_INFO};
+use sp_core::storage::StorageKey;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::{sync::Arc, time::Instant};
+use substrate_prometheus_endpoint::{
+	register, Counter, Gauge, GaugeVec, Opts, PrometheusError, Registry, F64, U64,
+};
+
+const LOG_TARGET: &str = "cord-metrics";
+
+/// Number of best blocks between two chain health samples.
+pub const HEALTH_REPORT_PERIOD: u32 = 10;
+
+/// Counters and gauges fed from CORD pallet events.
+#[derive(Clone)]
+pub struct CordMetrics {
+	statements_anchored: Counter<U64>,
+	statements_anchored_in_block: Gauge<U64>,
+	statement_revocations: Counter<U64>,
+	schemas_created: Counter<U64>,
+	space_utilization: GaugeVec<F64>,
+	active_spaces: Gauge<U64>,
+	members: Gauge<U64>,
+}
+
+impl CordMetrics {
+	/// Registers the CORD metrics with the given registry.
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			statements_anchored: register(
+				Counter::new(
+					"cord_statements_anchored_total",
+					"Number of statements anchored in imported best blocks",
+				)?,
+				registry,
+			)?,
+			statements_anchored_in_block: register(
+				Gauge::new(
+					"cord_statements_anchored_per_block",
+					"Number of statements anchored in the latest imported best block",
+				)?,
+				registry,
+			)?,
+			statement_revocations: register(
+				Counter::new(
+					"cord_statement_revocations_total",
+					"Number of statements revoked in imported best blocks",
+				)?,
+				registry,
+			)?,
+			schemas_created: register(
+				Counter::new(
+					"cord_schemas_created_total",
+					"Number of schemas and schema versions created in imported best blocks",
+				)?,
+				registry,
+			)?,
+			space_utilization: register(
+				GaugeVec::new(
+					Opts::new(
+						"cord_space_capacity_utilization",
+						"Ratio of used to allocated transaction capacity of a chain space",
+					),
+					&["space"],
+				)?,
+				registry,
+			)?,
+			active_spaces: register(
+				Gauge::new(
+					"cord_active_spaces",
+					"Number of approved and unarchived chain spaces at the last health sample",
+				)?,
+				registry,
+			)?,
+			members: register(
+				Gauge::new(
+					"cord_network_members",
+					"Number of network members at the last health sample",
+				)?,
+				registry,
+			)?,
+		})
+	}
+}
+
+/// Spawns the block import observer feeding the CORD metrics and the
+/// `cord.health` telemetry message.
+///
+/// Nothing is spawned when neither a registry nor a telemetry handle is given,
+/// or when the node was built without the native runtime of the selected chain,
+/// as its events cannot be decoded.
+pub fn spawn_cord_metrics(
+	chain: Chain,
+	client: Arc<FullClient>,
+	registry: Option<&Registry>,
+	telemetry: Option<TelemetryHandle>,
+	spawn_handle: &SpawnTaskHandle,
+) -> Result<(), PrometheusError> {
+	if registry.is_none() && telemetry.is_none() {
+		return Ok(())
+	}
+	let metrics = registry.map(CordMetrics::register).transpose()?;
+	let observer = Observer { client, metrics, telemetry };
+
+	match chain {
+		#[cfg(feature = "braid-native")]
+		Chain::Braid =>
+			spawn_handle.spawn("cord-metrics", None, observer.run::<cord_braid_runtime::Runtime>()),
+		#[cfg(feature = "loom-native")]
+		Chain::Loom =>
+			spawn_handle.spawn("cord-metrics", None, observer.run::<cord_loom_runtime::Runtime>()),
+		#[cfg(feature = "weave-native")]
+		Chain::Weave =>
+			spawn_handle.spawn("cord-metrics", None, observer.run::<cord_weave_runtime::Runtime>()),
+		_ => {
+			let _ = (observer, spawn_handle);
+			log::warn!(target: LOG_TARGET, "No native runtime for {:?}, CORD metrics disabled", chain);
+		},
+	}
+
+	Ok(())
+}
+
+/// Chain health sampled from the state of a best block.
+struct Health {
+	active_spaces: u64,
+	members: u32,
+}
+
+struct Observer {
+	client: Arc<FullClient>,
+	metrics: Option<CordMetrics>,
+	telemetry: Option<TelemetryHandle>,
+}
+
+impl Observer {
+	async fn run<R>(self)
+	where
+		R: frame_system::Config<Hash = <Block as BlockT>::Hash>
+			+ pallet_statement::Config
+			+ pallet_schema::Config
+			+ pallet_chain_space::Config
+			+ pallet_network_membership::Config,
+		<R as frame_system::Config>::RuntimeEvent: Clone
+			+ TryInto<pallet_statement::Event<R>>
+			+ TryInto<pallet_schema::Event<R>>
+			+ TryInto<pallet_chain_space::Event<R>>,
+	{
+		let mut imports = self.client.import_notification_stream();
+		let mut anchored_since_report = 0u64;
+		let mut last_report = Instant::now();
+
+		while let Some(notification) = imports.next().await {
+			if !notification.is_new_best {
+				continue
+			}
+			let hash = notification.hash;
+			match self.observe_block::<R>(hash) {
+				Ok(anchored) => anchored_since_report += anchored,
+				Err(e) => {
+					log::debug!(target: LOG_TARGET, "Failed to read CORD events at {:?}: {}", hash, e);
+					continue
+				},
+			}
+
+			if *notification.header.number() % HEALTH_REPORT_PERIOD != 0 {
+				continue
+			}
+			let health = match self.sample_health::<R>(hash) {
+				Ok(health) => health,
+				Err(e) => {
+					log::debug!(target: LOG_TARGET, "Failed to sample chain health at {:?}: {}", hash, e);
+					continue
+				},
+			};
+			let elapsed = last_report.elapsed().as_secs_f64();
+			let anchoring_tps =
+				if elapsed > 0.0 { anchored_since_report as f64 / elapsed } else { 0.0 };
+
+			if let Some(metrics) = &self.metrics {
+				metrics.active_spaces.set(health.active_spaces);
+				metrics.members.set(health.members.into());
+			}
+			telemetry!(
+				self.telemetry;
+				SUBSTRATE_INFO;
+				"cord.health";
+				"best" => ?hash,
+				"height" => *notification.header.number(),
+				"active_spaces" => health.active_spaces,
+				"members" => health.members,
+				"anchoring_tps" => anchoring_tps
+			);
+
+			anchored_since_report = 0;
+			last_report = Instant::now();
+		}
+	}
+
+	/// Feeds the CORD events of a block into the metrics and returns the number
+	/// of statements anchored in it.
+	fn observe_block<R>(&self, hash: <Block as BlockT>::Hash) -> sp_blockchain::Result<u64>
+	where
+		R: frame_system::Config<Hash = <Block as BlockT>::Hash>
+			+ pallet_statement::Config
+			+ pallet_schema::Config
+			+ pallet_chain_space::Config,
+		<R as frame_system::Config>::RuntimeEvent: Clone
+			+ TryInto<pallet_statement::Event<R>>
+			+ TryInto<pallet_schema::Event<R>>
+			+ TryInto<pallet_chain_space::Event<R>>,
+	{
+		let key = storage_key::<R, frame_system::Pallet<R>>(b"Events");
+		let records: Vec<
+			frame_system::EventRecord<<R as frame_system::Config>::RuntimeEvent, R::Hash>,
+		> = self.read_storage(hash, key)?.unwrap_or_default();
+
+		let mut anchored = 0u64;
+		let mut revoked = 0u64;
+		let mut schemas = 0u64;
+		let mut spaces = Vec::<pallet_chain_space::SpaceIdOf>::new();
+		let mut touch = |space: pallet_chain_space::SpaceIdOf| {
+			if !spaces.contains(&space) {
+				spaces.push(space);
+			}
+		};
+
+		for record in records {
+			if let Ok(event) = TryInto::<pallet_statement::Event<R>>::try_into(record.event.clone())
+			{
+				use pallet_statement::Event;
+				let touched = match event {
+					Event::Register { identifier, .. } | Event::Anchored { identifier } => {
+						anchored += 1;
+						Some(identifier)
+					},
+					Event::RegisterBatch { successful, .. } => {
+						anchored += u64::from(successful);
+						None
+					},
+					Event::Revoke { identifier, .. } => {
+						revoked += 1;
+						Some(identifier)
+					},
+					Event::Update { identifier, .. } | Event::Restore { identifier, .. } =>
+						Some(identifier),
+					_ => None,
+				};
+				if let Some(identifier) = touched {
+					let key = pallet_statement::Statements::<R>::hashed_key_for(&identifier);
+					if let Some(details) =
+						self.read_storage::<pallet_statement::StatementDetailsOf<R>>(hash, key)?
+					{
+						touch(details.space);
+					}
+				}
+			} else if let Ok(event) =
+				TryInto::<pallet_schema::Event<R>>::try_into(record.event.clone())
+			{
+				if matches!(
+					event,
+					pallet_schema::Event::Created { .. } | pallet_schema::Event::Versioned { .. }
+				) {
+					schemas += 1;
+				}
+			} else if let Ok(event) =
+				TryInto::<pallet_chain_space::Event<R>>::try_into(record.event)
+			{
+				use pallet_chain_space::Event;
+				match event {
+					Event::Create { space, .. } |
+					Event::UpdateCapacity { space } |
+					Event::ResetUsage { space } |
+					Event::CapacityThresholdReached { space, .. } => touch(space),
+					_ => {},
+				}
+			}
+		}
+
+		let Some(metrics) = &self.metrics else { return Ok(anchored) };
+		metrics.statements_anchored.inc_by(anchored);
+		metrics.statements_anchored_in_block.set(anchored);
+		metrics.statement_revocations.inc_by(revoked);
+		metrics.schemas_created.inc_by(schemas);
+
+		for space in spaces {
+			let key = pallet_chain_space::Spaces::<R>::hashed_key_for(&space);
+			let label = String::from_utf8_lossy(space.inner()).into_owned();
+			match self.read_storage::<pallet_chain_space::SpaceDetailsOf<R>>(hash, key)? {
+				Some(details) if details.txn_capacity > 0 => metrics
+					.space_utilization
+					.with_label_values(&[&label])
+					.set(details.txn_count as f64 / details.txn_capacity as f64),
+				_ => {
+					let _ = metrics.space_utilization.remove_label_values(&[&label]);
+				},
+			}
+		}
+
+		Ok(anchored)
+	}
+
+	/// Counts the approved, unarchived spaces and the network members at a
+	/// block.
+	fn sample_health<R>(&self, hash: <Block as BlockT>::Hash) -> sp_blockchain::Result<Health>
+	where
+		R: pallet_chain_space::Config + pallet_network_membership::Config,
+	{
+		let prefix = StorageKey(storage_key::<R, pallet_chain_space::Pallet<R>>(b"Spaces"));
+		let mut active_spaces = 0u64;
+		for (_, data) in self.client.storage_pairs(hash, Some(&prefix), None)? {
+			let details = pallet_chain_space::SpaceDetailsOf::<R>::decode(&mut &data.0[..])
+				.map_err(|e| sp_blockchain::Error::Storage(e.to_string()))?;
+			if details.approved && !details.archive {
+				active_spaces += 1;
+			}
+		}
+
+		let key = storage_key::<R, pallet_network_membership::Pallet<R>>(b"CounterForMembers");
+		let members = self.read_storage::<u32>(hash, key)?.unwrap_or_default();
+
+		Ok(Health { active_spaces, members })
+	}
+
+	fn read_storage<T: Decode>(
+		&self,
+		hash: <Block as BlockT>::Hash,
+		key: Vec<u8>,
+	) -> sp_blockchain::Result<Option<T>> {
+		self.client
+			.storage(hash, &StorageKey(key))?
+			.map(|data| {
+				T::decode(&mut &data.0[..])
+					.map_err(|e| sp_blockchain::Error::Storage(e.to_string()))
+			})
+			.transpose()
+	}
+}
+
+/// Storage key of a value, or prefix of a map, declared by pallet `P` in
+/// runtime `R`.
+fn storage_key<R: frame_system::Config, P: 'static>(storage_name: &[u8]) -> Vec<u8> {
+	let pallet_name = <R as frame_system::Config>::PalletInfo::name::<P>().unwrap_or_default();
+	frame_support::storage::storage_prefix(pallet_name.as_bytes(), storage_name).to_vec()
+}
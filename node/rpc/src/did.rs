@@ -0,0 +1,274 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for resolving DIDs into W3C DID documents.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use cord_primitives::{AccountId, BlockNumber, DidIdentifier, Hash};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use pallet_did::did_details::{DidEncryptionKey, DidPublicKey, DidVerificationKey};
+pub use pallet_did_runtime_api::{DidApi, RawDidLinkedInfo};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{crypto::Ss58Codec, ByteArray};
+use sp_runtime::traits::Block as BlockT;
+
+/// The method prefix of CORD DIDs.
+pub const DID_PREFIX: &str = "did:cord:";
+
+/// The JSON-LD context of W3C DID documents.
+pub const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+
+/// A key listed in a DID document.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+	/// The key identifier, the DID followed by the key id as fragment.
+	pub id: String,
+	/// The type of the key.
+	#[serde(rename = "type")]
+	pub method_type: String,
+	/// The DID controlling the key.
+	pub controller: String,
+	/// The public key, hex encoded.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub public_key_hex: Option<String>,
+	/// The account controlling the key, for account keys.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub blockchain_account_id: Option<String>,
+}
+
+/// A service endpoint listed in a DID document.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Service {
+	/// The service identifier, the DID followed by the service id as
+	/// fragment.
+	pub id: String,
+	/// The types of the service.
+	#[serde(rename = "type")]
+	pub service_types: Vec<String>,
+	/// The URLs the service is reachable at.
+	pub service_endpoint: Vec<String>,
+}
+
+/// A DID document, following the W3C DID Core data model.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+	/// The JSON-LD context of the document.
+	#[serde(rename = "@context")]
+	pub context: Vec<String>,
+	/// The DID.
+	pub id: String,
+	/// The DID name linked to the DID, if any.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub also_known_as: Vec<String>,
+	/// Every key of the DID.
+	pub verification_method: Vec<VerificationMethod>,
+	/// The authentication key.
+	pub authentication: Vec<String>,
+	/// The assertion key, if any.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub assertion_method: Vec<String>,
+	/// The delegation key, if any.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub capability_delegation: Vec<String>,
+	/// The key agreement keys.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub key_agreement: Vec<String>,
+	/// The service endpoints of the DID.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub service: Vec<Service>,
+}
+
+/// Metadata about a resolved DID document.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocumentMetadata {
+	/// The number of the block the most recent key was added in.
+	pub updated: Option<BlockNumber>,
+}
+
+/// The result of resolving a DID.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolution {
+	/// The DID document.
+	pub did_document: DidDocument,
+	/// Metadata about the DID document.
+	pub did_document_metadata: DidDocumentMetadata,
+}
+
+#[rpc(server)]
+pub trait CordDidApi<BlockHash> {
+	/// Returns the DID document of the given DID, or `None` if the DID does
+	/// not exist. The DID may be given with or without the `did:cord:`
+	/// prefix.
+	#[method(name = "did_resolve")]
+	fn resolve(&self, did: String, at: Option<BlockHash>) -> RpcResult<Option<DidResolution>>;
+}
+
+/// Provides RPC methods to resolve DIDs.
+pub struct CordDid<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> CordDid<C, P> {
+	/// Creates a new instance of the CordDid Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The DID is malformed.
+	InvalidIdentifier,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidIdentifier => 2,
+		}
+	}
+}
+
+fn utf8(bytes: Vec<u8>) -> String {
+	String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn verification_method(
+	did: &str,
+	key_id: &Hash,
+	key: DidPublicKey<AccountId>,
+) -> VerificationMethod {
+	let (method_type, public_key_hex, blockchain_account_id) = match key {
+		DidPublicKey::PublicVerificationKey(DidVerificationKey::Ed25519(key)) => (
+			"Ed25519VerificationKey2018",
+			Some(sp_core::bytes::to_hex(key.as_slice(), false)),
+			None,
+		),
+		DidPublicKey::PublicVerificationKey(DidVerificationKey::Sr25519(key)) => (
+			"Sr25519VerificationKey2020",
+			Some(sp_core::bytes::to_hex(key.as_slice(), false)),
+			None,
+		),
+		DidPublicKey::PublicVerificationKey(DidVerificationKey::Ecdsa(key)) => (
+			"EcdsaSecp256k1VerificationKey2019",
+			Some(sp_core::bytes::to_hex(key.as_slice(), false)),
+			None,
+		),
+		DidPublicKey::PublicVerificationKey(DidVerificationKey::Account(account)) =>
+			("BlockchainVerificationMethod2021", None, Some(account.to_ss58check())),
+		DidPublicKey::PublicEncryptionKey(DidEncryptionKey::X25519(key)) =>
+			("X25519KeyAgreementKey2019", Some(sp_core::bytes::to_hex(&key, false)), None),
+	};
+
+	VerificationMethod {
+		id: format!("{}#{:#x}", did, key_id),
+		method_type: method_type.into(),
+		controller: did.into(),
+		public_key_hex,
+		blockchain_account_id,
+	}
+}
+
+impl From<RawDidLinkedInfo<DidIdentifier, AccountId, Hash, BlockNumber>> for DidResolution {
+	fn from(info: RawDidLinkedInfo<DidIdentifier, AccountId, Hash, BlockNumber>) -> Self {
+		let did = format!("{}{}", DID_PREFIX, info.identifier.to_ss58check());
+		let key_ref = |key_id: &Hash| format!("{}#{:#x}", did, key_id);
+		let details = info.details;
+
+		let updated = details.public_keys.values().map(|key| key.block_number).max();
+		let authentication = vec![key_ref(&details.authentication_key)];
+		let assertion_method = details.assertion_key.iter().map(key_ref).collect();
+		let capability_delegation = details.delegation_key.iter().map(key_ref).collect();
+		let key_agreement = details.key_agreement_keys.iter().map(key_ref).collect();
+		let verification_method = details
+			.public_keys
+			.into_iter()
+			.map(|(key_id, key)| verification_method(&did, &key_id, key.key))
+			.collect();
+		let service = info
+			.service_endpoints
+			.into_iter()
+			.map(|endpoint| Service {
+				id: format!("{}#{}", did, utf8(endpoint.id).trim_start_matches('#')),
+				service_types: endpoint.service_types.into_iter().map(utf8).collect(),
+				service_endpoint: endpoint.urls.into_iter().map(utf8).collect(),
+			})
+			.collect();
+
+		DidResolution {
+			did_document: DidDocument {
+				context: vec![DID_CONTEXT.into()],
+				id: did,
+				also_known_as: info.name.into_iter().map(utf8).collect(),
+				verification_method,
+				authentication,
+				assertion_method,
+				capability_delegation,
+				key_agreement,
+				service,
+			},
+			did_document_metadata: DidDocumentMetadata { updated },
+		}
+	}
+}
+
+impl<C, Block> CordDidApiServer<<Block as BlockT>::Hash> for CordDid<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: DidApi<Block, DidIdentifier, AccountId, Hash, BlockNumber>,
+{
+	fn resolve(&self, did: String, at: Option<Block::Hash>) -> RpcResult<Option<DidResolution>> {
+		let identifier = DidIdentifier::from_ss58check(
+			did.strip_prefix(DID_PREFIX).unwrap_or(&did),
+		)
+		.map_err(|e| {
+			ErrorObject::owned(
+				Error::InvalidIdentifier.into(),
+				"Unable to resolve DID.",
+				Some(format!("{:?}", e)),
+			)
+		})?;
+
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let info = api.query(at_hash, identifier).map_err(|e| {
+			ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to resolve DID.",
+				Some(e.to_string()),
+			)
+		})?;
+
+		Ok(info.map(Into::into))
+	}
+}
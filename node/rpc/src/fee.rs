@@ -0,0 +1,133 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for estimating the fee of an extrinsic and who pays it.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Decode;
+use cord_primitives::{AccountId, Balance};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use pallet_transaction_sponsor_runtime_api::SpaceIdOf;
+pub use pallet_transaction_sponsor_runtime_api::TransactionSponsorApi;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+/// The expected fee of an extrinsic and the account it is charged to.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeEstimate {
+	/// The inclusion fee of the extrinsic, excluding any tip.
+	pub partial_fee: NumberOrHex,
+	/// The account the fee would be withdrawn from.
+	pub payer: AccountId,
+	/// The space whose sponsorship covers the fee, if any.
+	pub sponsored_space: Option<String>,
+}
+
+#[rpc(server)]
+pub trait CordFeeApi<BlockHash> {
+	/// Returns the expected fee of a signed extrinsic, taking space
+	/// sponsorships into account.
+	#[method(name = "cord_estimateFee")]
+	fn estimate_fee(&self, encoded_xt: Bytes, at: Option<BlockHash>) -> RpcResult<FeeEstimate>;
+}
+
+/// Provides RPC methods to estimate extrinsic fees.
+pub struct CordFee<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> CordFee<C, P> {
+	/// Creates a new instance of the CordFee Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The transaction was not decodable.
+	DecodeError,
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The transaction carries no signer to charge.
+	UnsignedExtrinsic,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::DecodeError => 2,
+			Error::UnsignedExtrinsic => 3,
+		}
+	}
+}
+
+impl<C, Block> CordFeeApiServer<<Block as BlockT>::Hash> for CordFee<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TransactionSponsorApi<Block, AccountId, Balance, SpaceIdOf>,
+{
+	fn estimate_fee(&self, encoded_xt: Bytes, at: Option<Block::Hash>) -> RpcResult<FeeEstimate> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let encoded_len = encoded_xt.len() as u32;
+		let uxt: Block::Extrinsic = Decode::decode(&mut &*encoded_xt).map_err(|e| {
+			ErrorObject::owned(
+				Error::DecodeError.into(),
+				"Unable to estimate fee.",
+				Some(format!("{:?}", e)),
+			)
+		})?;
+
+		let estimate = api
+			.estimate_fee(at_hash, uxt, encoded_len)
+			.map_err(|e| {
+				ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to estimate fee.",
+					Some(e.to_string()),
+				)
+			})?
+			.ok_or_else(|| {
+				ErrorObject::owned(
+					Error::UnsignedExtrinsic.into(),
+					"Unable to estimate fee.",
+					Some("Unsigned extrinsics are not charged a fee"),
+				)
+			})?;
+
+		Ok(FeeEstimate {
+			partial_fee: estimate.partial_fee.into(),
+			payer: estimate.payer,
+			sponsored_space: estimate
+				.sponsored_space
+				.map(|space| String::from_utf8_lossy(space.as_ref()).into_owned()),
+		})
+	}
+}
@@ -0,0 +1,87 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for looking up where an identifier was created, backed by
+//! the offchain index.
+
+use codec::Decode;
+use identifier::{offchain_index_key, Timepoint};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use serde::{Deserialize, Serialize};
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+
+/// The point in the chain at which an identifier was created.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentifierLocation {
+	/// The number of the block that created the identifier.
+	pub block_number: u32,
+	/// The index of the creating extrinsic within that block.
+	pub extrinsic_index: u32,
+}
+
+#[rpc(server)]
+pub trait IdentifierIndexApi {
+	/// Returns the block and extrinsic that created an identifier, or
+	/// `None` if the node has not indexed it.
+	///
+	/// Identifiers are only indexed by nodes started with
+	/// `--enable-offchain-indexing`, from the block the flag was enabled.
+	#[method(name = "cord_identifierLocation")]
+	fn identifier_location(&self, identifier: String) -> RpcResult<Option<IdentifierLocation>>;
+}
+
+/// Provides RPC methods to query the identifier offchain index.
+pub struct IdentifierIndex<S> {
+	/// Offchain storage of the node.
+	storage: S,
+}
+
+impl<S> IdentifierIndex<S> {
+	/// Creates a new instance of the IdentifierIndex Rpc helper.
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+}
+
+impl<S> IdentifierIndexApiServer for IdentifierIndex<S>
+where
+	S: OffchainStorage + 'static,
+{
+	fn identifier_location(&self, identifier: String) -> RpcResult<Option<IdentifierLocation>> {
+		let key = offchain_index_key(identifier.as_bytes());
+
+		self.storage
+			.get(STORAGE_PREFIX, &key)
+			.map(|value| {
+				Timepoint::decode(&mut &value[..])
+					.map(|location| IdentifierLocation {
+						block_number: location.height,
+						extrinsic_index: location.index,
+					})
+					.map_err(|e| {
+						ErrorObject::owned(
+							1,
+							"Unable to decode indexed identifier location.",
+							Some(e.to_string()),
+						)
+					})
+			})
+			.transpose()
+	}
+}
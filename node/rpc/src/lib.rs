@@ -18,9 +18,15 @@
 
 //! A collection of CORD specific RPC methods.
 
+pub mod did;
+pub mod fee;
+pub mod identifier_index;
+pub mod schema;
+pub mod statement;
+
 use std::sync::Arc;
 
-use cord_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Nonce};
+use cord_primitives::{AccountId, Balance, Block, BlockNumber, DidIdentifier, Hash, Nonce};
 use jsonrpsee::RpcModule;
 use sc_client_api::AuxStore;
 use sc_consensus_babe::BabeWorkerHandle;
@@ -104,6 +110,18 @@ where
 		+ 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: did::DidApi<Block, DidIdentifier, AccountId, Hash, BlockNumber>,
+	C::Api: fee::TransactionSponsorApi<Block, AccountId, Balance, fee::SpaceIdOf>,
+	C::Api: schema::SchemaApi<Block, schema::SchemaIdOf, Hash, schema::SchemaInfoOf>,
+	C::Api: statement::StatementApi<
+		Block,
+		statement::StatementIdOf,
+		Hash,
+		statement::SpaceIdOf,
+		statement::StatementInfoOf,
+		statement::AnchorStateOf,
+		statement::ReceiptOf,
+	>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
@@ -111,12 +129,17 @@ where
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use did::{CordDid, CordDidApiServer};
+	use fee::{CordFee, CordFeeApiServer};
+	use identifier_index::{IdentifierIndex, IdentifierIndexApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
 	use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
 	use sc_rpc::dev::{Dev, DevApiServer};
 	use sc_rpc_spec_v2::chain_spec::{ChainSpec, ChainSpecApiServer};
 	use sc_sync_state_rpc::{SyncState, SyncStateApiServer};
+	use schema::{CordSchema, CordSchemaApiServer};
+	use statement::{CordStatement, CordStatementApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
 
@@ -138,6 +161,10 @@ where
 
 	io.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
 	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(CordDid::new(client.clone()).into_rpc())?;
+	io.merge(CordFee::new(client.clone()).into_rpc())?;
+	io.merge(CordSchema::new(client.clone()).into_rpc())?;
+	io.merge(CordStatement::new(client.clone()).into_rpc())?;
 
 	io.merge(
 		Babe::new(client.clone(), babe_worker_handle.clone(), keystore, select_chain, deny_unsafe)
@@ -158,6 +185,9 @@ where
 		SyncState::new(chain_spec, client.clone(), shared_authority_set, babe_worker_handle)?
 			.into_rpc(),
 	)?;
+	if let Some(storage) = backend.offchain_storage() {
+		io.merge(IdentifierIndex::new(storage).into_rpc())?;
+	}
 	io.merge(StateMigration::new(client.clone(), backend, deny_unsafe).into_rpc())?;
 	io.merge(Dev::new(client, deny_unsafe).into_rpc())?;
 
@@ -0,0 +1,180 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for resolving anchored schemas.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::{Decode, Encode};
+use cord_primitives::{AccountId, BlockNumber, Hash};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use pallet_schema_runtime_api::{DigestPolicy, FreezeAuthority};
+pub use pallet_schema_runtime_api::{SchemaApi, SchemaIdOf, SchemaInfoOf};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+/// An anchored schema with its status and version links.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaDetails {
+	/// The schema identifier.
+	pub identifier: String,
+	/// The schema hash. For schemas anchored from a preimage this is the
+	/// hash of the preimage.
+	pub digest: Hash,
+	/// The controller of the schema.
+	pub creator: AccountId,
+	/// The space the schema is anchored in.
+	pub space: String,
+	/// The inline schema, or `None` for schemas anchored from a preimage.
+	pub schema: Option<Bytes>,
+	/// The number of the block the schema was deprecated at, if any.
+	pub deprecated_at: Option<BlockNumber>,
+	/// Who froze the schema, `admin` or `council`, if it is frozen.
+	pub frozen_by: Option<String>,
+	/// Whether statements under the schema may share digests.
+	pub unique_digests: bool,
+	/// The version superseded by the schema, if any.
+	pub parent: Option<String>,
+	/// The version superseding the schema, if any.
+	pub successor: Option<String>,
+}
+
+#[rpc(server)]
+pub trait CordSchemaApi<BlockHash> {
+	/// Returns the schema with the given identifier, or `None` if it is not
+	/// anchored.
+	#[method(name = "cord_getSchema")]
+	fn schema(&self, identifier: String, at: Option<BlockHash>)
+		-> RpcResult<Option<SchemaDetails>>;
+
+	/// Returns every schema anchored with the given schema hash.
+	#[method(name = "cord_getSchemaByHash")]
+	fn schema_by_hash(&self, hash: Hash, at: Option<BlockHash>) -> RpcResult<Vec<SchemaDetails>>;
+}
+
+/// Provides RPC methods to resolve anchored schemas.
+pub struct CordSchema<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> CordSchema<C, P> {
+	/// Creates a new instance of the CordSchema Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The schema identifier is malformed.
+	InvalidIdentifier,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidIdentifier => 2,
+		}
+	}
+}
+
+fn ss58(identifier: &impl AsRef<[u8]>) -> String {
+	String::from_utf8_lossy(identifier.as_ref()).into_owned()
+}
+
+impl From<SchemaInfoOf> for SchemaDetails {
+	fn from(info: SchemaInfoOf) -> Self {
+		SchemaDetails {
+			identifier: ss58(&info.identifier),
+			digest: info.details.digest,
+			creator: info.details.creator,
+			space: ss58(&info.details.space),
+			schema: (!info.preimage).then(|| info.details.schema.into()),
+			deprecated_at: info.deprecated_at,
+			frozen_by: info.frozen_by.map(|authority| {
+				match authority {
+					FreezeAuthority::Admin => "admin",
+					FreezeAuthority::Council => "council",
+				}
+				.into()
+			}),
+			unique_digests: info.digest_policy == DigestPolicy::Unique,
+			parent: info.parent.as_ref().map(ss58),
+			successor: info.successor.as_ref().map(ss58),
+		}
+	}
+}
+
+impl<C, Block> CordSchemaApiServer<<Block as BlockT>::Hash> for CordSchema<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: SchemaApi<Block, SchemaIdOf, Hash, SchemaInfoOf>,
+{
+	fn schema(
+		&self,
+		identifier: String,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<SchemaDetails>> {
+		let identifier =
+			SchemaIdOf::decode(&mut &identifier.into_bytes().encode()[..]).map_err(|e| {
+				ErrorObject::owned(
+					Error::InvalidIdentifier.into(),
+					"Unable to resolve schema.",
+					Some(e.to_string()),
+				)
+			})?;
+
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let info = api.schema(at_hash, identifier).map_err(|e| {
+			ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to resolve schema.",
+				Some(e.to_string()),
+			)
+		})?;
+
+		Ok(info.map(Into::into))
+	}
+
+	fn schema_by_hash(&self, hash: Hash, at: Option<Block::Hash>) -> RpcResult<Vec<SchemaDetails>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let schemas = api.schema_by_hash(at_hash, hash).map_err(|e| {
+			ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to resolve schemas.",
+				Some(e.to_string()),
+			)
+		})?;
+
+		Ok(schemas.into_iter().map(Into::into).collect())
+	}
+}
@@ -0,0 +1,172 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for verifying batches of anchored document digests.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use cord_primitives::{BlockNumber, Hash};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+pub use pallet_statement_runtime_api::{
+	AnchorStateOf, ReceiptOf, SpaceIdOf, StatementApi, StatementIdOf, StatementInfoOf,
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// The maximum number of digests accepted by a single `cord_verifyBatch`
+/// request.
+pub const MAX_VERIFY_BATCH: usize = 1000;
+
+/// A space a digest is anchored in, with the state of the anchor.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestAnchor {
+	/// The space the digest is anchored in.
+	pub space: String,
+	/// The statement identifier the digest belongs to.
+	pub identifier: String,
+	/// The schema referenced by the statement, if any.
+	pub schema: Option<String>,
+	/// Whether the digest has been revoked.
+	pub revoked: bool,
+	/// The number of the block the digest was anchored in.
+	pub block_number: BlockNumber,
+}
+
+/// The verification result of a single digest.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestVerification {
+	/// The digest that was looked up.
+	pub digest: Hash,
+	/// Whether the digest is anchored in at least one space.
+	pub anchored: bool,
+	/// Every space the digest is anchored in.
+	pub anchors: Vec<DigestAnchor>,
+}
+
+#[rpc(server)]
+pub trait CordStatementApi<BlockHash> {
+	/// Returns, for each digest and in the same order, whether it is
+	/// anchored together with its statement identifier, revocation status
+	/// and anchoring block in every space it is anchored in.
+	///
+	/// At most [`MAX_VERIFY_BATCH`] digests are accepted per request.
+	#[method(name = "cord_verifyBatch")]
+	fn verify_batch(
+		&self,
+		hashes: Vec<Hash>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DigestVerification>>;
+}
+
+/// Provides RPC methods to verify anchored statement digests.
+pub struct CordStatement<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> CordStatement<C, P> {
+	/// Creates a new instance of the CordStatement Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The request carries more digests than allowed.
+	TooManyDigests,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::TooManyDigests => 2,
+		}
+	}
+}
+
+fn ss58(identifier: &impl AsRef<[u8]>) -> String {
+	String::from_utf8_lossy(identifier.as_ref()).into_owned()
+}
+
+impl<C, Block> CordStatementApiServer<<Block as BlockT>::Hash> for CordStatement<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: StatementApi<
+		Block,
+		StatementIdOf,
+		Hash,
+		SpaceIdOf,
+		StatementInfoOf,
+		AnchorStateOf,
+		ReceiptOf,
+	>,
+{
+	fn verify_batch(
+		&self,
+		hashes: Vec<Hash>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<DigestVerification>> {
+		if hashes.len() > MAX_VERIFY_BATCH {
+			return Err(ErrorObject::owned(
+				Error::TooManyDigests.into(),
+				"Unable to verify digests.",
+				Some(format!("At most {} digests are accepted per request", MAX_VERIFY_BATCH)),
+			));
+		}
+
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let anchors = api.verify_batch(at_hash, hashes.clone()).map_err(|e| {
+			ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to verify digests.",
+				Some(e.to_string()),
+			)
+		})?;
+
+		Ok(hashes
+			.into_iter()
+			.zip(anchors)
+			.map(|(digest, anchors)| DigestVerification {
+				digest,
+				anchored: !anchors.is_empty(),
+				anchors: anchors
+					.into_iter()
+					.map(|(space, state)| DigestAnchor {
+						space: ss58(&space),
+						identifier: ss58(&state.identifier),
+						schema: state.schema.as_ref().map(ss58),
+						revoked: state.revoked,
+						block_number: state.block,
+					})
+					.collect(),
+			})
+			.collect())
+	}
+}
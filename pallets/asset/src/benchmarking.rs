@@ -353,5 +353,276 @@ benchmarks! {
 			assert_last_event::<T>(Event::StatusChange { identifier: asset_id.clone(), instance: Some(instance_id.clone()), status: new_status.clone() }.into());
 		}
 
+		propose_transfer {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+
+			let did_transfer: T::SpaceCreatorId = account("did", 1, SEED);
+
+			let asset_desc = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_tag = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_meta = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_qty = 10;
+			let asset_value = 10;
+			let asset_type = AssetTypeOf::MF;
+
+			let raw_space = [56u8; 256].to_vec();
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+			let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+
+			let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]]
+					.concat()[..],
+			);
+
+			let auth_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+			let entry = AssetInputEntryOf::<T> {
+				asset_desc,
+				asset_qty,
+				asset_type,
+				asset_value,
+				asset_tag,
+				asset_meta,
+			};
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let capacity = 5u64;
+
+			let digest = <T as frame_system::Config>::Hashing::hash(
+				&[&entry.encode()[..]].concat()[..],
+			);
+
+			let create_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+			);
+
+			let asset_id: Ss58Identifier = generate_asset_id::<T>(&create_id_digest);
+
+			let chain_space_origin = RawOrigin::Root.into();
+
+			let issue_entry = AssetIssuanceEntryOf::<T> {
+				asset_id: asset_id.clone(),
+				asset_owner: did.clone(),
+				asset_issuance_qty: Some(10),
+			};
+
+			let issue_entry_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&issue_entry.encode()[..]].concat()[..],
+			);
+
+			let issue_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&asset_id.encode()[..], &did.encode()[..], &space_id.encode()[..], &did.encode()[..], &issue_entry_digest.encode()[..]].concat()[..],
+			);
+
+			let instance_id = generate_asset_instance_id::<T>(&issue_id_digest);
+
+			let transfer_entry = AssetTransferEntryOf::<T> {
+				asset_id: asset_id.clone(),
+				asset_instance_id: instance_id.clone(),
+				asset_owner: did.clone(),
+				new_asset_owner: did_transfer.clone(),
+			};
+
+			pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+			pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity).expect("Approval should not fail.");
+			Pallet::<T>::create(origin.clone(), entry, digest, authorization_id.clone())?;
+			Pallet::<T>::issue(origin.clone(), issue_entry, issue_entry_digest, authorization_id)?;
+
+		}: _<T::RuntimeOrigin>(origin, transfer_entry)
+		verify {
+			let expires_at = frame_system::Pallet::<T>::block_number() + T::TransferIntentLifetime::get();
+			assert_last_event::<T>(Event::TransferProposed { identifier: asset_id, instance: instance_id, from: did, to: did_transfer, expires_at }.into());
+		}
+
+		accept_transfer {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+
+			let did_transfer: T::SpaceCreatorId = account("did", 1, SEED);
+
+			let asset_desc = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_tag = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_meta = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_qty = 10;
+			let asset_value = 10;
+			let asset_type = AssetTypeOf::MF;
+
+			let raw_space = [56u8; 256].to_vec();
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+			let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+
+			let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]]
+					.concat()[..],
+			);
+
+			let auth_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+			let entry = AssetInputEntryOf::<T> {
+				asset_desc,
+				asset_qty,
+				asset_type,
+				asset_value,
+				asset_tag,
+				asset_meta,
+			};
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let capacity = 5u64;
+
+			let digest = <T as frame_system::Config>::Hashing::hash(
+				&[&entry.encode()[..]].concat()[..],
+			);
+
+			let create_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+			);
+
+			let asset_id: Ss58Identifier = generate_asset_id::<T>(&create_id_digest);
+
+			let chain_space_origin = RawOrigin::Root.into();
+
+			let issue_entry = AssetIssuanceEntryOf::<T> {
+				asset_id: asset_id.clone(),
+				asset_owner: did.clone(),
+				asset_issuance_qty: Some(10),
+			};
+
+			let issue_entry_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&issue_entry.encode()[..]].concat()[..],
+			);
+
+			let issue_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&asset_id.encode()[..], &did.encode()[..], &space_id.encode()[..], &did.encode()[..], &issue_entry_digest.encode()[..]].concat()[..],
+			);
+
+			let instance_id = generate_asset_instance_id::<T>(&issue_id_digest);
+
+			let transfer_entry = AssetTransferEntryOf::<T> {
+				asset_id: asset_id.clone(),
+				asset_instance_id: instance_id.clone(),
+				asset_owner: did.clone(),
+				new_asset_owner: did_transfer.clone(),
+			};
+
+			pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+			pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity).expect("Approval should not fail.");
+			Pallet::<T>::create(origin.clone(), entry, digest, authorization_id.clone())?;
+			Pallet::<T>::issue(origin.clone(), issue_entry, issue_entry_digest, authorization_id)?;
+
+			Pallet::<T>::propose_transfer(origin, transfer_entry)?;
+
+			let recipient_origin = <T as Config>::EnsureOrigin::generate_origin(caller, did_transfer.clone());
+
+		}: _<T::RuntimeOrigin>(recipient_origin, asset_id.clone(), instance_id.clone())
+		verify {
+			assert_last_event::<T>(Event::Transfer { identifier: asset_id, instance: instance_id, from: did, to: did_transfer }.into());
+		}
+
+		cancel_transfer {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+
+			let did_transfer: T::SpaceCreatorId = account("did", 1, SEED);
+
+			let asset_desc = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_tag = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_meta = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_qty = 10;
+			let asset_value = 10;
+			let asset_type = AssetTypeOf::MF;
+
+			let raw_space = [56u8; 256].to_vec();
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+			let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+
+			let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]]
+					.concat()[..],
+			);
+
+			let auth_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+			let entry = AssetInputEntryOf::<T> {
+				asset_desc,
+				asset_qty,
+				asset_type,
+				asset_value,
+				asset_tag,
+				asset_meta,
+			};
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let capacity = 5u64;
+
+			let digest = <T as frame_system::Config>::Hashing::hash(
+				&[&entry.encode()[..]].concat()[..],
+			);
+
+			let create_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+			);
+
+			let asset_id: Ss58Identifier = generate_asset_id::<T>(&create_id_digest);
+
+			let chain_space_origin = RawOrigin::Root.into();
+
+			let issue_entry = AssetIssuanceEntryOf::<T> {
+				asset_id: asset_id.clone(),
+				asset_owner: did.clone(),
+				asset_issuance_qty: Some(10),
+			};
+
+			let issue_entry_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&issue_entry.encode()[..]].concat()[..],
+			);
+
+			let issue_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&asset_id.encode()[..], &did.encode()[..], &space_id.encode()[..], &did.encode()[..], &issue_entry_digest.encode()[..]].concat()[..],
+			);
+
+			let instance_id = generate_asset_instance_id::<T>(&issue_id_digest);
+
+			let transfer_entry = AssetTransferEntryOf::<T> {
+				asset_id: asset_id.clone(),
+				asset_instance_id: instance_id.clone(),
+				asset_owner: did.clone(),
+				new_asset_owner: did_transfer.clone(),
+			};
+
+			pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+			pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity).expect("Approval should not fail.");
+			Pallet::<T>::create(origin.clone(), entry, digest, authorization_id.clone())?;
+			Pallet::<T>::issue(origin.clone(), issue_entry, issue_entry_digest, authorization_id)?;
+
+			Pallet::<T>::propose_transfer(origin.clone(), transfer_entry)?;
+
+		}: _<T::RuntimeOrigin>(origin, asset_id.clone(), instance_id.clone())
+		verify {
+			assert_last_event::<T>(Event::TransferCancelled { identifier: asset_id, instance: instance_id }.into());
+		}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
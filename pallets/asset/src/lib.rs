@@ -112,6 +112,8 @@ pub mod pallet {
 
 	pub type AssetIssuanceEntryOf<T> = AssetIssuanceEntry<AssetIdOf, AssetCreatorOf<T>>;
 
+	pub type AssetTransferIntentOf<T> = AssetTransferIntent<AssetCreatorOf<T>, BlockNumberFor<T>>;
+
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config + pallet_chain_space::Config + identifier::Config
@@ -128,6 +130,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAssetDistribution: Get<u32>;
 
+		/// Number of blocks a proposed transfer stays open for acceptance.
+		#[pallet::constant]
+		type TransferIntentLifetime: Get<BlockNumberFor<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -185,6 +191,18 @@ pub mod pallet {
 	pub type AssetLookup<T> =
 		StorageMap<_, Blake2_128Concat, EntryHashOf<T>, AssetIdOf, OptionQuery>;
 
+	/// asset instance transfers waiting for the recipient to accept them.
+	#[pallet::storage]
+	pub type TransferIntents<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AssetIdOf,
+		Blake2_128Concat,
+		AssetInstanceIdOf,
+		AssetTransferIntentOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -210,6 +228,19 @@ pub mod pallet {
 			instance: Option<AssetInstanceIdOf>,
 			status: AssetStatusOf,
 		},
+		/// A transfer of an asset instance has been proposed.
+		/// \[asset entry identifier, instance identifier, owner, beneficiary,
+		/// expiry block\]
+		TransferProposed {
+			identifier: AssetIdOf,
+			instance: AssetInstanceIdOf,
+			from: AssetCreatorOf<T>,
+			to: AssetCreatorOf<T>,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// A proposed transfer has been cancelled by the owner.
+		/// \[asset entry identifier, instance identifier\]
+		TransferCancelled { identifier: AssetIdOf, instance: AssetInstanceIdOf },
 	}
 
 	#[pallet::error]
@@ -246,6 +277,10 @@ pub mod pallet {
 		AssetInstanceNotFound,
 		/// Asset is in same status as asked for
 		AssetInSameState,
+		/// No transfer has been proposed for the asset instance
+		TransferIntentNotFound,
+		/// The proposed transfer is no longer open for acceptance
+		TransferIntentExpired,
 	}
 
 	#[pallet::call]
@@ -496,26 +531,12 @@ pub mod pallet {
 				Error::<T>::InstanceNotActive
 			);
 
-			let block_number = frame_system::Pallet::<T>::block_number();
-
-			<Issuance<T>>::insert(
-				&entry.asset_id,
-				&entry.asset_instance_id,
-				AssetDistributionEntryOf::<T> {
-					asset_instance_owner: entry.new_asset_owner.clone(),
-					created_at: block_number,
-					..instance
-				},
-			);
-
-			Self::update_activity(&entry.asset_instance_id, CallTypeOf::Transfer)
-				.map_err(<Error<T>>::from)?;
-			Self::deposit_event(Event::Transfer {
-				identifier: entry.asset_id,
-				instance: entry.asset_instance_id,
-				from: owner,
-				to: entry.new_asset_owner,
-			});
+			Self::instance_transfer(
+				entry.asset_id,
+				entry.asset_instance_id,
+				instance,
+				entry.new_asset_owner,
+			)?;
 
 			Ok(())
 		}
@@ -950,10 +971,214 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Proposes the transfer of an asset instance to a new owner.
+		///
+		/// Unlike `transfer`, ownership does not change until the recipient
+		/// calls `accept_transfer`. The proposal lapses after
+		/// `TransferIntentLifetime` blocks. Proposing again replaces any
+		/// earlier proposal for the same instance.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the current owner.
+		/// - `entry`: The transfer details, including the asset ID, instance ID, current owner, and
+		///   proposed new owner.
+		///
+		/// # Errors
+		/// - `AssetIdNotFound`: If the asset with the given ID does not exist.
+		/// - `AssetInstanceNotFound`: If the asset instance with the given ID does not exist.
+		/// - `UnauthorizedOperation`: If the caller or the specified current owner is not the owner
+		///   of the asset instance.
+		/// - `AssetNotActive`: If the asset is not active.
+		/// - `InstanceNotActive`: If the asset instance is not active.
+		///
+		/// # Events
+		/// - `Event::TransferProposed`: Emitted when the transfer has been proposed.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_transfer())]
+		pub fn propose_transfer(
+			origin: OriginFor<T>,
+			entry: AssetTransferEntryOf<T>,
+		) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let asset = <Assets<T>>::get(&entry.asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let instance = <Issuance<T>>::get(&entry.asset_id, &entry.asset_instance_id)
+				.ok_or(Error::<T>::AssetInstanceNotFound)?;
+
+			ensure!(instance.asset_instance_owner == owner, Error::<T>::UnauthorizedOperation);
+			ensure!(
+				instance.asset_instance_owner == entry.asset_owner,
+				Error::<T>::UnauthorizedOperation
+			);
+
+			ensure!(AssetStatusOf::ACTIVE == asset.asset_status, Error::<T>::AssetNotActive);
+
+			ensure!(
+				AssetStatusOf::ACTIVE == instance.asset_instance_status,
+				Error::<T>::InstanceNotActive
+			);
+
+			let expires_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::TransferIntentLifetime::get());
+
+			<TransferIntents<T>>::insert(
+				&entry.asset_id,
+				&entry.asset_instance_id,
+				AssetTransferIntentOf::<T> {
+					from: owner.clone(),
+					to: entry.new_asset_owner.clone(),
+					expires_at,
+				},
+			);
+
+			Self::deposit_event(Event::TransferProposed {
+				identifier: entry.asset_id,
+				instance: entry.asset_instance_id,
+				from: owner,
+				to: entry.new_asset_owner,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
+		/// Accepts a proposed transfer of an asset instance.
+		///
+		/// The caller must be the recipient named in the proposal, and the
+		/// proposal must still be open. The instance must still belong to
+		/// the owner that proposed the transfer and both the asset and the
+		/// instance must be active.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the recipient.
+		/// - `asset_id`: The identifier of the asset.
+		/// - `instance_id`: The identifier of the asset instance.
+		///
+		/// # Errors
+		/// - `TransferIntentNotFound`: If no transfer has been proposed for the instance.
+		/// - `UnauthorizedOperation`: If the caller is not the proposed recipient, or the instance
+		///   changed hands since the proposal.
+		/// - `TransferIntentExpired`: If the proposal has lapsed.
+		/// - `AssetNotActive`: If the asset is not active.
+		/// - `InstanceNotActive`: If the asset instance is not active.
+		///
+		/// # Events
+		/// - `Event::Transfer`: Emitted when the asset instance has changed owner.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_transfer())]
+		pub fn accept_transfer(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf,
+			instance_id: AssetInstanceIdOf,
+		) -> DispatchResult {
+			let recipient = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let intent = <TransferIntents<T>>::get(&asset_id, &instance_id)
+				.ok_or(Error::<T>::TransferIntentNotFound)?;
+
+			ensure!(intent.to == recipient, Error::<T>::UnauthorizedOperation);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= intent.expires_at,
+				Error::<T>::TransferIntentExpired
+			);
+
+			let asset = <Assets<T>>::get(&asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let instance = <Issuance<T>>::get(&asset_id, &instance_id)
+				.ok_or(Error::<T>::AssetInstanceNotFound)?;
+
+			ensure!(
+				instance.asset_instance_owner == intent.from,
+				Error::<T>::UnauthorizedOperation
+			);
+
+			ensure!(AssetStatusOf::ACTIVE == asset.asset_status, Error::<T>::AssetNotActive);
+
+			ensure!(
+				AssetStatusOf::ACTIVE == instance.asset_instance_status,
+				Error::<T>::InstanceNotActive
+			);
+
+			<TransferIntents<T>>::remove(&asset_id, &instance_id);
+
+			Self::instance_transfer(asset_id, instance_id, instance, recipient)?;
+
+			Ok(())
+		}
+
+		/// Cancels a proposed transfer of an asset instance.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the owner that proposed the
+		///   transfer.
+		/// - `asset_id`: The identifier of the asset.
+		/// - `instance_id`: The identifier of the asset instance.
+		///
+		/// # Errors
+		/// - `TransferIntentNotFound`: If no transfer has been proposed for the instance.
+		/// - `UnauthorizedOperation`: If the caller did not propose the transfer.
+		///
+		/// # Events
+		/// - `Event::TransferCancelled`: Emitted when the proposal has been withdrawn.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_transfer())]
+		pub fn cancel_transfer(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf,
+			instance_id: AssetInstanceIdOf,
+		) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let intent = <TransferIntents<T>>::get(&asset_id, &instance_id)
+				.ok_or(Error::<T>::TransferIntentNotFound)?;
+
+			ensure!(intent.from == owner, Error::<T>::UnauthorizedOperation);
+
+			<TransferIntents<T>>::remove(&asset_id, &instance_id);
+
+			Self::deposit_event(Event::TransferCancelled {
+				identifier: asset_id,
+				instance: instance_id,
+			});
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Moves an asset instance to a new owner, records the activity and
+	/// emits the `Transfer` event. Callers are responsible for all checks.
+	fn instance_transfer(
+		asset_id: AssetIdOf,
+		instance_id: AssetInstanceIdOf,
+		instance: AssetDistributionEntryOf<T>,
+		new_owner: AssetCreatorOf<T>,
+	) -> Result<(), Error<T>> {
+		let from = instance.asset_instance_owner.clone();
+		let block_number = frame_system::Pallet::<T>::block_number();
+
+		<Issuance<T>>::insert(
+			&asset_id,
+			&instance_id,
+			AssetDistributionEntryOf::<T> {
+				asset_instance_owner: new_owner.clone(),
+				created_at: block_number,
+				..instance
+			},
+		);
+
+		Self::update_activity(&instance_id, CallTypeOf::Transfer)?;
+		Self::deposit_event(Event::Transfer {
+			identifier: asset_id,
+			instance: instance_id,
+			from,
+			to: new_owner,
+		});
+
+		Ok(())
+	}
+
 	pub fn get_distributed_qty(asset_id: &AssetIdOf) -> u32 {
 		<Distribution<T>>::get(asset_id)
 			.map(|bounded_vec| bounded_vec.len() as u32)
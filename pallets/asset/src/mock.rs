@@ -19,7 +19,7 @@
 use super::*;
 use crate as pallet_asset;
 use cord_utilities::mock::{mock_origin, SubjectId};
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{derive_impl, parameter_types, traits::ConstU32};
 use pallet_chain_space::IsPermissioned;
 
 use frame_system::EnsureRoot;
@@ -66,6 +66,7 @@ impl mock_origin::Config for Test {
 parameter_types! {
 	pub const MaxEncodedValueLength: u32 = 1_024;
 	pub const MaxAssetDistribution: u32 = u32::MAX;
+	pub const TransferIntentLifetime: u64 = 10;
 }
 
 impl Config for Test {
@@ -74,6 +75,7 @@ impl Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type MaxEncodedValueLength = MaxEncodedValueLength;
 	type MaxAssetDistribution = MaxAssetDistribution;
+	type TransferIntentLifetime = TransferIntentLifetime;
 	type WeightInfo = ();
 }
 
@@ -95,6 +97,11 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type CapacityAlertThresholds = ();
+	type MaxSpaceAnchorsPerBlock = ConstU32<0>;
+	type MaxDelegateAnchorsPerBlock = ConstU32<0>;
+	type MaxExpiryScanPerBlock = ConstU32<0>;
+	type MaxDelegateReassignments = ConstU32<0>;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type WeightInfo = ();
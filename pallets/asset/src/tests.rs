@@ -2916,7 +2916,8 @@ fn asset_vc_create_should_fail_for_invalid_asset_value() {
 
 	let asset_qty = 0; // Invalid quantity
 
-	let digest = <Test as frame_system::Config>::Hashing::hash(&[&asset_qty.encode()[..]].concat()[..]);
+	let digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&asset_qty.encode()[..]].concat()[..]);
 
 	new_test_ext().execute_with(|| {
 		assert_ok!(Space::create(
@@ -2938,3 +2939,152 @@ fn asset_vc_create_should_fail_for_invalid_asset_value() {
 	});
 }
 
+#[test]
+fn asset_transfer_should_wait_for_recipient_acceptance() {
+	let creator = DID_00;
+	let new_owner = SubjectId(AccountId32::new([2u8; 32]));
+
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let asset_desc = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+	let asset_tag = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+	let asset_meta = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+	let asset_qty = 10;
+	let asset_value = 10;
+	let asset_type = AssetTypeOf::MF;
+
+	let entry = AssetInputEntryOf::<Test> {
+		asset_desc,
+		asset_qty,
+		asset_type,
+		asset_value,
+		asset_tag,
+		asset_meta,
+	};
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[&entry.encode()[..]].concat()[..]);
+
+	let issue_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let asset_id: Ss58Identifier = generate_asset_id::<Test>(&issue_id_digest);
+
+	let issue_entry = AssetIssuanceEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_owner: creator.clone(),
+		asset_issuance_qty: Some(10),
+	};
+
+	let issue_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&issue_entry.encode()[..]].concat()[..]);
+
+	let instance_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&creator.encode()[..],
+			&space_id.encode()[..],
+			&creator.encode()[..],
+			&issue_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+
+	let instance_id = generate_asset_instance_id::<Test>(&instance_id_digest);
+
+	let transfer_entry = AssetTransferEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: creator.clone(),
+		new_asset_owner: new_owner.clone(),
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Asset::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entry,
+			digest,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Asset::issue(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			issue_entry.clone(),
+			issue_entry_digest,
+			authorization_id
+		));
+
+		assert_ok!(Asset::propose_transfer(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			transfer_entry.clone(),
+		));
+
+		// Ownership does not change until the recipient accepts
+		assert_eq!(
+			Issuance::<Test>::get(&asset_id, &instance_id).unwrap().asset_instance_owner,
+			creator
+		);
+
+		assert_err!(
+			Asset::accept_transfer(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				asset_id.clone(),
+				instance_id.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+
+		// The proposal lapses after the configured lifetime
+		System::set_block_number(1 + TransferIntentLifetime::get() + 1);
+		assert_err!(
+			Asset::accept_transfer(
+				DoubleOrigin(author.clone(), new_owner.clone()).into(),
+				asset_id.clone(),
+				instance_id.clone(),
+			),
+			Error::<Test>::TransferIntentExpired
+		);
+
+		assert_ok!(Asset::propose_transfer(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			transfer_entry,
+		));
+		assert_ok!(Asset::accept_transfer(
+			DoubleOrigin(author.clone(), new_owner.clone()).into(),
+			asset_id.clone(),
+			instance_id.clone(),
+		));
+
+		assert_eq!(
+			Issuance::<Test>::get(&asset_id, &instance_id).unwrap().asset_instance_owner,
+			new_owner
+		);
+		assert!(TransferIntents::<Test>::get(&asset_id, &instance_id).is_none());
+
+		assert_err!(
+			Asset::cancel_transfer(DoubleOrigin(author, creator).into(), asset_id, instance_id,),
+			Error::<Test>::TransferIntentNotFound
+		);
+	});
+}
@@ -184,3 +184,16 @@ pub struct AssetTransferEntry<AssetIdOf, AssetInstanceIdOf, AssetCreatorOf> {
 	/// new asset owner
 	pub new_asset_owner: AssetCreatorOf,
 }
+
+/// A transfer of an asset instance waiting for the recipient to accept it.
+#[derive(
+	Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen,
+)]
+pub struct AssetTransferIntent<AssetCreatorOf, BlockNumber> {
+	/// current asset owner
+	pub from: AssetCreatorOf,
+	/// proposed new asset owner
+	pub to: AssetCreatorOf,
+	/// last block in which the transfer can be accepted
+	pub expires_at: BlockNumber,
+}
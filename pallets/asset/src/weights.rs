@@ -53,6 +53,9 @@ pub trait WeightInfo {
 	fn issue() -> Weight;
 	fn transfer() -> Weight;
 	fn status_change() -> Weight;
+	fn propose_transfer() -> Weight;
+	fn accept_transfer() -> Weight;
+	fn cancel_transfer() -> Weight;
 }
 
 /// Weights for `pallet_asset` using the CORD node and recommended hardware.
@@ -130,6 +133,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn propose_transfer() -> Weight {
+		Weight::from_parts(22_106_000, 6789)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn accept_transfer() -> Weight {
+		Weight::from_parts(31_478_000, 6799)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn cancel_transfer() -> Weight {
+		Weight::from_parts(13_022_000, 3643)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -206,4 +230,25 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn propose_transfer() -> Weight {
+		Weight::from_parts(22_106_000, 6789)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn accept_transfer() -> Weight {
+		Weight::from_parts(31_478_000, 6799)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn cancel_transfer() -> Weight {
+		Weight::from_parts(13_022_000, 3643)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
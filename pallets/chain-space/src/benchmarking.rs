@@ -59,7 +59,8 @@ benchmarks! {
 			Pallet::<T>::approve(chain_space_origin, space_id.clone(), capacity ).expect("Approval should not fail.");
 
 			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
-		}: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_did.clone(), authorization_id  )
+			let valid_until = Some(100u32.into());
+		}: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_did.clone(), authorization_id, valid_until )
 		verify {
 			assert_last_event::<T>(Event::Authorization { space: space_id, authorization: delegate_authorization_id, delegate: delegate_did,  }.into());
 		}
@@ -132,6 +133,40 @@ benchmarks! {
 			 assert_last_event::<T>(Event::Authorization { space: space_id, authorization: delegate_authorization_id, delegate: delegate_did,  }.into());
 		 }
 
+		add_revoke_delegate {
+			 let caller: T::AccountId = account("caller", 0, SEED);
+			 let did: T::SpaceCreatorId = account("did", 0, SEED);
+			 let delegate_did: T::SpaceCreatorId = account("did", 1, SEED);
+			 let space = [2u8; 256].to_vec();
+			 let capacity = 5u64;
+
+			 let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			 let id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			 let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			 let delegate_id_digest = T::Hashing::hash(
+				 &[&space_id.encode()[..], &delegate_did.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let delegate_authorization_id = generate_authorization_id::<T>(&delegate_id_digest);
+
+			 let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+			 let root_origin = RawOrigin::Root.into();
+
+			 Pallet::<T>::create(origin.clone(), space_digest )?;
+			 Pallet::<T>::approve(root_origin, space_id.clone(), capacity )?;
+
+		 }: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_did.clone(), authorization_id  )
+		 verify {
+			 assert_last_event::<T>(Event::Authorization { space: space_id, authorization: delegate_authorization_id, delegate: delegate_did,  }.into());
+		 }
+
 		remove_delegate {
 			 let caller: T::AccountId = account("caller", 0, SEED);
 			 let did: T::SpaceCreatorId = account("did", 0, SEED);
@@ -161,7 +196,7 @@ benchmarks! {
 
 			 Pallet::<T>::create(origin.clone(), space_digest )?;
 			 Pallet::<T>::approve(root_origin, space_id.clone(), capacity )?;
-			 Pallet::<T>::add_delegate(origin.clone(), space_id.clone(), delegate_did, authorization_id.clone() )?;
+			 Pallet::<T>::add_delegate(origin.clone(), space_id.clone(), delegate_did, authorization_id.clone(), None )?;
 
 		}: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_authorization_id.clone(), authorization_id)
 		verify {
@@ -419,6 +454,234 @@ benchmarks! {
 			 assert_last_event::<T>(Event::Create { space: subspace_id, creator: did, authorization: authorization_id }.into());
 		 }
 
+		 transfer_ownership {
+			 let caller: T::AccountId = account("caller", 0, SEED);
+			 let did: T::SpaceCreatorId = account("did", 0, SEED);
+			 let new_owner: T::SpaceCreatorId = account("did", 1, SEED);
+			 let space = [2u8; 256].to_vec();
+			 let capacity = 5u64;
+
+			 let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			 let id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			 let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_id.encode()[..], &new_owner.encode()[..], &new_owner.encode()[..]].concat()[..],
+			 );
+			 let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			 let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+
+			 Pallet::<T>::create(origin.clone(), space_digest )?;
+			 Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), capacity )?;
+
+		 }: _<T::RuntimeOrigin>(origin, space_id.clone(), new_owner.clone())
+		 verify {
+			 assert_last_event::<T>(Event::OwnershipTransferProposed { space: space_id, owner: did, proposed: new_owner }.into());
+		 }
+
+		 accept_ownership {
+			 let caller: T::AccountId = account("caller", 0, SEED);
+			 let did: T::SpaceCreatorId = account("did", 0, SEED);
+			 let new_owner: T::SpaceCreatorId = account("did", 1, SEED);
+			 let space = [2u8; 256].to_vec();
+			 let capacity = 5u64;
+
+			 let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			 let id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			 let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_id.encode()[..], &new_owner.encode()[..], &new_owner.encode()[..]].concat()[..],
+			 );
+			 let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			 let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+
+			 Pallet::<T>::create(origin.clone(), space_digest )?;
+			 Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), capacity )?;
+
+			 Pallet::<T>::transfer_ownership(origin, space_id.clone(), new_owner.clone())?;
+			 let new_owner_origin = <T as Config>::EnsureOrigin::generate_origin(caller, new_owner.clone());
+
+		 }: _<T::RuntimeOrigin>(new_owner_origin, space_id.clone())
+		 verify {
+			 assert_last_event::<T>(Event::OwnershipTransferred { space: space_id, from: did, to: new_owner, authorization: authorization_id }.into());
+		 }
+
+		 force_transfer_ownership {
+			 let caller: T::AccountId = account("caller", 0, SEED);
+			 let did: T::SpaceCreatorId = account("did", 0, SEED);
+			 let new_owner: T::SpaceCreatorId = account("did", 1, SEED);
+			 let space = [2u8; 256].to_vec();
+			 let capacity = 5u64;
+
+			 let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			 let id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			 let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_id.encode()[..], &new_owner.encode()[..], &new_owner.encode()[..]].concat()[..],
+			 );
+			 let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			 let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+
+			 Pallet::<T>::create(origin.clone(), space_digest )?;
+			 Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), capacity )?;
+
+		 }: _<T::RuntimeOrigin>(RawOrigin::Root.into(), space_id.clone(), new_owner.clone())
+		 verify {
+			 assert_last_event::<T>(Event::OwnershipTransferred { space: space_id, from: did, to: new_owner, authorization: authorization_id }.into());
+		 }
+
+		freeze {
+			 let caller: T::AccountId = account("caller", 0, SEED);
+			 let did: T::SpaceCreatorId = account("did", 0, SEED);
+			 let space = [2u8; 256].to_vec();
+			 let capacity = 5u64;
+
+			 let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			 let id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			 let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			 let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+
+			 Pallet::<T>::create(origin.clone(), space_digest )?;
+			 Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), capacity )?;
+
+		 }: _<T::RuntimeOrigin>(origin, space_id.clone(), authorization_id )
+		 verify {
+			 assert_last_event::<T>(Event::Freeze { space: space_id, authority: FreezeAuthority::Admin }.into());
+		 }
+
+		unfreeze {
+			 let caller: T::AccountId = account("caller", 0, SEED);
+			 let did: T::SpaceCreatorId = account("did", 0, SEED);
+			 let space = [2u8; 256].to_vec();
+			 let capacity = 5u64;
+
+			 let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			 let id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			 let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			 let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+
+			 Pallet::<T>::create(origin.clone(), space_digest )?;
+			 Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), capacity )?;
+			 Pallet::<T>::freeze(origin.clone(), space_id.clone(), authorization_id.clone() )?;
+
+		 }: _<T::RuntimeOrigin>(origin, space_id.clone(), authorization_id )
+		 verify {
+			 assert_last_event::<T>(Event::Unfreeze { space: space_id, authority: FreezeAuthority::Admin }.into());
+		 }
+
+		force_freeze {
+			 let caller: T::AccountId = account("caller", 0, SEED);
+			 let did: T::SpaceCreatorId = account("did", 0, SEED);
+			 let space = [2u8; 256].to_vec();
+			 let capacity = 5u64;
+
+			 let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			 let id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			 let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			 let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+
+			 Pallet::<T>::create(origin.clone(), space_digest )?;
+			 Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), capacity )?;
+
+		 }: _<T::RuntimeOrigin>(RawOrigin::Root.into(), space_id.clone())
+		 verify {
+			 assert_last_event::<T>(Event::Freeze { space: space_id, authority: FreezeAuthority::Council }.into());
+		 }
+
+		force_unfreeze {
+			 let caller: T::AccountId = account("caller", 0, SEED);
+			 let did: T::SpaceCreatorId = account("did", 0, SEED);
+			 let space = [2u8; 256].to_vec();
+			 let capacity = 5u64;
+
+			 let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			 let id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			 let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			 let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+
+			 Pallet::<T>::create(origin.clone(), space_digest )?;
+			 Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), capacity )?;
+			 Pallet::<T>::force_freeze(RawOrigin::Root.into(), space_id.clone())?;
+
+		 }: _<T::RuntimeOrigin>(RawOrigin::Root.into(), space_id.clone())
+		 verify {
+			 assert_last_event::<T>(Event::Unfreeze { space: space_id, authority: FreezeAuthority::Council }.into());
+		 }
+
+		reassign_delegate {
+			let n in 0 .. T::MaxDelegateReassignments::get();
+
+			let old: T::SpaceCreatorId = account("did", 0, SEED);
+			let new: T::SpaceCreatorId = account("did", 1, SEED);
+			let delegator: T::SpaceCreatorId = account("did", 2, SEED);
+
+			for i in 0 .. n {
+				let space_digest = <T as frame_system::Config>::Hashing::hash(&i.encode()[..]);
+				let space_id: SpaceIdOf = generate_space_id::<T>(&space_digest);
+				let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+					&[&space_id.encode()[..], &old.encode()[..], &delegator.encode()[..]].concat()[..],
+				);
+				let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+				<Authorizations<T>>::insert(
+					&authorization_id,
+					SpaceAuthorizationOf::<T> {
+						space_id,
+						delegate: old.clone(),
+						permissions: Permissions::ASSERT,
+						delegator: delegator.clone(),
+					},
+				);
+			}
+
+		}: _<T::RuntimeOrigin>(RawOrigin::Root.into(), old.clone(), new.clone(), None)
+		verify {
+			assert_last_event::<T>(Event::DelegateReassigned { old, new, reassigned: n, complete: true }.into());
+		}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 
 }
@@ -45,7 +45,13 @@
 //! - `add_admin_delegate`: Adds an admin delegate to a space, granting them administrative
 //!   permissions.
 //! - `add_audit_delegate`: Adds an audit delegate to a space, granting them audit permissions.
+//! - `add_revoke_delegate`: Adds a delegate that can only revoke and restore records in a space.
 //! - `remove_delegate`: Removes a delegate from a space, revoking their permissions.
+//! - `transfer_ownership`: Proposes a new owner for a space, who takes over with
+//!   `accept_ownership`.
+//! - `force_transfer_ownership`: Reassigns the owner of a space through the `ChainSpaceOrigin`.
+//! - `reassign_delegate`: Moves the authorizations of a compromised identity to a new one through
+//!   the `ChainSpaceOrigin`, in chunks resumed across calls.
 //!
 //! ## Permissions
 //!
@@ -97,7 +103,7 @@ pub mod benchmarking;
 #[cfg(test)]
 mod tests;
 
-use frame_support::{ensure, storage::types::StorageMap, BoundedVec};
+use frame_support::{ensure, storage::types::StorageMap, weights::Weight, BoundedVec};
 pub mod types;
 pub use crate::{pallet::*, types::*, weights::WeightInfo};
 use codec::Encode;
@@ -105,7 +111,11 @@ use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::traits::{Hash, UniqueSaturatedInto};
+use sp_runtime::{
+	traits::{Hash, UniqueSaturatedInto},
+	PerThing, Percent,
+};
+use sp_std::vec::Vec;
 
 /// Type of a CORD account.
 pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -122,6 +132,20 @@ pub type SpaceDetailsOf<T> = SpaceDetails<SpaceCodeOf<T>, SpaceCreatorOf<T>, Sta
 
 pub type SpaceAuthorizationOf<T> = SpaceAuthorization<SpaceIdOf, SpaceCreatorOf<T>, Permissions>;
 
+pub type SpaceDelegateInfoOf<T> = SpaceDelegateInfo<
+	AuthorizationIdOf,
+	SpaceCreatorOf<T>,
+	Permissions,
+	frame_system::pallet_prelude::BlockNumberFor<T>,
+>;
+
+/// Usage summary of a space.
+pub type SpaceUsageOf = SpaceUsage<StatusOf>;
+
+/// Progress of a delegate reassignment.
+pub type DelegateReassignmentOf<T> =
+	DelegateReassignment<SpaceCreatorOf<T>, SpaceIdOf, AuthorizationIdOf>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -142,13 +166,38 @@ pub mod pallet {
 			Success = <Self as Config>::OriginSuccess,
 		>;
 		type OriginSuccess: CallSources<AccountIdOf<Self>, SpaceCreatorOf<Self>>;
-		type SpaceCreatorId: Parameter + MaxEncodedLen;
+		type SpaceCreatorId: Parameter + MaxEncodedLen + MaybeSerializeDeserialize;
 		type ChainSpaceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		type NetworkPermission: IsPermissioned;
 
 		#[pallet::constant]
 		type MaxSpaceDelegates: Get<u32>;
 
+		/// Usage levels, as a share of the approved transaction capacity, at
+		/// which `CapacityThresholdReached` is emitted for a space.
+		#[pallet::constant]
+		type CapacityAlertThresholds: Get<Vec<Percent>>;
+
+		/// Maximum number of anchoring calls a single space may record in one
+		/// block. `0` disables the limit.
+		#[pallet::constant]
+		type MaxSpaceAnchorsPerBlock: Get<u32>;
+
+		/// Maximum number of anchoring calls a single delegate may record in
+		/// one block, across all spaces. `0` disables the limit.
+		#[pallet::constant]
+		type MaxDelegateAnchorsPerBlock: Get<u32>;
+
+		/// Maximum number of time-bound authorizations inspected by the
+		/// expiry sweep in a single block.
+		#[pallet::constant]
+		type MaxExpiryScanPerBlock: Get<u32>;
+
+		/// Maximum number of authorizations scanned by a single delegate
+		/// reassignment call.
+		#[pallet::constant]
+		type MaxDelegateReassignments: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -158,7 +207,15 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			Self::clear_anchor_counts()
+		}
+
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::expire_authorizations(n, remaining_weight)
+		}
+	}
 
 	/// Space information stored on chain.
 	/// It maps from an identifier to its details.
@@ -183,6 +240,67 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Expiry of time-bound space authorizations.
+	/// It maps from an authorization identifier to the last block at which
+	/// the authorization can be used.
+	#[pallet::storage]
+	pub type AuthorizationExpiry<T: Config> =
+		StorageMap<_, Blake2_128Concat, AuthorizationIdOf, BlockNumberFor<T>, OptionQuery>;
+
+	/// Last authorization inspected by the expiry sweep. The next sweep
+	/// resumes after it; cleared once the sweep has covered every entry.
+	#[pallet::storage]
+	pub type ExpirySweepCursor<T> = StorageValue<_, AuthorizationIdOf, OptionQuery>;
+
+	/// Pending space ownership transfers.
+	/// It maps from a space identifier to the proposed new owner.
+	#[pallet::storage]
+	pub type PendingOwnership<T: Config> =
+		StorageMap<_, Blake2_128Concat, SpaceIdOf, SpaceCreatorOf<T>, OptionQuery>;
+
+	/// Number of anchoring calls recorded against a space in the current
+	/// block. Cleared at the start of every block.
+	#[pallet::storage]
+	pub type SpaceAnchorsInBlock<T: Config> =
+		StorageMap<_, Blake2_128Concat, SpaceIdOf, u32, ValueQuery>;
+
+	/// Number of anchoring calls made by a delegate in the current block.
+	/// Cleared at the start of every block.
+	#[pallet::storage]
+	pub type DelegateAnchorsInBlock<T: Config> =
+		StorageMap<_, Blake2_128Concat, SpaceCreatorOf<T>, u32, ValueQuery>;
+
+	/// Spaces in which anchoring is temporarily blocked.
+	/// It maps from a space identifier to the authority that froze it.
+	#[pallet::storage]
+	pub type FrozenSpaces<T> =
+		StorageMap<_, Blake2_128Concat, SpaceIdOf, FreezeAuthority, OptionQuery>;
+
+	/// Delegate reassignments in progress.
+	/// It maps from the identity authorizations are moved away from to the
+	/// progress of the reassignment.
+	#[pallet::storage]
+	pub type DelegateReassignments<T: Config> =
+		StorageMap<_, Blake2_128Concat, SpaceCreatorOf<T>, DelegateReassignmentOf<T>, OptionQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Spaces anchored and approved at genesis, as `(code, creator,
+		/// transaction capacity)`.
+		pub spaces: Vec<(SpaceCodeOf<T>, SpaceCreatorOf<T>, u64)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (code, creator, txn_capacity) in &self.spaces {
+				Pallet::<T>::space_creation(*code, creator.clone(), true, *txn_capacity)
+					.expect("Genesis spaces must be unique and valid");
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -223,6 +341,42 @@ pub mod pallet {
 		/// A chain space usage has been reset.
 		/// \[space identifier \]
 		ResetUsage { space: SpaceIdOf },
+		/// A space usage has crossed one of the configured capacity
+		/// thresholds.
+		/// \[space identifier, threshold, usage, capacity\]
+		CapacityThresholdReached { space: SpaceIdOf, threshold: Percent, usage: u64, capacity: u64 },
+		/// A space ownership transfer has been proposed.
+		/// \[space identifier, current owner, proposed owner\]
+		OwnershipTransferProposed {
+			space: SpaceIdOf,
+			owner: SpaceCreatorOf<T>,
+			proposed: SpaceCreatorOf<T>,
+		},
+		/// A space ownership has been transferred.
+		/// \[space identifier, previous owner, new owner, authorization\]
+		OwnershipTransferred {
+			space: SpaceIdOf,
+			from: SpaceCreatorOf<T>,
+			to: SpaceCreatorOf<T>,
+			authorization: AuthorizationIdOf,
+		},
+		/// A space has been frozen.
+		/// \[space identifier, authority\]
+		Freeze { space: SpaceIdOf, authority: FreezeAuthority },
+		/// A space has been unfrozen.
+		/// \[space identifier, authority\]
+		Unfreeze { space: SpaceIdOf, authority: FreezeAuthority },
+		/// A time-bound space authorization has expired and been removed.
+		/// \[space identifier, authorization\]
+		Expired { space: SpaceIdOf, authorization: AuthorizationIdOf },
+		/// The authorizations of an identity have been moved to another one.
+		/// \[old delegate, new delegate, reassigned count, complete\]
+		DelegateReassigned {
+			old: SpaceCreatorOf<T>,
+			new: SpaceCreatorOf<T>,
+			reassigned: u32,
+			complete: bool,
+		},
 	}
 
 	#[pallet::error]
@@ -266,6 +420,26 @@ pub mod pallet {
 		CapacityValueMissing,
 		/// Type capacity overflow
 		TypeCapacityOverflow,
+		/// The authorization is past its validity period
+		AuthorizationExpired,
+		/// The validity period must end after the current block
+		InvalidExpiry,
+		/// No ownership transfer is pending for the space
+		NoPendingOwnershipTransfer,
+		/// The space is already owned by the given identity
+		AlreadySpaceOwner,
+		/// The space or delegate reached its anchoring limit for this block
+		OverRateLimit,
+		/// The space is frozen and does not accept new records
+		Frozen,
+		/// The space is already frozen
+		AlreadyFrozen,
+		/// The space is not frozen
+		NotFrozen,
+		/// Authorizations cannot be reassigned to the identity holding them
+		InvalidReassignment,
+		/// A reassignment with a different target or space is in progress
+		ReassignmentInProgress,
 	}
 
 	#[pallet::call]
@@ -280,11 +454,17 @@ pub mod pallet {
 		/// `ASSERT` permission using the `space_delegate_addition`
 		/// internal function.
 		///
+		/// A delegation can be limited in time with `valid_until`. Once the
+		/// given block has passed, the authorization fails verification
+		/// without having to be removed, which suits short-term contributors.
+		///
 		/// # Parameters
 		/// - `origin`: The origin of the call, which must be signed by an admin of the space.
 		/// - `space_id`: The identifier of the space to which the delegate is being added.
 		/// - `delegate`: The identifier of the delegate being added to the space.
 		/// - `authorization`: The authorization ID used to validate the addition.
+		/// - `valid_until`: The last block at which the delegation can be used, or `None` for a
+		///   delegation that does not expire.
 		///
 		/// # Returns
 		/// Returns `Ok(())` if the delegate was successfully added with
@@ -293,6 +473,7 @@ pub mod pallet {
 		///
 		/// # Errors
 		/// - `UnauthorizedOperation`: If the caller is not an admin of the space.
+		/// - `InvalidExpiry`: If `valid_until` is not after the current block.
 		/// - Propagates errors from `space_delegate_addition` if it fails.
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_delegate())]
@@ -301,14 +482,27 @@ pub mod pallet {
 			space_id: SpaceIdOf,
 			delegate: SpaceCreatorOf<T>,
 			authorization: AuthorizationIdOf,
+			valid_until: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
 			let creator = T::EnsureOrigin::ensure_origin(origin)?.subject();
 			let auth_space_id =
 				Self::ensure_authorization_delegator_origin(&authorization, &creator)?;
 			ensure!(auth_space_id == space_id, Error::<T>::UnauthorizedOperation);
 
+			if let Some(block) = valid_until {
+				ensure!(
+					block > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidExpiry
+				);
+			}
+
 			let permissions = Permissions::ASSERT;
-			Self::space_delegate_addition(auth_space_id, delegate, creator, permissions)?;
+			let delegate_authorization_id =
+				Self::space_delegate_addition(auth_space_id, delegate, creator, permissions)?;
+
+			if let Some(block) = valid_until {
+				<AuthorizationExpiry<T>>::insert(&delegate_authorization_id, block);
+			}
 
 			Ok(())
 		}
@@ -396,6 +590,47 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Adds a revocation delegate to a space.
+		///
+		/// The `REVOKE` permission allows the delegate to revoke and restore
+		/// records anchored in the space without being able to anchor new
+		/// ones. It is meant for team members who only review or withdraw
+		/// issued records. Only an admin of the space can add a revocation
+		/// delegate.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by an existing admin of the
+		///   space.
+		/// - `space_id`: The identifier of the space to which the delegate is being added.
+		/// - `delegate`: The identifier of the delegate being granted revoke permissions.
+		/// - `authorization`: The authorization ID used to validate the addition.
+		///
+		/// # Returns
+		/// Returns `Ok(())` if the revocation delegate was successfully added,
+		/// or an `Err` with an appropriate error if the operation fails.
+		///
+		/// # Errors
+		/// - `UnauthorizedOperation`: If the caller is not an admin of the space.
+		/// - Propagates errors from `space_delegate_addition` if it fails.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_revoke_delegate())]
+		pub fn add_revoke_delegate(
+			origin: OriginFor<T>,
+			space_id: SpaceIdOf,
+			delegate: SpaceCreatorOf<T>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			let auth_space_id = Self::ensure_authorization_admin_origin(&authorization, &creator)?;
+
+			ensure!(auth_space_id == space_id, Error::<T>::UnauthorizedOperation);
+
+			let permissions = Permissions::REVOKE;
+			Self::space_delegate_addition(auth_space_id, delegate, creator, permissions)?;
+
+			Ok(())
+		}
+
 		/// Removes a delegate from a specified space.
 		///
 		/// This function will remove an existing delegate from a space, given
@@ -455,6 +690,7 @@ pub mod pallet {
 				Delegates::<T>::insert(&space_id, delegates);
 
 				Authorizations::<T>::remove(&remove_authorization);
+				AuthorizationExpiry::<T>::remove(&remove_authorization);
 
 				Self::decrement_usage(&space_id).map_err(Error::<T>::from)?;
 
@@ -510,68 +746,9 @@ pub mod pallet {
 		pub fn create(origin: OriginFor<T>, space_code: SpaceCodeOf<T>) -> DispatchResult {
 			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
-			// Id Digest = concat (H(<scale_encoded_registry_input>,
-			// <scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&space_code.encode()[..], &creator.encode()[..]].concat()[..],
-			);
-
-			let identifier =
-				Ss58Identifier::create_identifier(&id_digest.encode()[..], IdentifierType::Space)
-					.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			ensure!(!<Spaces<T>>::contains_key(&identifier), Error::<T>::SpaceAlreadyAnchored);
-
-			// Construct the authorization_id from the provided parameters.
-			// Id Digest = concat (H(<scale_encoded_space_identifier>,
-			// <scale_encoded_creator_identifier> ))
-			let auth_id_digest = T::Hashing::hash(
-				&[&identifier.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()
-					[..],
-			);
-
-			let authorization_id = Ss58Identifier::create_identifier(
-				&auth_id_digest.encode(),
-				IdentifierType::Authorization,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			let mut delegates: BoundedVec<SpaceCreatorOf<T>, T::MaxSpaceDelegates> =
-				BoundedVec::default();
-			delegates
-				.try_push(creator.clone())
-				.map_err(|_| Error::<T>::SpaceDelegatesLimitExceeded)?;
-
-			Delegates::<T>::insert(&identifier, delegates);
-
-			Authorizations::<T>::insert(
-				&authorization_id,
-				SpaceAuthorizationOf::<T> {
-					space_id: identifier.clone(),
-					delegate: creator.clone(),
-					permissions: Permissions::all(),
-					delegator: creator.clone(),
-				},
-			);
-
 			let approved = !T::NetworkPermission::is_permissioned();
-
-			<Spaces<T>>::insert(
-				&identifier,
-				SpaceDetailsOf::<T> {
-					code: space_code,
-					creator: creator.clone(),
-					txn_capacity: 0,
-					txn_reserve: 0,
-					txn_count: 0,
-					approved,
-					archive: false,
-					parent: identifier.clone(),
-				},
-			);
-
-			Self::update_activity(&identifier, IdentifierTypeOf::ChainSpace, CallTypeOf::Genesis)
-				.map_err(Error::<T>::from)?;
+			let (identifier, authorization_id) =
+				Self::space_creation(space_code, creator.clone(), approved, 0)?;
 
 			Self::deposit_event(Event::Create {
 				space: identifier,
@@ -1186,104 +1363,809 @@ pub mod pallet {
 
 			Ok(())
 		}
-	}
-}
-
-impl<T: Config> Pallet<T> {
-	/// Adds a delegate to a space with specified permissions.
-	///
-	/// This function will add a new delegate to a space, given the space's ID,
-	/// the delegate's information, and the required permissions. It constructs
-	/// an authorization ID based on the space ID, delegate, and creator,
-	/// ensuring that the delegate is not already added. It also checks that the
-	/// space is not archived, is approved, and has not exceeded its capacity.
-	fn space_delegate_addition(
-		space_id: SpaceIdOf,
-		delegate: SpaceCreatorOf<T>,
-		creator: SpaceCreatorOf<T>,
-		permissions: Permissions,
-	) -> Result<(), Error<T>> {
-		// Id Digest = concat (H(<scale_encoded_space_identifier>,
-		// <scale_encoded_creator_identifier>, <scale_encoded_delegate_identifier>))
-		let id_digest = T::Hashing::hash(
-			&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
-		);
 
-		let delegate_authorization_id =
-			Ss58Identifier::create_identifier(&id_digest.encode(), IdentifierType::Authorization)
-				.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+		/// Proposes a transfer of the space ownership to a new identity.
+		///
+		/// Ownership is moved in two steps: the current owner proposes the
+		/// new owner here and the transfer takes effect once the proposed
+		/// owner calls `accept_ownership`. Proposing again replaces any
+		/// pending proposal.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the space creator.
+		/// - `space_id`: The identifier of the space being transferred.
+		/// - `new_owner`: The identity proposed as the new owner.
+		///
+		/// # Errors
+		/// - `SpaceNotFound`: If the space does not exist.
+		/// - `ArchivedSpace`: If the space is archived.
+		/// - `UnauthorizedOperation`: If the caller is not the space creator.
+		/// - `AlreadySpaceOwner`: If the proposed owner already owns the space.
+		///
+		/// # Events
+		/// - `OwnershipTransferProposed`: Emitted when the proposal is recorded.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
+			origin: OriginFor<T>,
+			space_id: SpaceIdOf,
+			new_owner: SpaceCreatorOf<T>,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
-		ensure!(
-			!Authorizations::<T>::contains_key(&delegate_authorization_id),
-			Error::<T>::DelegateAlreadyAdded
-		);
+			let space_details = Spaces::<T>::get(&space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
+			ensure!(space_details.creator == creator, Error::<T>::UnauthorizedOperation);
+			ensure!(space_details.creator != new_owner, Error::<T>::AlreadySpaceOwner);
 
-		let mut delegates = Delegates::<T>::get(&space_id);
-		delegates
-			.try_push(delegate.clone())
-			.map_err(|_| Error::<T>::SpaceDelegatesLimitExceeded)?;
-		Delegates::<T>::insert(&space_id, delegates);
+			<PendingOwnership<T>>::insert(&space_id, new_owner.clone());
 
-		Authorizations::<T>::insert(
-			&delegate_authorization_id,
-			SpaceAuthorizationOf::<T> {
-				space_id: space_id.clone(),
-				delegate: delegate.clone(),
-				permissions,
-				delegator: creator,
-			},
-		);
+			Self::deposit_event(Event::OwnershipTransferProposed {
+				space: space_id,
+				owner: creator,
+				proposed: new_owner,
+			});
 
-		Self::update_activity(&space_id, IdentifierTypeOf::Auth, CallTypeOf::Authorization)
-			.map_err(Error::<T>::from)?;
+			Ok(())
+		}
 
-		Self::deposit_event(Event::Authorization {
-			space: space_id,
-			authorization: delegate_authorization_id,
-			delegate,
-		});
+		/// Accepts a pending space ownership transfer.
+		///
+		/// The caller must be the identity proposed through
+		/// `transfer_ownership`. The previous owner's authorization is
+		/// removed and the new owner receives an authorization with all
+		/// permissions on the space.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the proposed owner.
+		/// - `space_id`: The identifier of the space being transferred.
+		///
+		/// # Errors
+		/// - `NoPendingOwnershipTransfer`: If no transfer is pending for the space.
+		/// - `UnauthorizedOperation`: If the caller is not the proposed owner.
+		/// - `SpaceNotFound`: If the space does not exist.
+		/// - `ArchivedSpace`: If the space is archived.
+		///
+		/// # Events
+		/// - `OwnershipTransferred`: Emitted when the new owner has taken over the space.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_ownership())]
+		pub fn accept_ownership(origin: OriginFor<T>, space_id: SpaceIdOf) -> DispatchResult {
+			let new_owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
-		Ok(())
-	}
+			let proposed = <PendingOwnership<T>>::get(&space_id)
+				.ok_or(Error::<T>::NoPendingOwnershipTransfer)?;
+			ensure!(proposed == new_owner, Error::<T>::UnauthorizedOperation);
 
-	/// Checks if a given entity is a delegate for the specified space.
-	///
-	/// This function retrieves the list of delegates for a space and determines
-	/// whether the specified delegate is among them. It is a read-only
-	/// operation and does not modify the state.
-	pub fn is_a_delegate(tx_id: &SpaceIdOf, delegate: SpaceCreatorOf<T>) -> bool {
-		<Delegates<T>>::get(tx_id).iter().any(|d| d == &delegate)
-	}
+			let space_details = Spaces::<T>::get(&space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
 
-	/// Verifies if a given delegate has a specific authorization.
-	///
-	/// This function checks if the provided delegate is associated with the
-	/// given authorization ID and has the 'ASSERT' permission.
-	pub fn ensure_authorization_origin(
-		authorization_id: &AuthorizationIdOf,
-		delegate: &SpaceCreatorOf<T>,
-	) -> Result<SpaceIdOf, Error<T>> {
-		let d =
-			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
+			Self::space_ownership_transfer(space_id, space_details, new_owner)?;
 
-		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
+			Ok(())
+		}
 
-		Self::increment_usage(&d.space_id)?;
+		/// Transfers the space ownership without the consent of the current
+		/// owner.
+		///
+		/// This is the governance path for owners that lost access to their
+		/// keys. Any pending proposal for the space is discarded.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be the `ChainSpaceOrigin`.
+		/// - `space_id`: The identifier of the space being transferred.
+		/// - `new_owner`: The identity that becomes the new owner.
+		///
+		/// # Errors
+		/// - `SpaceNotFound`: If the space does not exist.
+		/// - `AlreadySpaceOwner`: If the new owner already owns the space.
+		///
+		/// # Events
+		/// - `OwnershipTransferred`: Emitted when the new owner has taken over the space.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_transfer_ownership())]
+		pub fn force_transfer_ownership(
+			origin: OriginFor<T>,
+			space_id: SpaceIdOf,
+			new_owner: SpaceCreatorOf<T>,
+		) -> DispatchResult {
+			T::ChainSpaceOrigin::ensure_origin(origin)?;
 
-		Self::validate_space_for_transaction(&d.space_id)?;
+			let space_details = Spaces::<T>::get(&space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			ensure!(space_details.creator != new_owner, Error::<T>::AlreadySpaceOwner);
 
-		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
+			Self::space_ownership_transfer(space_id, space_details, new_owner)?;
 
-		Ok(d.space_id)
-	}
+			Ok(())
+		}
 
-	pub fn ensure_authorization_restore_origin(
-		authorization_id: &AuthorizationIdOf,
-		delegate: &SpaceCreatorOf<T>,
+		/// Freezes a space, blocking all anchoring and updates under it
+		/// until it is unfrozen.
+		///
+		/// Freezing is meant for incident response, for instance when the key
+		/// of a delegate is suspected to be compromised. Nothing is revoked:
+		/// records stay valid, and delegates with the `REVOKE` permission can
+		/// still revoke them. Calls that anchor or update records under the
+		/// space fail with `Frozen`.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by an admin of the space.
+		/// - `space_id`: The identifier of the space to freeze.
+		/// - `authorization`: The authorization ID used to validate the admin.
+		///
+		/// # Errors
+		/// - `UnauthorizedOperation`: If the caller is not an admin of the space.
+		/// - `SpaceNotFound`: If the space does not exist.
+		/// - `AlreadyFrozen`: If the space is already frozen.
+		///
+		/// # Events
+		/// - `Freeze`: Emitted when the space has been frozen.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::freeze())]
+		pub fn freeze(
+			origin: OriginFor<T>,
+			space_id: SpaceIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let auth_space_id = Self::ensure_authorization_admin_origin(&authorization, &creator)?;
+
+			ensure!(auth_space_id == space_id, Error::<T>::UnauthorizedOperation);
+
+			Self::space_freeze(space_id, FreezeAuthority::Admin)?;
+
+			Ok(())
+		}
+
+		/// Unfreezes a space that was frozen by one of its admins.
+		///
+		/// A space frozen by the council can only be unfrozen through
+		/// `force_unfreeze`.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by an admin of the space.
+		/// - `space_id`: The identifier of the space to unfreeze.
+		/// - `authorization`: The authorization ID used to validate the admin.
+		///
+		/// # Errors
+		/// - `UnauthorizedOperation`: If the caller is not an admin of the space, or the space was
+		///   frozen by the council.
+		/// - `NotFrozen`: If the space is not frozen.
+		///
+		/// # Events
+		/// - `Unfreeze`: Emitted when the space has been unfrozen.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unfreeze())]
+		pub fn unfreeze(
+			origin: OriginFor<T>,
+			space_id: SpaceIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let auth_space_id = Self::ensure_authorization_admin_origin(&authorization, &creator)?;
+
+			ensure!(auth_space_id == space_id, Error::<T>::UnauthorizedOperation);
+
+			Self::space_unfreeze(space_id, FreezeAuthority::Admin)?;
+
+			Ok(())
+		}
+
+		/// Freezes a space on behalf of the council. See `freeze`.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be the `ChainSpaceOrigin`.
+		/// - `space_id`: The identifier of the space to freeze.
+		///
+		/// # Errors
+		/// - `SpaceNotFound`: If the space does not exist.
+		/// - `AlreadyFrozen`: If the space is already frozen by the council.
+		///
+		/// # Events
+		/// - `Freeze`: Emitted when the space has been frozen.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_freeze())]
+		pub fn force_freeze(origin: OriginFor<T>, space_id: SpaceIdOf) -> DispatchResult {
+			T::ChainSpaceOrigin::ensure_origin(origin)?;
+
+			Self::space_freeze(space_id, FreezeAuthority::Council)?;
+
+			Ok(())
+		}
+
+		/// Unfreezes a space on behalf of the council, whoever froze it.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be the `ChainSpaceOrigin`.
+		/// - `space_id`: The identifier of the space to unfreeze.
+		///
+		/// # Errors
+		/// - `NotFrozen`: If the space is not frozen.
+		///
+		/// # Events
+		/// - `Unfreeze`: Emitted when the space has been unfrozen.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_unfreeze())]
+		pub fn force_unfreeze(origin: OriginFor<T>, space_id: SpaceIdOf) -> DispatchResult {
+			T::ChainSpaceOrigin::ensure_origin(origin)?;
+
+			Self::space_unfreeze(space_id, FreezeAuthority::Council)?;
+
+			Ok(())
+		}
+
+		/// Moves the space authorizations of an identity to a new identity,
+		/// for instance after the keys of the old identity have been
+		/// compromised.
+		///
+		/// Records are not controlled by individual accounts but by the
+		/// spaces they are anchored in, so moving the authorizations hands
+		/// every record the old identity could act on over to the new one.
+		/// Each authorization keeps its permissions, delegator and validity
+		/// period. The authorization a space owner received at creation is
+		/// moved through an ownership transfer.
+		///
+		/// Authorizations are scanned in storage order, up to
+		/// `MaxDelegateReassignments` per call. The position reached is kept
+		/// in `DelegateReassignments` so that calling again with the same
+		/// arguments resumes after it. The reassignment is complete once all
+		/// authorizations have been scanned.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be the `ChainSpaceOrigin`.
+		/// - `old`: The identity to move the authorizations away from.
+		/// - `new`: The identity to move the authorizations to.
+		/// - `space`: Restricts the reassignment to a single space.
+		///
+		/// # Errors
+		/// - `InvalidReassignment`: If `old` and `new` are the same.
+		/// - `ReassignmentInProgress`: If a reassignment of `old` to another identity or space has
+		///   not completed yet.
+		/// - `SpaceDelegatesLimitExceeded`: If `new` cannot be added to a space.
+		///
+		/// # Events
+		/// - `DelegateReassigned`: Emitted with the number of authorizations moved by the call.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reassign_delegate(T::MaxDelegateReassignments::get()))]
+		pub fn reassign_delegate(
+			origin: OriginFor<T>,
+			old: SpaceCreatorOf<T>,
+			new: SpaceCreatorOf<T>,
+			space: Option<SpaceIdOf>,
+		) -> DispatchResultWithPostInfo {
+			T::ChainSpaceOrigin::ensure_origin(origin)?;
+
+			ensure!(old != new, Error::<T>::InvalidReassignment);
+
+			let mut authorizations = match <DelegateReassignments<T>>::get(&old) {
+				Some(progress) => {
+					ensure!(
+						progress.new == new && progress.space == space,
+						Error::<T>::ReassignmentInProgress
+					);
+					<Authorizations<T>>::iter_from(<Authorizations<T>>::hashed_key_for(
+						&progress.last,
+					))
+				},
+				None => <Authorizations<T>>::iter(),
+			};
+
+			let max_scanned = T::MaxDelegateReassignments::get();
+			let mut scanned = 0u32;
+			let mut reassigned = 0u32;
+			let mut last = None;
+			while scanned < max_scanned {
+				let Some((authorization_id, details)) = authorizations.next() else { break };
+				scanned += 1;
+				last = Some(authorization_id.clone());
+
+				let in_scope =
+					space.as_ref().map_or(true, |space_id| details.space_id == *space_id);
+				if in_scope && details.delegate == old {
+					Self::authorization_reassignment(authorization_id, details, new.clone())?;
+					reassigned += 1;
+				}
+			}
+
+			let complete = scanned < max_scanned || authorizations.next().is_none();
+			match last {
+				Some(last) if !complete => <DelegateReassignments<T>>::insert(
+					&old,
+					DelegateReassignmentOf::<T> { new: new.clone(), space, last },
+				),
+				_ => <DelegateReassignments<T>>::remove(&old),
+			}
+
+			Self::deposit_event(Event::DelegateReassigned { old, new, reassigned, complete });
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::reassign_delegate(scanned)).into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Adds a delegate to a space with specified permissions.
+	///
+	/// This function will add a new delegate to a space, given the space's ID,
+	/// the delegate's information, and the required permissions. It constructs
+	/// an authorization ID based on the space ID, delegate, and creator,
+	/// ensuring that the delegate is not already added. It also checks that the
+	/// space is not archived, is approved, and has not exceeded its capacity.
+	/// Returns the identifier of the new authorization.
+	fn space_delegate_addition(
+		space_id: SpaceIdOf,
+		delegate: SpaceCreatorOf<T>,
+		creator: SpaceCreatorOf<T>,
+		permissions: Permissions,
+	) -> Result<AuthorizationIdOf, Error<T>> {
+		// Id Digest = concat (H(<scale_encoded_space_identifier>,
+		// <scale_encoded_creator_identifier>, <scale_encoded_delegate_identifier>))
+		let id_digest = T::Hashing::hash(
+			&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		let delegate_authorization_id =
+			Ss58Identifier::create_identifier(&id_digest.encode(), IdentifierType::Authorization)
+				.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		ensure!(
+			!Authorizations::<T>::contains_key(&delegate_authorization_id),
+			Error::<T>::DelegateAlreadyAdded
+		);
+
+		let mut delegates = Delegates::<T>::get(&space_id);
+		delegates
+			.try_push(delegate.clone())
+			.map_err(|_| Error::<T>::SpaceDelegatesLimitExceeded)?;
+		Delegates::<T>::insert(&space_id, delegates);
+
+		Authorizations::<T>::insert(
+			&delegate_authorization_id,
+			SpaceAuthorizationOf::<T> {
+				space_id: space_id.clone(),
+				delegate: delegate.clone(),
+				permissions,
+				delegator: creator,
+			},
+		);
+
+		Self::update_activity(&space_id, IdentifierTypeOf::Auth, CallTypeOf::Authorization)
+			.map_err(Error::<T>::from)?;
+
+		Self::deposit_event(Event::Authorization {
+			space: space_id,
+			authorization: delegate_authorization_id.clone(),
+			delegate,
+		});
+
+		Ok(delegate_authorization_id)
+	}
+
+	/// Moves the ownership of a space to a new identity.
+	///
+	/// The authorization the previous owner received at creation is
+	/// removed together with its delegate entry, and the new owner is
+	/// registered with all permissions under its own authorization. The
+	/// creator recorded in the space details is updated accordingly.
+	fn space_ownership_transfer(
+		space_id: SpaceIdOf,
+		space_details: SpaceDetailsOf<T>,
+		new_owner: SpaceCreatorOf<T>,
+	) -> Result<(), Error<T>> {
+		let previous_owner = space_details.creator.clone();
+
+		let previous_auth_digest = T::Hashing::hash(
+			&[&space_id.encode()[..], &previous_owner.encode()[..], &previous_owner.encode()[..]]
+				.concat()[..],
+		);
+		let previous_authorization_id = Ss58Identifier::create_identifier(
+			&previous_auth_digest.encode(),
+			IdentifierType::Authorization,
+		)
+		.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		let auth_digest = T::Hashing::hash(
+			&[&space_id.encode()[..], &new_owner.encode()[..], &new_owner.encode()[..]].concat()[..],
+		);
+		let authorization_id =
+			Ss58Identifier::create_identifier(&auth_digest.encode(), IdentifierType::Authorization)
+				.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		Authorizations::<T>::remove(&previous_authorization_id);
+		AuthorizationExpiry::<T>::remove(&previous_authorization_id);
+
+		let mut delegates = Delegates::<T>::get(&space_id);
+		if let Some(index) = delegates.iter().position(|d| d == &previous_owner) {
+			delegates.remove(index);
+		}
+		if !delegates.iter().any(|d| d == &new_owner) {
+			delegates
+				.try_push(new_owner.clone())
+				.map_err(|_| Error::<T>::SpaceDelegatesLimitExceeded)?;
+		}
+		Delegates::<T>::insert(&space_id, delegates);
+
+		Authorizations::<T>::insert(
+			&authorization_id,
+			SpaceAuthorizationOf::<T> {
+				space_id: space_id.clone(),
+				delegate: new_owner.clone(),
+				permissions: Permissions::all(),
+				delegator: new_owner.clone(),
+			},
+		);
+
+		<Spaces<T>>::insert(
+			&space_id,
+			SpaceDetailsOf::<T> { creator: new_owner.clone(), ..space_details },
+		);
+		<PendingOwnership<T>>::remove(&space_id);
+
+		Self::update_activity(&space_id, IdentifierTypeOf::ChainSpace, CallTypeOf::Transfer)
+			.map_err(Error::<T>::from)?;
+
+		Self::deposit_event(Event::OwnershipTransferred {
+			space: space_id,
+			from: previous_owner,
+			to: new_owner,
+			authorization: authorization_id,
+		});
+
+		Ok(())
+	}
+
+	/// Moves an authorization to `new`, keeping its permissions, delegator
+	/// and validity period. The authorization a space owner received at
+	/// creation is moved through an ownership transfer instead. An
+	/// authorization `new` already holds from the same delegator is kept as
+	/// it is.
+	fn authorization_reassignment(
+		authorization_id: AuthorizationIdOf,
+		details: SpaceAuthorizationOf<T>,
+		new: SpaceCreatorOf<T>,
+	) -> Result<(), Error<T>> {
+		let space_id = details.space_id.clone();
+
+		if details.delegator == details.delegate {
+			if let Some(space_details) = <Spaces<T>>::get(&space_id) {
+				if space_details.creator == details.delegate {
+					return Self::space_ownership_transfer(space_id, space_details, new)
+				}
+			}
+		}
+
+		// Id Digest = concat (H(<scale_encoded_space_identifier>,
+		// <scale_encoded_creator_identifier>, <scale_encoded_delegate_identifier>))
+		let id_digest = T::Hashing::hash(
+			&[&space_id.encode()[..], &new.encode()[..], &details.delegator.encode()[..]].concat()
+				[..],
+		);
+		let new_authorization_id =
+			Ss58Identifier::create_identifier(&id_digest.encode(), IdentifierType::Authorization)
+				.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		<Authorizations<T>>::remove(&authorization_id);
+		let valid_until = <AuthorizationExpiry<T>>::take(&authorization_id);
+
+		let mut delegates = <Delegates<T>>::get(&space_id);
+		delegates.retain(|d| d != &details.delegate);
+		if !delegates.iter().any(|d| d == &new) {
+			delegates
+				.try_push(new.clone())
+				.map_err(|_| Error::<T>::SpaceDelegatesLimitExceeded)?;
+		}
+		<Delegates<T>>::insert(&space_id, delegates);
+
+		if !<Authorizations<T>>::contains_key(&new_authorization_id) {
+			<Authorizations<T>>::insert(
+				&new_authorization_id,
+				SpaceAuthorizationOf::<T> { delegate: new.clone(), ..details },
+			);
+			if let Some(valid_until) = valid_until {
+				<AuthorizationExpiry<T>>::insert(&new_authorization_id, valid_until);
+			}
+		}
+
+		Self::update_activity(&space_id, IdentifierTypeOf::Auth, CallTypeOf::Transfer)?;
+
+		Self::deposit_event(Event::Deauthorization {
+			space: space_id.clone(),
+			authorization: authorization_id,
+		});
+		Self::deposit_event(Event::Authorization {
+			space: space_id,
+			authorization: new_authorization_id,
+			delegate: new,
+		});
+
+		Ok(())
+	}
+
+	/// Ensures that a time-bound authorization is still within its validity
+	/// period. Authorizations without an expiry always pass.
+	fn ensure_authorization_not_expired(
+		authorization_id: &AuthorizationIdOf,
+	) -> Result<(), Error<T>> {
+		if let Some(valid_until) = <AuthorizationExpiry<T>>::get(authorization_id) {
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= valid_until,
+				Error::<T>::AuthorizationExpired
+			);
+		}
+		Ok(())
+	}
+
+	/// Removes time-bound authorizations whose validity period has ended.
+	///
+	/// Runs from `on_idle` and inspects at most `MaxExpiryScanPerBlock`
+	/// entries of `AuthorizationExpiry`, within the weight left in the
+	/// block. The sweep resumes from `ExpirySweepCursor`, so large expiry
+	/// sets are worked through over several blocks.
+	fn expire_authorizations(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let scan_weight = T::DbWeight::get().reads(1);
+		let expire_weight = <T as Config>::WeightInfo::remove_delegate();
+		let mut total_weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+
+		if !remaining_weight.all_gte(total_weight + scan_weight + expire_weight) {
+			return Weight::zero()
+		}
+
+		let mut entries = match <ExpirySweepCursor<T>>::get() {
+			Some(last) =>
+				<AuthorizationExpiry<T>>::iter_from(<AuthorizationExpiry<T>>::hashed_key_for(&last)),
+			None => <AuthorizationExpiry<T>>::iter(),
+		};
+
+		let mut expired = Vec::new();
+		let mut last = None;
+		let mut exhausted = false;
+		for _ in 0..T::MaxExpiryScanPerBlock::get() {
+			if !remaining_weight.all_gte(total_weight + scan_weight + expire_weight) {
+				break
+			}
+			let Some((authorization_id, valid_until)) = entries.next() else {
+				exhausted = true;
+				break
+			};
+			total_weight += scan_weight;
+			if now > valid_until {
+				total_weight += expire_weight;
+				expired.push(authorization_id.clone());
+			}
+			last = Some(authorization_id);
+		}
+
+		match last {
+			Some(last) if !exhausted => <ExpirySweepCursor<T>>::put(last),
+			_ => <ExpirySweepCursor<T>>::kill(),
+		}
+
+		for authorization_id in expired {
+			Self::expire_authorization(authorization_id);
+		}
+
+		total_weight
+	}
+
+	/// Removes an expired authorization and its delegate entry, mirroring
+	/// `remove_delegate`.
+	fn expire_authorization(authorization_id: AuthorizationIdOf) {
+		<AuthorizationExpiry<T>>::remove(&authorization_id);
+		let Some(details) = <Authorizations<T>>::take(&authorization_id) else { return };
+
+		<Delegates<T>>::mutate(&details.space_id, |delegates| {
+			if let Some(index) = delegates.iter().position(|d| d == &details.delegate) {
+				delegates.remove(index);
+			}
+		});
+
+		let _ = Self::decrement_usage(&details.space_id);
+		let _ = Self::update_activity(
+			&details.space_id,
+			IdentifierTypeOf::Auth,
+			CallTypeOf::Deauthorization,
+		);
+
+		Self::deposit_event(Event::Expired {
+			space: details.space_id,
+			authorization: authorization_id,
+		});
+	}
+
+	/// Lists the delegates of a space along with their authorizations.
+	///
+	/// Authorizations are not indexed by space, so this walks the whole
+	/// `Authorizations` map. It is meant to be used from runtime APIs and
+	/// must not be called from dispatchables.
+	pub fn space_delegates(space_id: &SpaceIdOf) -> Vec<SpaceDelegateInfoOf<T>> {
+		<Authorizations<T>>::iter()
+			.filter(|(_, d)| d.space_id == *space_id)
+			.map(|(authorization, d)| SpaceDelegateInfoOf::<T> {
+				valid_until: <AuthorizationExpiry<T>>::get(&authorization),
+				authorization,
+				delegate: d.delegate,
+				permissions: d.permissions,
+				delegator: d.delegator,
+			})
+			.collect()
+	}
+
+	/// Returns the capacity, consumption, delegate count and status of a
+	/// space, or `None` if the space does not exist. Used by the
+	/// `ChainSpaceApi` runtime API.
+	pub fn space_usage(space_id: &SpaceIdOf) -> Option<SpaceUsageOf> {
+		let details = <Spaces<T>>::get(space_id)?;
+
+		Some(SpaceUsageOf {
+			capacity: details.txn_capacity,
+			reserved: details.txn_reserve,
+			consumed: details.txn_count,
+			delegates: <Delegates<T>>::decode_len(space_id).unwrap_or_default() as u32,
+			approved: details.approved,
+			archived: details.archive,
+			frozen: Self::is_frozen(space_id),
+		})
+	}
+
+	/// Checks if a given entity is a delegate for the specified space.
+	///
+	/// This function retrieves the list of delegates for a space and determines
+	/// whether the specified delegate is among them. It is a read-only
+	/// operation and does not modify the state.
+	pub fn is_a_delegate(tx_id: &SpaceIdOf, delegate: SpaceCreatorOf<T>) -> bool {
+		<Delegates<T>>::get(tx_id).iter().any(|d| d == &delegate)
+	}
+
+	/// Returns `true` if anchoring under the space is blocked by a freeze.
+	pub fn is_frozen(space_id: &SpaceIdOf) -> bool {
+		<FrozenSpaces<T>>::contains_key(space_id)
+	}
+
+	/// Freezes a space on behalf of `authority`. The council may take over
+	/// a freeze set by an admin, so that the admin can no longer lift it.
+	fn space_freeze(space_id: SpaceIdOf, authority: FreezeAuthority) -> Result<(), Error<T>> {
+		ensure!(Spaces::<T>::contains_key(&space_id), Error::<T>::SpaceNotFound);
+		ensure!(
+			<FrozenSpaces<T>>::get(&space_id).map_or(true, |current| {
+				current == FreezeAuthority::Admin && authority == FreezeAuthority::Council
+			}),
+			Error::<T>::AlreadyFrozen
+		);
+
+		<FrozenSpaces<T>>::insert(&space_id, authority);
+
+		Self::update_activity(&space_id, IdentifierTypeOf::ChainSpace, CallTypeOf::Freeze)
+			.map_err(Error::<T>::from)?;
+
+		Self::deposit_event(Event::Freeze { space: space_id, authority });
+
+		Ok(())
+	}
+
+	/// Lifts the freeze of a space on behalf of `authority`. Admins cannot
+	/// lift a freeze set by the council.
+	fn space_unfreeze(space_id: SpaceIdOf, authority: FreezeAuthority) -> Result<(), Error<T>> {
+		let current = <FrozenSpaces<T>>::get(&space_id).ok_or(Error::<T>::NotFrozen)?;
+		ensure!(
+			current == FreezeAuthority::Admin || authority == FreezeAuthority::Council,
+			Error::<T>::UnauthorizedOperation
+		);
+
+		<FrozenSpaces<T>>::remove(&space_id);
+
+		Self::update_activity(&space_id, IdentifierTypeOf::ChainSpace, CallTypeOf::Unfreeze)
+			.map_err(Error::<T>::from)?;
+
+		Self::deposit_event(Event::Unfreeze { space: space_id, authority });
+
+		Ok(())
+	}
+
+	/// Verifies if a given delegate has a specific authorization.
+	///
+	/// This function checks if the provided delegate is associated with the
+	/// given authorization ID and has the 'ASSERT' permission.
+	pub fn ensure_authorization_origin(
+		authorization_id: &AuthorizationIdOf,
+		delegate: &SpaceCreatorOf<T>,
+	) -> Result<SpaceIdOf, Error<T>> {
+		let d =
+			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
+
+		Self::ensure_authorization_not_expired(authorization_id)?;
+
+		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
+
+		ensure!(!Self::is_frozen(&d.space_id), Error::<T>::Frozen);
+
+		Self::increment_usage(&d.space_id)?;
+
+		Self::validate_space_for_transaction(&d.space_id)?;
+
+		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
+
+		Ok(d.space_id)
+	}
+
+	/// Verifies the authorization like `ensure_authorization_origin` and
+	/// records one anchoring call against the space and the delegate for the
+	/// per-block rate limits. Used by calls that anchor new records.
+	pub fn ensure_anchoring_origin(
+		authorization_id: &AuthorizationIdOf,
+		delegate: &SpaceCreatorOf<T>,
+	) -> Result<SpaceIdOf, Error<T>> {
+		let space_id = Self::ensure_authorization_origin(authorization_id, delegate)?;
+		Self::note_anchors(&space_id, delegate, 1)?;
+
+		Ok(space_id)
+	}
+
+	/// Verifies that the delegate may revoke or restore records in the space
+	/// linked to the authorization.
+	///
+	/// Both `ASSERT` and `REVOKE` delegates are accepted, so delegates that
+	/// can anchor records keep the ability to withdraw them. The space usage
+	/// is incremented in the same way as for `ensure_authorization_origin`.
+	pub fn ensure_authorization_revoke_origin(
+		authorization_id: &AuthorizationIdOf,
+		delegate: &SpaceCreatorOf<T>,
 	) -> Result<SpaceIdOf, Error<T>> {
 		let d =
 			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
 
+		Self::ensure_authorization_not_expired(authorization_id)?;
+
+		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
+
+		Self::increment_usage(&d.space_id)?;
+
+		Self::validate_space_for_transaction(&d.space_id)?;
+
+		ensure!(
+			d.permissions.intersects(Permissions::ASSERT | Permissions::REVOKE),
+			Error::<T>::UnauthorizedOperation
+		);
+
+		Ok(d.space_id)
+	}
+
+	/// Checks, without recording any usage, that `delegate` still holds
+	/// revoke rights in the space of `authorization_id`: the authorization
+	/// exists and has not expired and the delegate is still a delegate of
+	/// the space. Used to re-validate revocations that were authorized at an
+	/// earlier block. Like other revocations, these are not blocked by a
+	/// freeze of the space.
+	pub fn ensure_revoke_authority(
+		authorization_id: &AuthorizationIdOf,
+		delegate: &SpaceCreatorOf<T>,
+	) -> Result<SpaceIdOf, Error<T>> {
+		let d =
+			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
+
+		Self::ensure_authorization_not_expired(authorization_id)?;
+
+		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
+		ensure!(
+			Self::is_a_delegate(&d.space_id, delegate.clone()),
+			Error::<T>::UnauthorizedOperation
+		);
+		ensure!(
+			d.permissions.intersects(Permissions::ASSERT | Permissions::REVOKE),
+			Error::<T>::UnauthorizedOperation
+		);
+
+		Ok(d.space_id)
+	}
+
+	pub fn ensure_authorization_restore_origin(
+		authorization_id: &AuthorizationIdOf,
+		delegate: &SpaceCreatorOf<T>,
+	) -> Result<SpaceIdOf, Error<T>> {
+		let d =
+			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
+
+		Self::ensure_authorization_not_expired(authorization_id)?;
+
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
 		Self::increment_usage(&d.space_id)?;
@@ -1308,6 +2190,8 @@ impl<T: Config> Pallet<T> {
 		let d =
 			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
 
+		Self::ensure_authorization_not_expired(authorization_id)?;
+
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
 		Self::increment_usage(&d.space_id)?;
@@ -1333,6 +2217,8 @@ impl<T: Config> Pallet<T> {
 		let d =
 			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
 
+		Self::ensure_authorization_not_expired(authorization_id)?;
+
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
 		Self::increment_usage(&d.space_id)?;
@@ -1360,6 +2246,8 @@ impl<T: Config> Pallet<T> {
 		let d =
 			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
 
+		Self::ensure_authorization_not_expired(authorization_id)?;
+
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
 		Self::validate_space_for_transaction(&d.space_id)?;
@@ -1458,7 +2346,9 @@ impl<T: Config> Pallet<T> {
 	pub fn increment_usage(tx_id: &SpaceIdOf) -> Result<(), Error<T>> {
 		Spaces::<T>::try_mutate(tx_id, |space_opt| {
 			if let Some(space_details) = space_opt {
+				let previous = space_details.txn_count;
 				space_details.txn_count = space_details.txn_count.saturating_add(1);
+				Self::check_capacity_thresholds(tx_id, previous, space_details);
 				Ok(())
 			} else {
 				Err(Error::<T>::SpaceNotFound)
@@ -1490,7 +2380,9 @@ impl<T: Config> Pallet<T> {
 	pub fn increment_usage_entries(tx_id: &SpaceIdOf, increment: u16) -> Result<(), Error<T>> {
 		Spaces::<T>::try_mutate(tx_id, |space_opt| {
 			if let Some(space_details) = space_opt {
+				let previous = space_details.txn_count;
 				space_details.txn_count = space_details.txn_count.saturating_add(increment.into());
+				Self::check_capacity_thresholds(tx_id, previous, space_details);
 				Ok(())
 			} else {
 				Err(Error::<T>::SpaceNotFound)
@@ -1514,6 +2406,179 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Anchors a new space for `creator` together with the authorization
+	/// granting the creator all permissions, and returns the identifiers of
+	/// both. Shared by `create` and the genesis build.
+	fn space_creation(
+		space_code: SpaceCodeOf<T>,
+		creator: SpaceCreatorOf<T>,
+		approved: bool,
+		txn_capacity: u64,
+	) -> Result<(SpaceIdOf, AuthorizationIdOf), Error<T>> {
+		let identifier = Self::space_identifier(&space_code, &creator)?;
+
+		ensure!(!<Spaces<T>>::contains_key(&identifier), Error::<T>::SpaceAlreadyAnchored);
+
+		// Construct the authorization_id from the provided parameters.
+		// Id Digest = concat (H(<scale_encoded_space_identifier>,
+		// <scale_encoded_creator_identifier> ))
+		let auth_id_digest = T::Hashing::hash(
+			&[&identifier.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		let authorization_id = Ss58Identifier::create_identifier(
+			&auth_id_digest.encode(),
+			IdentifierType::Authorization,
+		)
+		.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		let mut delegates: BoundedVec<SpaceCreatorOf<T>, T::MaxSpaceDelegates> =
+			BoundedVec::default();
+		delegates
+			.try_push(creator.clone())
+			.map_err(|_| Error::<T>::SpaceDelegatesLimitExceeded)?;
+
+		Delegates::<T>::insert(&identifier, delegates);
+
+		Authorizations::<T>::insert(
+			&authorization_id,
+			SpaceAuthorizationOf::<T> {
+				space_id: identifier.clone(),
+				delegate: creator.clone(),
+				permissions: Permissions::all(),
+				delegator: creator.clone(),
+			},
+		);
+
+		<Spaces<T>>::insert(
+			&identifier,
+			SpaceDetailsOf::<T> {
+				code: space_code,
+				creator: creator.clone(),
+				txn_capacity,
+				txn_reserve: 0,
+				txn_count: 0,
+				approved,
+				archive: false,
+				parent: identifier.clone(),
+			},
+		);
+
+		Self::update_activity(&identifier, IdentifierTypeOf::ChainSpace, CallTypeOf::Genesis)?;
+
+		Ok((identifier, authorization_id))
+	}
+
+	/// Derives the identifier of the space anchored by `creator` with
+	/// `space_code`.
+	pub fn space_identifier(
+		space_code: &SpaceCodeOf<T>,
+		creator: &SpaceCreatorOf<T>,
+	) -> Result<SpaceIdOf, Error<T>> {
+		// Id Digest = concat (H(<scale_encoded_registry_input>,
+		// <scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_code.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		Ss58Identifier::create_identifier(&id_digest.encode()[..], IdentifierType::Space)
+			.map_err(|_| Error::<T>::InvalidIdentifierLength)
+	}
+
+	/// Resolves a space seeded at genesis and checks that its creator may
+	/// anchor records in it.
+	///
+	/// Genesis records carry no authorization identifier, so the space is
+	/// looked up from the code and creator it was seeded with. The usage of
+	/// the space is incremented in the same way as for
+	/// `ensure_authorization_origin`.
+	pub fn ensure_genesis_space(
+		space_code: &SpaceCodeOf<T>,
+		creator: &SpaceCreatorOf<T>,
+	) -> Result<SpaceIdOf, Error<T>> {
+		let space_id = Self::space_identifier(space_code, creator)?;
+
+		ensure!(<Spaces<T>>::contains_key(&space_id), Error::<T>::SpaceNotFound);
+		ensure!(Self::is_a_delegate(&space_id, creator.clone()), Error::<T>::UnauthorizedOperation);
+
+		Self::increment_usage(&space_id)?;
+		Self::validate_space_for_transaction(&space_id)?;
+
+		Ok(space_id)
+	}
+
+	/// Records `count` anchoring calls against a space and a delegate for
+	/// the current block.
+	///
+	/// Fails with `OverRateLimit`, without recording anything, if either
+	/// the space or the delegate would exceed its per-block limit. Limits
+	/// set to `0` are not enforced and their counters are not kept.
+	pub fn note_anchors(
+		space_id: &SpaceIdOf,
+		delegate: &SpaceCreatorOf<T>,
+		count: u32,
+	) -> Result<(), Error<T>> {
+		let space_limit = T::MaxSpaceAnchorsPerBlock::get();
+		let delegate_limit = T::MaxDelegateAnchorsPerBlock::get();
+
+		let space_anchors = <SpaceAnchorsInBlock<T>>::get(space_id).saturating_add(count);
+		let delegate_anchors = <DelegateAnchorsInBlock<T>>::get(delegate).saturating_add(count);
+
+		ensure!(space_limit == 0 || space_anchors <= space_limit, Error::<T>::OverRateLimit);
+		ensure!(
+			delegate_limit == 0 || delegate_anchors <= delegate_limit,
+			Error::<T>::OverRateLimit
+		);
+
+		if space_limit != 0 {
+			<SpaceAnchorsInBlock<T>>::insert(space_id, space_anchors);
+		}
+		if delegate_limit != 0 {
+			<DelegateAnchorsInBlock<T>>::insert(delegate, delegate_anchors);
+		}
+
+		Ok(())
+	}
+
+	/// Removes the anchoring counts of the previous block. Only spaces and
+	/// delegates that anchored in that block have an entry, so the number of
+	/// removals is bounded by the per-block limits and the block weight.
+	fn clear_anchor_counts() -> Weight {
+		let spaces = <SpaceAnchorsInBlock<T>>::clear(u32::MAX, None);
+		let delegates = <DelegateAnchorsInBlock<T>>::clear(u32::MAX, None);
+
+		T::DbWeight::get().reads_writes(
+			u64::from(spaces.loops.saturating_add(delegates.loops)),
+			u64::from(spaces.unique.saturating_add(delegates.unique)),
+		)
+	}
+
+	/// Emits `CapacityThresholdReached` for each configured threshold that
+	/// the space usage crossed when growing from `previous` to its current
+	/// value. Spaces with unlimited capacity never raise alerts.
+	fn check_capacity_thresholds(
+		tx_id: &SpaceIdOf,
+		previous: u64,
+		space_details: &SpaceDetailsOf<T>,
+	) {
+		let capacity = space_details.txn_capacity;
+		if capacity == 0 {
+			return;
+		}
+
+		for threshold in T::CapacityAlertThresholds::get() {
+			let limit = threshold.mul_ceil(capacity);
+			if previous < limit && space_details.txn_count >= limit {
+				Self::deposit_event(Event::CapacityThresholdReached {
+					space: tx_id.clone(),
+					threshold,
+					usage: space_details.txn_count,
+					capacity,
+				});
+			}
+		}
+	}
+
 	/// Updates the global timeline with a new activity event for a space.
 	///
 	/// This function is an internal mechanism that logs each significant change
@@ -1545,4 +2610,13 @@ impl<T: Config> Pallet<T> {
 			index: frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
 		}
 	}
+
+	/// Returns the event topic for a space, schema or other identifier.
+	///
+	/// The topic is the hash of the SCALE encoded identifier, so indexers
+	/// can derive it off-chain and subscribe to the events of one
+	/// identifier instead of decoding every event in a block.
+	pub fn identifier_topic(identifier: &Ss58Identifier) -> T::Hash {
+		<T as frame_system::Config>::Hashing::hash_of(identifier)
+	}
 }
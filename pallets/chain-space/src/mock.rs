@@ -24,7 +24,7 @@ use pallet_chain_space::IsPermissioned;
 use frame_system::EnsureRoot;
 use sp_runtime::{
 	traits::{IdentifyAccount, IdentityLookup, Verify},
-	BuildStorage, MultiSignature,
+	BuildStorage, MultiSignature, Percent,
 };
 
 type Signature = MultiSignature;
@@ -64,6 +64,12 @@ impl mock_origin::Config for Test {
 parameter_types! {
 	#[derive(Debug, Clone)]
 	pub const MaxSpaceDelegates: u32 = 5u32;
+	pub CapacityAlertThresholds: Vec<Percent> =
+		vec![Percent::from_percent(80), Percent::from_percent(95)];
+	pub const MaxSpaceAnchorsPerBlock: u32 = 5;
+	pub const MaxDelegateAnchorsPerBlock: u32 = 3;
+	pub const MaxExpiryScanPerBlock: u32 = 2;
+	pub const MaxDelegateReassignments: u32 = 2;
 }
 
 pub struct NetworkPermission;
@@ -79,6 +85,11 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type CapacityAlertThresholds = CapacityAlertThresholds;
+	type MaxSpaceAnchorsPerBlock = MaxSpaceAnchorsPerBlock;
+	type MaxDelegateAnchorsPerBlock = MaxDelegateAnchorsPerBlock;
+	type MaxExpiryScanPerBlock = MaxExpiryScanPerBlock;
+	type MaxDelegateReassignments = MaxDelegateReassignments;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type WeightInfo = ();
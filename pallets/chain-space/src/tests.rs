@@ -2,9 +2,9 @@ use super::*;
 use crate::mock::*;
 use codec::Encode;
 use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
-use frame_support::{assert_err, assert_ok, error::BadOrigin};
+use frame_support::{assert_err, assert_ok, error::BadOrigin, traits::Hooks, weights::Weight};
 use frame_system::RawOrigin;
-use sp_runtime::{traits::Hash, AccountId32};
+use sp_runtime::{traits::Hash, AccountId32, BuildStorage};
 use sp_std::prelude::*;
 
 pub fn generate_space_id<T: Config>(digest: &SpaceCodeOf<T>) -> SpaceIdOf {
@@ -55,10 +55,78 @@ fn add_delegate_should_succeed() {
 			space_id,
 			DID_01,
 			authorization_id,
+			None,
 		));
 	});
 }
 
+#[test]
+fn time_bound_delegate_should_fail_verification_after_expiry() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let space = [2u8; 256].to_vec();
+	let capacity = 5u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		// The validity period has to end in the future
+		assert_err!(
+			Space::add_delegate(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				delegate.clone(),
+				authorization_id.clone(),
+				Some(1),
+			),
+			Error::<Test>::InvalidExpiry
+		);
+
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate.clone(),
+			authorization_id,
+			Some(5),
+		));
+
+		System::set_block_number(5);
+		assert_ok!(Space::ensure_authorization_origin(&delegate_authorization_id, &delegate));
+
+		System::set_block_number(6);
+		assert_err!(
+			Space::ensure_authorization_origin(&delegate_authorization_id, &delegate),
+			Error::<Test>::AuthorizationExpired
+		);
+	});
+}
+
 #[test]
 fn add_admin_delegate_should_succeed() {
 	let creator = DID_00;
@@ -180,6 +248,43 @@ fn add_delegator_should_succeed() {
 	});
 }
 
+#[test]
+fn add_revoke_delegate_should_succeed() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let capacity = 3u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		//Admin should be able to add the revoke delegate
+		assert_ok!(Space::add_revoke_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id,
+			DID_01,
+			authorization_id,
+		));
+	});
+}
+
 #[test]
 fn add_delegator_should_fail_if_delegator_already_exists() {
 	let creator = DID_00;
@@ -254,6 +359,7 @@ fn add_delegate_should_fail_if_space_is_not_created() {
 				space_id,
 				SubjectId(AccountId32::new([1u8; 32])),
 				authorization_id,
+				None,
 			),
 			Error::<Test>::AuthorizationNotFound
 		);
@@ -365,6 +471,7 @@ fn add_delegate_should_fail_if_the_regisrty_is_archived() {
 				space_id,
 				SubjectId(AccountId32::new([1u8; 32])),
 				authorization_id,
+				None,
 			),
 			Error::<Test>::ArchivedSpace
 		);
@@ -401,6 +508,7 @@ fn add_delegate_should_fail_if_the_space_is_not_approved() {
 				space_id,
 				SubjectId(AccountId32::new([1u8; 32])),
 				authorization_id,
+				None,
 			),
 			Error::<Test>::SpaceNotApproved
 		);
@@ -441,6 +549,7 @@ fn add_delegate_should_fail_if_a_non_delegate_tries_to_add() {
 				space_id,
 				SubjectId(AccountId32::new([1u8; 32])),
 				authorization_id,
+				None,
 			),
 			Error::<Test>::UnauthorizedOperation
 		);
@@ -485,6 +594,7 @@ fn add_delegate_should_fail_if_the_space_capacity_is_full() {
 				space_id,
 				SubjectId(AccountId32::new([1u8; 32])),
 				authorization_id,
+				None,
 			),
 			Error::<Test>::CapacityLimitExceeded
 		);
@@ -525,6 +635,7 @@ fn add_delegate_should_fail_if_delegate_already_exists() {
 			space_id.clone(),
 			DID_01.clone(),
 			authorization_id.clone(),
+			None,
 		));
 
 		assert_err!(
@@ -533,6 +644,7 @@ fn add_delegate_should_fail_if_delegate_already_exists() {
 				space_id,
 				DID_01,
 				authorization_id,
+				None,
 			),
 			Error::<Test>::DelegateAlreadyAdded
 		);
@@ -652,6 +764,7 @@ fn archiving_a_space_should_succeed() {
 				space_id,
 				delegate,
 				authorization_id,
+				None,
 			),
 			Error::<Test>::ArchivedSpace
 		);
@@ -725,6 +838,7 @@ fn restoring_an_archived_a_space_should_succeed() {
 			space_id,
 			delegate,
 			authorization_id,
+			None,
 		));
 	});
 }
@@ -1163,6 +1277,7 @@ fn add_delegate_should_fail_for_space_creator_delegating_themselves() {
 				space_id,
 				creator.clone(),
 				authorization_id,
+				None,
 			),
 			Error::<Test>::DelegateAlreadyAdded
 		);
@@ -1248,3 +1363,728 @@ fn add_delegator_should_fail_for_space_creator_delegating_themselves() {
 		);
 	});
 }
+
+#[test]
+fn transferring_space_ownership_should_succeed() {
+	let creator = DID_00;
+	let new_owner = DID_01;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let capacity = 5u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let new_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &new_owner.encode()[..], &new_owner.encode()[..]].concat()[..],
+	);
+
+	let new_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&new_auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		// Only the creator can propose a new owner
+		assert_err!(
+			Space::transfer_ownership(
+				DoubleOrigin(author.clone(), new_owner.clone()).into(),
+				space_id.clone(),
+				new_owner.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+
+		assert_ok!(Space::transfer_ownership(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			new_owner.clone(),
+		));
+
+		// Only the proposed owner can accept the transfer
+		assert_err!(
+			Space::accept_ownership(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+
+		assert_ok!(Space::accept_ownership(
+			DoubleOrigin(author.clone(), new_owner.clone()).into(),
+			space_id.clone(),
+		));
+
+		assert_eq!(Spaces::<Test>::get(&space_id).unwrap().creator, new_owner);
+		assert!(PendingOwnership::<Test>::get(&space_id).is_none());
+		assert!(Authorizations::<Test>::get(&authorization_id).is_none());
+		assert_ok!(Space::ensure_authorization_admin_origin(&new_authorization_id, &new_owner));
+
+		assert_err!(
+			Space::accept_ownership(DoubleOrigin(author, new_owner).into(), space_id),
+			Error::<Test>::NoPendingOwnershipTransfer
+		);
+	});
+}
+
+#[test]
+fn force_transferring_space_ownership_should_succeed() {
+	let creator = DID_00;
+	let new_owner = DID_01;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let capacity = 5u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_err!(
+			Space::force_transfer_ownership(
+				DoubleOrigin(author, creator.clone()).into(),
+				space_id.clone(),
+				new_owner.clone(),
+			),
+			BadOrigin
+		);
+
+		assert_ok!(Space::force_transfer_ownership(
+			RawOrigin::Root.into(),
+			space_id.clone(),
+			new_owner.clone(),
+		));
+
+		assert_eq!(Spaces::<Test>::get(&space_id).unwrap().creator, new_owner);
+		assert!(!Space::is_a_delegate(&space_id, creator));
+		assert!(Space::is_a_delegate(&space_id, new_owner));
+	});
+}
+
+#[test]
+fn listing_space_delegates_should_return_all_authorizations() {
+	let creator = DID_00;
+	let delegate = DID_01;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let capacity = 5u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author, creator.clone()).into(),
+			space_id.clone(),
+			delegate.clone(),
+			authorization_id.clone(),
+			Some(10),
+		));
+
+		let mut delegates = Space::space_delegates(&space_id);
+		delegates.sort_by_key(|d| d.delegate.encode());
+
+		assert_eq!(
+			delegates,
+			vec![
+				SpaceDelegateInfoOf::<Test> {
+					authorization: authorization_id,
+					delegate: creator.clone(),
+					permissions: Permissions::all(),
+					delegator: creator.clone(),
+					valid_until: None,
+				},
+				SpaceDelegateInfoOf::<Test> {
+					authorization: delegate_authorization_id,
+					delegate,
+					permissions: Permissions::ASSERT,
+					delegator: creator,
+					valid_until: Some(10),
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn space_usage_should_summarize_capacity_delegates_and_status() {
+	let creator = DID_00;
+	let delegate = DID_01;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let capacity = 5u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_eq!(Space::space_usage(&space_id), None);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author, creator).into(),
+			space_id.clone(),
+			delegate,
+			authorization_id,
+			None,
+		));
+
+		assert_ok!(Space::force_freeze(RawOrigin::Root.into(), space_id.clone()));
+
+		assert_eq!(
+			Space::space_usage(&space_id),
+			Some(SpaceUsageOf {
+				capacity,
+				reserved: 0,
+				consumed: 1,
+				delegates: 2,
+				approved: true,
+				archived: false,
+				frozen: true,
+			})
+		);
+	});
+}
+
+#[test]
+fn crossing_capacity_thresholds_should_emit_alerts_once() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let capacity = 20u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let alerts = || {
+		System::events()
+			.into_iter()
+			.filter_map(|r| match r.event {
+				RuntimeEvent::Space(Event::CapacityThresholdReached { threshold, .. }) =>
+					Some(threshold),
+				_ => None,
+			})
+			.collect::<Vec<_>>()
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(DoubleOrigin(author, creator).into(), space_digest));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		while Spaces::<Test>::get(&space_id).unwrap().txn_count < 15 {
+			assert_ok!(Space::increment_usage(&space_id));
+		}
+		assert!(alerts().is_empty());
+
+		// 80% of 20 is reached at the 16th transaction
+		assert_ok!(Space::increment_usage(&space_id));
+		assert_eq!(alerts(), vec![Percent::from_percent(80)]);
+
+		// Crossing 95% within a batch still raises the alert
+		assert_ok!(Space::increment_usage_entries(&space_id, 4));
+		assert_eq!(alerts(), vec![Percent::from_percent(80), Percent::from_percent(95)]);
+	});
+}
+
+#[test]
+fn anchoring_should_fail_once_the_block_rate_limit_is_reached() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let space = [2u8; 256].to_vec();
+	let capacity = 20u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate.clone(),
+			authorization_id.clone(),
+			None,
+		));
+
+		// The creator is limited to MaxDelegateAnchorsPerBlock
+		for _ in 0..MaxDelegateAnchorsPerBlock::get() {
+			assert_ok!(Space::ensure_anchoring_origin(&authorization_id, &creator));
+		}
+		assert_err!(
+			Space::ensure_anchoring_origin(&authorization_id, &creator),
+			Error::<Test>::OverRateLimit
+		);
+
+		// Calls that do not anchor are not counted against the limits
+		assert_ok!(Space::ensure_authorization_origin(&authorization_id, &creator));
+
+		// The space is limited to MaxSpaceAnchorsPerBlock across delegates
+		assert_ok!(Space::ensure_anchoring_origin(&delegate_authorization_id, &delegate));
+		assert_ok!(Space::ensure_anchoring_origin(&delegate_authorization_id, &delegate));
+		assert_err!(
+			Space::ensure_anchoring_origin(&delegate_authorization_id, &delegate),
+			Error::<Test>::OverRateLimit
+		);
+
+		// Both limits reset in the next block, and no counters are kept
+		System::set_block_number(2);
+		Space::on_initialize(2);
+		assert_eq!(SpaceAnchorsInBlock::<Test>::iter().count(), 0);
+		assert_eq!(DelegateAnchorsInBlock::<Test>::iter().count(), 0);
+		assert_ok!(Space::ensure_anchoring_origin(&authorization_id, &creator));
+		assert_ok!(Space::ensure_anchoring_origin(&delegate_authorization_id, &delegate));
+	});
+}
+
+#[test]
+fn genesis_spaces_should_be_anchored_and_approved() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let mut t: sp_runtime::Storage =
+		frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	crate::GenesisConfig::<Test> { spaces: vec![(space_digest, creator.clone(), 10)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		System::set_block_number(1);
+
+		let details = Spaces::<Test>::get(&space_id).expect("genesis space should exist");
+		assert!(details.approved);
+		assert_eq!(details.txn_capacity, 10);
+		assert_eq!(details.creator, creator);
+
+		// The creator holds a full authorization and can manage the space.
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			DID_01,
+			authorization_id,
+			None,
+		));
+
+		// Anchoring the same space again is rejected.
+		assert_err!(
+			Space::create(DoubleOrigin(author, creator).into(), space_digest),
+			Error::<Test>::SpaceAlreadyAnchored
+		);
+	});
+}
+
+#[test]
+fn freezing_a_space_should_block_anchoring_until_unfrozen() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let capacity = 5u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Space::freeze(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			authorization_id.clone(),
+		));
+		assert!(Space::is_frozen(&space_id));
+		assert_err!(
+			Space::ensure_authorization_origin(&authorization_id, &creator),
+			Error::<Test>::Frozen
+		);
+		assert_err!(
+			Space::freeze(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::AlreadyFrozen
+		);
+
+		assert_ok!(Space::unfreeze(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			authorization_id.clone(),
+		));
+		assert_ok!(Space::ensure_authorization_origin(&authorization_id, &creator));
+
+		// A freeze set by the council cannot be lifted by an admin
+		assert_err!(
+			Space::force_freeze(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone()
+			),
+			BadOrigin
+		);
+		assert_ok!(Space::force_freeze(RawOrigin::Root.into(), space_id.clone()));
+		assert_err!(
+			Space::unfreeze(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+
+		assert_ok!(Space::force_unfreeze(RawOrigin::Root.into(), space_id.clone()));
+		assert!(!Space::is_frozen(&space_id));
+		assert_err!(
+			Space::force_unfreeze(RawOrigin::Root.into(), space_id),
+			Error::<Test>::NotFrozen
+		);
+	});
+}
+
+#[test]
+fn expired_authorizations_should_be_swept_across_blocks() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let capacity = 10u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let time_bound = [
+		SubjectId(AccountId32::new([3u8; 32])),
+		SubjectId(AccountId32::new([4u8; 32])),
+		SubjectId(AccountId32::new([5u8; 32])),
+	];
+
+	let expired = || {
+		System::events()
+			.into_iter()
+			.filter(|r| matches!(r.event, RuntimeEvent::Space(Event::Expired { .. })))
+			.count()
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			DID_01,
+			authorization_id.clone(),
+			None,
+		));
+		for delegate in time_bound.iter() {
+			assert_ok!(Space::add_delegate(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				delegate.clone(),
+				authorization_id.clone(),
+				Some(5),
+			));
+		}
+		assert_eq!(Delegates::<Test>::get(&space_id).len(), 5);
+
+		// Nothing has expired yet
+		System::set_block_number(5);
+		Space::on_idle(5, Weight::MAX);
+		assert_eq!(AuthorizationExpiry::<Test>::iter().count(), 3);
+		assert!(ExpirySweepCursor::<Test>::get().is_some());
+
+		// Without spare weight the sweep does not run
+		System::set_block_number(6);
+		assert_eq!(Space::on_idle(6, Weight::zero()), Weight::zero());
+		assert_eq!(expired(), 0);
+
+		// The first sweep resumes from the cursor and stops at the scan limit
+		Space::on_idle(6, Weight::MAX);
+		assert_eq!(expired(), 1);
+		assert!(ExpirySweepCursor::<Test>::get().is_none());
+
+		Space::on_idle(6, Weight::MAX);
+		assert_eq!(expired(), 3);
+		assert!(ExpirySweepCursor::<Test>::get().is_some());
+
+		Space::on_idle(6, Weight::MAX);
+		assert!(ExpirySweepCursor::<Test>::get().is_none());
+
+		assert_eq!(AuthorizationExpiry::<Test>::iter().count(), 0);
+		assert_eq!(Delegates::<Test>::get(&space_id).len(), 2);
+		assert!(!Delegates::<Test>::get(&space_id).iter().any(|d| time_bound.contains(d)));
+	});
+}
+
+#[test]
+fn reassigning_a_delegate_should_move_its_authorizations() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let other_delegates =
+		[SubjectId(AccountId32::new([3u8; 32])), SubjectId(AccountId32::new([4u8; 32]))];
+	let new_delegate = SubjectId(AccountId32::new([9u8; 32]));
+	let new_owner = SubjectId(AccountId32::new([10u8; 32]));
+	let space = [2u8; 256].to_vec();
+	let capacity = 20u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_id_digest);
+
+	let new_delegate_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &new_delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let new_delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&new_delegate_id_digest);
+
+	let new_owner_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &new_owner.encode()[..], &new_owner.encode()[..]].concat()[..],
+	);
+	let new_owner_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&new_owner_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		for delegate in [delegate.clone()].iter().chain(other_delegates.iter()) {
+			assert_ok!(Space::add_delegate(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				delegate.clone(),
+				authorization_id.clone(),
+				None,
+			));
+		}
+
+		assert_err!(
+			Space::reassign_delegate(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				delegate.clone(),
+				new_delegate.clone(),
+				None,
+			),
+			BadOrigin
+		);
+		assert_err!(
+			Space::reassign_delegate(
+				RawOrigin::Root.into(),
+				delegate.clone(),
+				delegate.clone(),
+				None
+			),
+			Error::<Test>::InvalidReassignment
+		);
+
+		// Only `MaxDelegateReassignments` authorizations are scanned per call
+		assert_ok!(Space::reassign_delegate(
+			RawOrigin::Root.into(),
+			delegate.clone(),
+			new_delegate.clone(),
+			None,
+		));
+		assert!(DelegateReassignments::<Test>::contains_key(&delegate));
+
+		assert_err!(
+			Space::reassign_delegate(
+				RawOrigin::Root.into(),
+				delegate.clone(),
+				new_owner.clone(),
+				None,
+			),
+			Error::<Test>::ReassignmentInProgress
+		);
+
+		while DelegateReassignments::<Test>::contains_key(&delegate) {
+			assert_ok!(Space::reassign_delegate(
+				RawOrigin::Root.into(),
+				delegate.clone(),
+				new_delegate.clone(),
+				None,
+			));
+		}
+
+		assert!(!Authorizations::<Test>::contains_key(&delegate_authorization_id));
+		assert_eq!(
+			Authorizations::<Test>::get(&new_delegate_authorization_id),
+			Some(SpaceAuthorizationOf::<Test> {
+				space_id: space_id.clone(),
+				delegate: new_delegate.clone(),
+				permissions: Permissions::ASSERT,
+				delegator: creator.clone(),
+			})
+		);
+		assert!(!Space::is_a_delegate(&space_id, delegate.clone()));
+		assert!(Space::is_a_delegate(&space_id, new_delegate.clone()));
+		assert_ok!(Space::ensure_authorization_origin(
+			&new_delegate_authorization_id,
+			&new_delegate
+		));
+
+		// The authorization of the owner is moved with the ownership
+		loop {
+			assert_ok!(Space::reassign_delegate(
+				RawOrigin::Root.into(),
+				creator.clone(),
+				new_owner.clone(),
+				Some(space_id.clone()),
+			));
+			if !DelegateReassignments::<Test>::contains_key(&creator) {
+				break
+			}
+		}
+
+		assert_eq!(Spaces::<Test>::get(&space_id).map(|details| details.creator), Some(new_owner));
+		assert!(!Authorizations::<Test>::contains_key(&authorization_id));
+		assert!(Authorizations::<Test>::contains_key(&new_owner_authorization_id));
+	});
+}
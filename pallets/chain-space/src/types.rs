@@ -32,6 +32,7 @@ bitflags! {
 		const ASSERT = 0b0000_0001;
 		const DELEGATE = 0b0000_0010;
 		const ADMIN = 0b0000_0100;
+		const REVOKE = 0b0000_1000;
 	}
 }
 
@@ -108,3 +109,64 @@ pub struct SpaceAuthorization<SpaceIdOf, SpaceCreatorOf, Permissions> {
 	pub permissions: Permissions,
 	pub delegator: SpaceCreatorOf,
 }
+
+/// A space delegate as returned to clients, combining the authorization
+/// details with the authorization identifier and its validity period.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SpaceDelegateInfo<AuthorizationIdOf, SpaceCreatorOf, Permissions, BlockNumber> {
+	/// The authorization identifier used by the delegate.
+	pub authorization: AuthorizationIdOf,
+	/// The entity that has been granted permissions within the space.
+	pub delegate: SpaceCreatorOf,
+	/// The permissions granted to the delegate.
+	pub permissions: Permissions,
+	/// The entity that granted the permissions.
+	pub delegator: SpaceCreatorOf,
+	/// Last block at which the authorization can be used, if time-bound.
+	pub valid_until: Option<BlockNumber>,
+}
+
+/// Usage summary of a space, as reported by the `ChainSpaceApi`.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SpaceUsage<StatusOf> {
+	/// The transaction capacity approved for the space.
+	pub capacity: u64,
+	/// The part of the capacity allocated to subspaces.
+	pub reserved: u64,
+	/// The number of transactions anchored against the capacity.
+	pub consumed: u64,
+	/// The number of delegates of the space.
+	pub delegates: u32,
+	/// Indicates whether the space is approved.
+	pub approved: StatusOf,
+	/// Indicates whether the space is archived.
+	pub archived: StatusOf,
+	/// Indicates whether anchoring under the space is frozen.
+	pub frozen: StatusOf,
+}
+
+/// Progress of a delegate reassignment spanning several calls.
+///
+/// Authorizations are scanned in storage order, and `last` holds the last
+/// authorization scanned so that the next call resumes right after it.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct DelegateReassignment<SpaceCreatorOf, SpaceIdOf, AuthorizationIdOf> {
+	/// Identity the authorizations are moved to.
+	pub new: SpaceCreatorOf,
+	/// Space the reassignment is limited to, if any.
+	pub space: Option<SpaceIdOf>,
+	/// Last authorization scanned.
+	pub last: AuthorizationIdOf,
+}
+
+/// The authority that froze a space or schema.
+///
+/// A freeze by the council can only be lifted by the council, so that a
+/// suspected compromised admin key cannot undo it.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum FreezeAuthority {
+	/// Frozen by an admin of the space.
+	Admin,
+	/// Frozen through `ChainSpaceOrigin`.
+	Council,
+}
@@ -62,6 +62,15 @@ pub trait WeightInfo {
 	fn approval_revoke() -> Weight;
 	fn approval_restore() -> Weight;
 	fn subspace_create() -> Weight;
+	fn add_revoke_delegate() -> Weight;
+	fn transfer_ownership() -> Weight;
+	fn accept_ownership() -> Weight;
+	fn force_transfer_ownership() -> Weight;
+	fn freeze() -> Weight;
+	fn unfreeze() -> Weight;
+	fn force_freeze() -> Weight;
+	fn force_unfreeze() -> Weight;
+	fn reassign_delegate(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_chain_space` using the CORD node and recommended hardware.
@@ -75,14 +84,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The read and write of `ChainSpace::AuthorizationExpiry` are added by hand
+	/// and have not been benchmarked yet.
 	fn add_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_470_000 picoseconds.
 		Weight::from_parts(41_550_000, 323533)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -264,6 +275,73 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn add_revoke_delegate() -> Weight {
+		Weight::from_parts(40_140_000, 323533)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn transfer_ownership() -> Weight {
+		Weight::from_parts(15_730_000, 3671)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(43_210_000, 323533)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_transfer_ownership() -> Weight {
+		Weight::from_parts(40_620_000, 323533)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn freeze() -> Weight {
+		Weight::from_parts(28_040_000, 3671)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn unfreeze() -> Weight {
+		Weight::from_parts(28_310_000, 3671)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_freeze() -> Weight {
+		Weight::from_parts(22_180_000, 3671)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_unfreeze() -> Weight {
+		Weight::from_parts(22_410_000, 3671)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn reassign_delegate(n: u32, ) -> Weight {
+		Weight::from_parts(10_540_000, 3649)
+			.saturating_add(Weight::from_parts(38_260_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 322543).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -276,14 +354,16 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The read and write of `ChainSpace::AuthorizationExpiry` are added by hand
+	/// and have not been benchmarked yet.
 	fn add_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_470_000 picoseconds.
 		Weight::from_parts(41_550_000, 323533)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -465,4 +545,71 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn add_revoke_delegate() -> Weight {
+		Weight::from_parts(40_140_000, 323533)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn transfer_ownership() -> Weight {
+		Weight::from_parts(15_730_000, 3671)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(43_210_000, 323533)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_transfer_ownership() -> Weight {
+		Weight::from_parts(40_620_000, 323533)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn freeze() -> Weight {
+		Weight::from_parts(28_040_000, 3671)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn unfreeze() -> Weight {
+		Weight::from_parts(28_310_000, 3671)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_freeze() -> Weight {
+		Weight::from_parts(22_180_000, 3671)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_unfreeze() -> Weight {
+		Weight::from_parts(22_410_000, 3671)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn reassign_delegate(n: u32, ) -> Weight {
+		Weight::from_parts(10_540_000, 3649)
+			.saturating_add(Weight::from_parts(38_260_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 322543).saturating_mul(n.into()))
+	}
 }
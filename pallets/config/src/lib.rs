@@ -15,24 +15,76 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-//! # Network Membership Manager
+//! # Network Parameters
+//!
+//! Network wide configuration that can be changed by governance without a
+//! runtime upgrade.
+//!
+//! - **Permissioned Network**: Set at genesis, tells other pallets whether the network is
+//!   permissioned.
+//! - **Call Groups**: Groups of calls, such as balance transfers or contract calls, can be disabled
+//!   with `set_call_group`. Each runtime decides which calls belong to a group and checks
+//!   [`Pallet::is_enabled`] from its `BaseCallFilter`, so the same runtime can serve both
+//!   permissioned and open networks.
 #![warn(unused_extern_crates)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
 
-// use frame_support::traits::Get;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::Get;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::marker::PhantomData;
+
+/// A group of calls that can be disabled network wide.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum CallGroup {
+	/// Transfers of the native currency between accounts.
+	BalanceTransfers,
+	/// Uploading, instantiating and calling smart contracts.
+	Contracts,
+	/// Creating, transferring and swapping fungible assets.
+	Assets,
+}
+
+/// A limit that governance can tune without a runtime upgrade.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum NetworkLimit {
+	/// Maximum number of digests in a statement batch.
+	MaxDigestsPerBatch,
+	/// Maximum number of anchoring calls a space may record in one block.
+	MaxSpaceAnchorsPerBlock,
+	/// Maximum number of anchoring calls a delegate may record in one block.
+	MaxDelegateAnchorsPerBlock,
+}
+
+/// Markers selecting a [`NetworkLimit`] for [`ConfiguredLimit`].
+pub mod limits {
+	use super::NetworkLimit;
+
+	frame_support::parameter_types! {
+		pub const DigestsPerBatch: NetworkLimit = NetworkLimit::MaxDigestsPerBatch;
+		pub const SpaceAnchorsPerBlock: NetworkLimit = NetworkLimit::MaxSpaceAnchorsPerBlock;
+		pub const DelegateAnchorsPerBlock: NetworkLimit = NetworkLimit::MaxDelegateAnchorsPerBlock;
+	}
+}
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::{pallet_prelude::*, traits::StorageVersion};
-	use sp_std::marker::PhantomData;
+	use frame_system::pallet_prelude::*;
 	/// The current storage version.
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {}
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Origin allowed to enable and disable call groups and to set
+		/// network limits.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+	}
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -42,6 +94,18 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NetworkPermissioned<T> = StorageValue<_, bool, ValueQuery>;
 
+	/// Call groups disabled by governance.
+	/// It maps from a call group to nothing. Groups without an entry are
+	/// enabled.
+	#[pallet::storage]
+	pub type DisabledCallGroups<T> = StorageMap<_, Twox64Concat, CallGroup, (), OptionQuery>;
+
+	/// Network limits overridden by governance.
+	/// It maps from a limit to its value. Limits without an entry use the
+	/// value compiled into the runtime.
+	#[pallet::storage]
+	pub type NetworkLimits<T> = StorageMap<_, Twox64Concat, NetworkLimit, u32, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -55,6 +119,81 @@ pub mod pallet {
 			NetworkPermissioned::<T>::put(&self.permissioned);
 		}
 	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A call group has been enabled or disabled.
+		CallGroupSet { group: CallGroup, enabled: bool },
+		/// A network limit has been overridden, or reset to the runtime
+		/// value if `value` is `None`.
+		LimitSet { limit: NetworkLimit, value: Option<u32> },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Enable or disable a group of calls network wide.
+		///
+		/// Calls of a disabled group are rejected by the runtime's call
+		/// filter until the group is enabled again.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: Must be `UpdateOrigin`.
+		/// * `group`: The call group to update.
+		/// * `enabled`: Whether calls of the group may be dispatched.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_call_group(
+			origin: OriginFor<T>,
+			group: CallGroup,
+			enabled: bool,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if enabled {
+				DisabledCallGroups::<T>::remove(group);
+			} else {
+				DisabledCallGroups::<T>::insert(group, ());
+			}
+
+			Self::deposit_event(Event::CallGroupSet { group, enabled });
+
+			Ok(())
+		}
+
+		/// Override a network limit, or reset it to the value compiled into
+		/// the runtime.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: Must be `UpdateOrigin`.
+		/// * `limit`: The limit to update.
+		/// * `value`: The new value of the limit, or `None` to reset it.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_limit(
+			origin: OriginFor<T>,
+			limit: NetworkLimit,
+			value: Option<u32>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			NetworkLimits::<T>::set(limit, value);
+
+			Self::deposit_event(Event::LimitSet { limit, value });
+
+			Ok(())
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -62,6 +201,30 @@ impl<T: Config> Pallet<T> {
 	pub fn is_permissioned() -> bool {
 		NetworkPermissioned::<T>::get()
 	}
+
+	/// Returns `true` unless governance disabled the call group.
+	pub fn is_enabled(group: CallGroup) -> bool {
+		!DisabledCallGroups::<T>::contains_key(group)
+	}
+}
+
+/// A `Get` adapter reading the network limit selected by `L` from
+/// `NetworkLimits`, or `D` when governance has not overridden it.
+///
+/// `u16` limits saturate at `u16::MAX`.
+pub struct ConfiguredLimit<T, L, D>(PhantomData<(T, L, D)>);
+
+impl<T: Config, L: Get<NetworkLimit>, D: Get<u32>> Get<u32> for ConfiguredLimit<T, L, D> {
+	fn get() -> u32 {
+		NetworkLimits::<T>::get(L::get()).unwrap_or_else(D::get)
+	}
+}
+
+impl<T: Config, L: Get<NetworkLimit>, D: Get<u16>> Get<u16> for ConfiguredLimit<T, L, D> {
+	fn get() -> u16 {
+		NetworkLimits::<T>::get(L::get())
+			.map_or_else(D::get, |value| value.min(u16::MAX.into()) as u16)
+	}
 }
 
 impl<T: Config> cord_primitives::IsPermissioned for Pallet<T> {
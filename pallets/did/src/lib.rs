@@ -1172,6 +1172,22 @@ pub mod pallet {
 			operation: &DidAuthorizedCallOperationWithVerificationRelationship<T>,
 			signature: &DidSignature,
 		) -> Result<(), DidError> {
+			let did_details = Self::verify_did_operation_signature(operation, signature)?;
+
+			Did::<T>::insert(&operation.did, did_details);
+
+			Ok(())
+		}
+
+		/// Verify the validity (i.e., nonce, signature and mortality) of a
+		/// DID-authorized operation without changing the DID state.
+		///
+		/// Returns the DID details with the tx counter increased, ready to be
+		/// stored once the operation is accepted.
+		pub fn verify_did_operation_signature(
+			operation: &DidAuthorizedCallOperationWithVerificationRelationship<T>,
+			signature: &DidSignature,
+		) -> Result<DidDetails<T>, DidError> {
 			// Check that the tx has not expired.
 			Self::validate_block_number_value(operation.block_number)?;
 
@@ -1189,9 +1205,7 @@ pub mod pallet {
 				operation.verification_key_relationship,
 			)?;
 
-			Did::<T>::insert(&operation.did, did_details);
-
-			Ok(())
+			Ok(did_details)
 		}
 
 		/// Verify that [account] is authorized to dispatch DID calls on behave
@@ -21,7 +21,7 @@
 use crate as pallet_did;
 use codec::{Decode, Encode};
 use cord_utilities::mock::*;
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{derive_impl, parameter_types, traits::ConstU32};
 use frame_system::EnsureRoot;
 use pallet_chain_space::IsPermissioned;
 use scale_info::TypeInfo;
@@ -148,6 +148,11 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type CapacityAlertThresholds = ();
+	type MaxSpaceAnchorsPerBlock = ConstU32<0>;
+	type MaxDelegateAnchorsPerBlock = ConstU32<0>;
+	type MaxExpiryScanPerBlock = ConstU32<0>;
+	type MaxDelegateReassignments = ConstU32<0>;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type WeightInfo = ();
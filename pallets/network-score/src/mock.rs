@@ -19,7 +19,10 @@
 use super::*;
 use crate as pallet_score;
 use cord_utilities::mock::{mock_origin, SubjectId};
-use frame_support::{derive_impl, parameter_types, traits::ConstU64};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
 use frame_system::EnsureRoot;
 use pallet_chain_space::IsPermissioned;
 use sp_runtime::{
@@ -97,6 +100,11 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type CapacityAlertThresholds = ();
+	type MaxSpaceAnchorsPerBlock = ConstU32<0>;
+	type MaxDelegateAnchorsPerBlock = ConstU32<0>;
+	type MaxExpiryScanPerBlock = ConstU32<0>;
+	type MaxDelegateReassignments = ConstU32<0>;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type WeightInfo = ();
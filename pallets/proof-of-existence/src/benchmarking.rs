@@ -0,0 +1,48 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	anchor {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let digest = [7u8; 32];
+		let block_number: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+		let timestamp = pallet_timestamp::Pallet::<T>::get();
+
+		/* leave room for exactly one more anchor in this block */
+		let anchored = T::MaxAnchorsPerBlock::get().saturating_sub(1);
+		AnchorsInBlock::<T>::insert(&caller, (block_number, anchored));
+
+	}: _(RawOrigin::Signed(caller.clone()), digest)
+	verify {
+		assert_last_event::<T>(Event::Anchored { digest, owner: caller, block_number, timestamp }.into());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,180 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Proof of Existence Pallet
+//!
+//! A light pallet for trusted timestamping. Any account can anchor an
+//! arbitrary 32-byte digest, and the chain records who anchored it along
+//! with the block number and the timestamp of that block.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! Integrators that only need to prove that some data existed at a given
+//! point in time do not have to set up chain spaces, schemas or
+//! statements. The digest is computed off-chain with any hash function and
+//! anchored as is. Each digest can only be anchored once, so the first
+//! record is the authoritative one.
+//!
+//! To keep a single account from flooding a block, the number of digests an
+//! account can anchor within one block is limited by `MaxAnchorsPerBlock`.
+//!
+//! ## Interface
+//!
+//! - `anchor`: Records a digest together with the current block number and timestamp.
+//!
+//! Proofs can be looked up with [`Pallet::proof`], which is exposed through
+//! the `ProofOfExistenceApi` runtime API.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod types;
+pub mod weights;
+
+pub use crate::{pallet::*, types::*, weights::WeightInfo};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Digest anchored by an account.
+	pub type DigestOf = [u8; 32];
+	/// Type of an account anchoring digests.
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	/// Type of the on-chain time.
+	pub type MomentOf<T> = <T as pallet_timestamp::Config>::Moment;
+	/// Type for the record kept for an anchored digest.
+	pub type ProofOf<T> = Proof<AccountIdOf<T>, BlockNumberFor<T>, MomentOf<T>>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_timestamp::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Maximum number of digests a single account can anchor per block.
+		#[pallet::constant]
+		type MaxAnchorsPerBlock: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Anchored digests.
+	/// It maps from a digest to the record of when and by whom it was
+	/// anchored.
+	#[pallet::storage]
+	pub type Proofs<T: Config> = StorageMap<_, Identity, DigestOf, ProofOf<T>, OptionQuery>;
+
+	/// Number of digests anchored by an account in its most recent block.
+	/// The count is reset once the account anchors in a later block.
+	#[pallet::storage]
+	pub type AnchorsInBlock<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, (BlockNumberFor<T>, u32), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A digest has been anchored.
+		/// \[digest, owner, block number, timestamp\]
+		Anchored {
+			digest: DigestOf,
+			owner: AccountIdOf<T>,
+			block_number: BlockNumberFor<T>,
+			timestamp: MomentOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Digest has already been anchored.
+		ProofAlreadyAnchored,
+		/// Account has reached the number of anchors allowed in this block.
+		TooManyAnchorsInBlock,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Anchors a digest together with the current block number and
+		/// timestamp.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the account anchoring the
+		///   digest.
+		/// - `digest`: The 32-byte digest to anchor.
+		///
+		/// # Errors
+		/// - Returns `ProofAlreadyAnchored` if the digest has been anchored before.
+		/// - Returns `TooManyAnchorsInBlock` if the account already anchored `MaxAnchorsPerBlock`
+		///   digests in the current block.
+		///
+		/// # Events
+		/// - Emits `Anchored` on success.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::anchor())]
+		pub fn anchor(origin: OriginFor<T>, digest: DigestOf) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			ensure!(!<Proofs<T>>::contains_key(digest), Error::<T>::ProofAlreadyAnchored);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			let anchored = match <AnchorsInBlock<T>>::get(&owner) {
+				Some((block, count)) if block == block_number => count,
+				_ => 0,
+			};
+			ensure!(anchored < T::MaxAnchorsPerBlock::get(), Error::<T>::TooManyAnchorsInBlock);
+
+			let timestamp = pallet_timestamp::Pallet::<T>::get();
+
+			<Proofs<T>>::insert(
+				digest,
+				ProofOf::<T> { owner: owner.clone(), block_number, timestamp },
+			);
+			<AnchorsInBlock<T>>::insert(&owner, (block_number, anchored.saturating_add(1)));
+
+			Self::deposit_event(Event::Anchored { digest, owner, block_number, timestamp });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns the record of an anchored digest, or `None` if the digest
+	/// has not been anchored. Used by the `ProofOfExistenceApi` runtime API.
+	pub fn proof(digest: &DigestOf) -> Option<ProofOf<T>> {
+		<Proofs<T>>::get(digest)
+	}
+}
@@ -0,0 +1,69 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_err, assert_ok};
+use sp_runtime::AccountId32;
+
+const ACCOUNT_00: AccountId = AccountId32::new([1u8; 32]);
+const ACCOUNT_01: AccountId = AccountId32::new([2u8; 32]);
+
+#[test]
+fn anchoring_a_digest_should_record_block_and_timestamp() {
+	let digest = [7u8; 32];
+
+	new_test_ext().execute_with(|| {
+		Timestamp::set_timestamp(1_000);
+
+		assert_ok!(ProofOfExistence::anchor(RuntimeOrigin::signed(ACCOUNT_00), digest));
+
+		assert_eq!(
+			ProofOfExistence::proof(&digest),
+			Some(Proof { owner: ACCOUNT_00, block_number: 1, timestamp: 1_000 })
+		);
+		System::assert_last_event(
+			Event::Anchored { digest, owner: ACCOUNT_00, block_number: 1, timestamp: 1_000 }.into(),
+		);
+
+		// The first record stays authoritative
+		assert_err!(
+			ProofOfExistence::anchor(RuntimeOrigin::signed(ACCOUNT_01), digest),
+			Error::<Test>::ProofAlreadyAnchored
+		);
+	});
+}
+
+#[test]
+fn anchoring_more_than_allowed_in_a_block_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ProofOfExistence::anchor(RuntimeOrigin::signed(ACCOUNT_00), [1u8; 32]));
+		assert_ok!(ProofOfExistence::anchor(RuntimeOrigin::signed(ACCOUNT_00), [2u8; 32]));
+		assert_err!(
+			ProofOfExistence::anchor(RuntimeOrigin::signed(ACCOUNT_00), [3u8; 32]),
+			Error::<Test>::TooManyAnchorsInBlock
+		);
+
+		// Other accounts are not affected
+		assert_ok!(ProofOfExistence::anchor(RuntimeOrigin::signed(ACCOUNT_01), [3u8; 32]));
+
+		// The limit applies per block
+		System::set_block_number(2);
+		assert_ok!(ProofOfExistence::anchor(RuntimeOrigin::signed(ACCOUNT_00), [4u8; 32]));
+	});
+}
@@ -0,0 +1,59 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_proof_of_existence`.
+//!
+//! These weights have not been benchmarked yet. Storage reads and writes
+//! are counted from the calls, execution time and proof size are estimates.
+//! Regenerate this file with the benchmark CLI, see `docs/benchmarking.md`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_proof_of_existence`.
+pub trait WeightInfo {
+	fn anchor() -> Weight;
+}
+
+/// Weights for `pallet_proof_of_existence` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn anchor() -> Weight {
+		Weight::from_parts(14_802_000, 3553)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn anchor() -> Weight {
+		Weight::from_parts(14_802_000, 3553)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -20,6 +20,22 @@
 //!
 //! A wrapper pallet for frame_system set_code action. This call upgrades the
 //! runtime.
+//!
+//! Upgrades can also be staged in two steps. `authorize_upgrade` records the
+//! hash of the new runtime code, and `enact_authorized_upgrade` applies code
+//! matching that hash. The second step needs no privileged origin and may be
+//! submitted as an unsigned transaction, so the code blob itself does not
+//! have to pass through a governance motion.
+//!
+//! The pallet also drives lazy migrations: storage transformations too heavy
+//! for a single `on_runtime_upgrade` are written as
+//! [`SteppedMigration`](frame_support::migrations::SteppedMigration)s and
+//! listed in `Config::LazyMigrations`. After each runtime upgrade they are
+//! stepped through in order from `on_idle`, using only the weight left over
+//! in each block. Migrations are recorded once complete and are not run
+//! again by later upgrades. A migration still in progress when another
+//! upgrade is applied starts over from its first step, so steps have to be
+//! safe to repeat.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -27,8 +43,13 @@ pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::pallet_prelude::*;
+	use frame_support::{
+		migrations::{SteppedMigrationError, SteppedMigrations},
+		pallet_prelude::*,
+		weights::WeightMeter,
+	};
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Hash;
 	use sp_std::vec::Vec;
 
 	#[pallet::pallet]
@@ -37,8 +58,73 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Origin for runtime upgrades
 		type SetCodeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Multi-block migrations run from `on_idle` after a runtime upgrade.
+		type LazyMigrations: SteppedMigrations;
+	}
+
+	/// Progress of the lazy migration currently being run.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct ActiveMigration {
+		/// Position of the migration in `Config::LazyMigrations`.
+		pub index: u32,
+		/// Encoded cursor returned by the last step, `None` before the first.
+		pub cursor: Option<Vec<u8>>,
+		/// Number of steps taken so far.
+		pub steps: u32,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			Self::start_lazy_migrations()
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::progress_lazy_migrations(remaining_weight)
+		}
+	}
+
+	/// Hash of the runtime code authorized by `authorize_upgrade`, if any.
+	#[pallet::storage]
+	pub type AuthorizedUpgrade<T: Config> = StorageValue<_, T::Hash, OptionQuery>;
+
+	/// The lazy migration in progress, if any.
+	#[pallet::storage]
+	pub type ActiveLazyMigration<T: Config> = StorageValue<_, ActiveMigration, OptionQuery>;
+
+	/// Lazy migrations that have completed.
+	/// It maps from the encoded migration identifier to the block at which
+	/// the migration completed.
+	#[pallet::storage]
+	pub type CompletedLazyMigrations<T: Config> =
+		StorageMap<_, Twox64Concat, Vec<u8>, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A runtime upgrade was authorized.
+		UpgradeAuthorized { code_hash: T::Hash },
+		/// An authorized runtime upgrade was applied.
+		UpgradeEnacted { code_hash: T::Hash },
+		/// Lazy migrations were scheduled by a runtime upgrade.
+		LazyMigrationsStarted { count: u32 },
+		/// A lazy migration has completed.
+		LazyMigrationCompleted { index: u32, id: Vec<u8> },
+		/// A lazy migration failed and the remaining ones were abandoned.
+		LazyMigrationFailed { index: u32, id: Vec<u8> },
+		/// All scheduled lazy migrations have been run.
+		LazyMigrationsCompleted,
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No runtime upgrade has been authorized.
+		NothingAuthorized,
+		/// The submitted code does not match the authorized hash.
+		Unauthorized,
 	}
 
 	#[pallet::call]
@@ -51,5 +137,162 @@ pub mod pallet {
 			frame_system::Pallet::<T>::set_code(frame_system::RawOrigin::Root.into(), code)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Authorize a runtime upgrade to the code with hash `code_hash`.
+		///
+		/// A previously authorized hash is replaced. The code is applied
+		/// later through `enact_authorized_upgrade`.
+		#[pallet::call_index(1)]
+		#[pallet::weight((T::DbWeight::get().writes(1), DispatchClass::Operational))]
+		pub fn authorize_upgrade(origin: OriginFor<T>, code_hash: T::Hash) -> DispatchResult {
+			T::SetCodeOrigin::ensure_origin(origin)?;
+
+			<AuthorizedUpgrade<T>>::put(code_hash);
+
+			Self::deposit_event(Event::UpgradeAuthorized { code_hash });
+
+			Ok(())
+		}
+
+		/// Apply the authorized runtime upgrade.
+		///
+		/// Any origin, including an unsigned transaction, may submit the code
+		/// once its hash has been authorized. The usual `set_code` checks on
+		/// the runtime version still apply, and the authorization is removed
+		/// once the upgrade is applied.
+		#[pallet::call_index(2)]
+		#[pallet::weight((T::BlockWeights::get().base_block, DispatchClass::Operational))]
+		pub fn enact_authorized_upgrade(
+			_origin: OriginFor<T>,
+			code: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let code_hash = Self::ensure_authorized(&code)?;
+
+			frame_system::Pallet::<T>::set_code(frame_system::RawOrigin::Root.into(), code)?;
+			<AuthorizedUpgrade<T>>::kill();
+
+			Self::deposit_event(Event::UpgradeEnacted { code_hash });
+
+			Ok(Pays::No.into())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::enact_authorized_upgrade { code } = call else {
+				return InvalidTransaction::Call.into();
+			};
+
+			let code_hash = Self::ensure_authorized(code).map_err(|_| InvalidTransaction::Call)?;
+
+			ValidTransaction::with_tag_prefix("RuntimeUpgrade")
+				.and_provides(code_hash)
+				.priority(TransactionPriority::MAX)
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Checks that `code` matches the authorized upgrade and returns its
+		/// hash.
+		fn ensure_authorized(code: &[u8]) -> Result<T::Hash, Error<T>> {
+			let authorized = <AuthorizedUpgrade<T>>::get().ok_or(Error::<T>::NothingAuthorized)?;
+			let code_hash = T::Hashing::hash(code);
+			ensure!(code_hash == authorized, Error::<T>::Unauthorized);
+			Ok(code_hash)
+		}
+
+		/// Schedules the configured lazy migrations from the first one. An
+		/// unfinished migration of an earlier upgrade is restarted.
+		fn start_lazy_migrations() -> Weight {
+			let count = T::LazyMigrations::len();
+			if count == 0 {
+				<ActiveLazyMigration<T>>::kill();
+				return T::DbWeight::get().writes(1)
+			}
+
+			<ActiveLazyMigration<T>>::put(ActiveMigration { index: 0, cursor: None, steps: 0 });
+			Self::deposit_event(Event::LazyMigrationsStarted { count });
+
+			T::DbWeight::get().writes(2)
+		}
+
+		/// Steps through the scheduled lazy migrations until the weight
+		/// left in the block runs out. Completed migrations are skipped.
+		fn progress_lazy_migrations(remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+				return Weight::zero()
+			}
+			let Some(mut active) = <ActiveLazyMigration<T>>::get() else {
+				return T::DbWeight::get().reads(1)
+			};
+
+			loop {
+				let index = active.index;
+				let Some(id) = T::LazyMigrations::nth_id(index) else {
+					<ActiveLazyMigration<T>>::kill();
+					Self::deposit_event(Event::LazyMigrationsCompleted);
+					return meter.consumed()
+				};
+
+				if active.steps == 0 {
+					if meter.try_consume(T::DbWeight::get().reads(1)).is_err() {
+						break
+					}
+					if <CompletedLazyMigrations<T>>::contains_key(&id) {
+						active = ActiveMigration { index: index + 1, cursor: None, steps: 0 };
+						continue
+					}
+				}
+
+				let exceeded = T::LazyMigrations::nth_max_steps(index)
+					.flatten()
+					.is_some_and(|max_steps| active.steps >= max_steps);
+				let consumed = meter.consumed();
+				let step = if exceeded {
+					None
+				} else {
+					T::LazyMigrations::nth_transactional_step(
+						index,
+						active.cursor.clone(),
+						&mut meter,
+					)
+				};
+
+				match step {
+					Some(Ok(Some(cursor))) => {
+						active.cursor = Some(cursor);
+						active.steps = active.steps.saturating_add(1);
+						// A step that makes no use of the weight would loop forever.
+						if meter.consumed() == consumed {
+							break
+						}
+					},
+					Some(Ok(None)) => {
+						meter.consume(T::DbWeight::get().writes(1));
+						<CompletedLazyMigrations<T>>::insert(
+							&id,
+							frame_system::Pallet::<T>::block_number(),
+						);
+						Self::deposit_event(Event::LazyMigrationCompleted { index, id });
+						active = ActiveMigration { index: index + 1, cursor: None, steps: 0 };
+					},
+					Some(Err(SteppedMigrationError::InsufficientWeight { .. })) => break,
+					Some(Err(_)) | None => {
+						<ActiveLazyMigration<T>>::kill();
+						Self::deposit_event(Event::LazyMigrationFailed { index, id });
+						return meter.consumed()
+					},
+				}
+			}
+
+			<ActiveLazyMigration<T>>::put(active);
+			meter.consumed()
+		}
 	}
 }
@@ -22,7 +22,11 @@ use super::*;
 use codec::Encode;
 use cord_utilities::traits::GenerateBenchmarkOrigin;
 use frame_benchmarking::{account, benchmarks};
-use frame_support::{sp_runtime::traits::Hash, traits::Get, BoundedVec};
+use frame_support::{
+	sp_runtime::traits::{Bounded, Hash},
+	traits::{Currency, Get},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use sp_std::{
 	convert::{TryFrom, TryInto},
@@ -60,6 +64,7 @@ benchmarks! {
 		let l in 1 .. T::MaxEncodedSchemaLength::get();
 
 		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 		let did: T::SchemaCreatorId = account("did", 0, SEED);
 		let did1: T::SpaceCreatorId = account("did", 0, SEED);
 		let capacity = 3u64;
@@ -96,6 +101,330 @@ benchmarks! {
 	verify {
 		assert_last_event::<T>(Event::<T>::Created { identifier: schema_id, creator: did1 }.into());
 	}
+
+	version_of {
+		let l in 1 .. T::MaxEncodedSchemaLength::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let did1: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let parent_schema: InputSchemaOf<T> = BoundedVec::try_from([1u8; 8].to_vec())
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let parent_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&parent_schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let parent_id: SchemaIdOf = generate_schema_id::<T>(&parent_id_digest);
+
+		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(l.try_into().unwrap()).collect();
+		let schema = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+		Pallet::<T>::create(origin.clone(), parent_schema, authorization_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(origin, parent_id.clone(), schema, authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Versioned { identifier: schema_id, parent: parent_id, creator: did1 }.into());
+	}
+
+	deprecate {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let did1: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(T::MaxEncodedSchemaLength::get() as usize).collect();
+		let schema: InputSchemaOf<T> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+		Pallet::<T>::create(origin.clone(), schema, authorization_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(origin, schema_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Deprecated { identifier: schema_id, author: did1 }.into());
+	}
+	create_from_preimage {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let did1: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(T::MaxEncodedSchemaLength::get() as usize).collect();
+		let schema_hash = T::Preimages::note(raw_schema.into()).expect("Preimage should be noted.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema_hash.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+	}: _<T::RuntimeOrigin>(origin, schema_hash, authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Created { identifier: schema_id, creator: did1 }.into());
+	}
+	set_digest_policy {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let did1: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(T::MaxEncodedSchemaLength::get() as usize).collect();
+		let schema: InputSchemaOf<T> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+		Pallet::<T>::create(origin.clone(), schema, authorization_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(origin, schema_id.clone(), DigestPolicy::Unique, authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::DigestPolicySet { identifier: schema_id, policy: DigestPolicy::Unique, author: did1 }.into());
+	}
+
+	freeze {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(T::MaxEncodedSchemaLength::get() as usize).collect();
+		let schema: InputSchemaOf<T> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+		Pallet::<T>::create(origin.clone(), schema, authorization_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(origin, schema_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Frozen { identifier: schema_id, authority: FreezeAuthority::Admin }.into());
+	}
+
+	unfreeze {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(T::MaxEncodedSchemaLength::get() as usize).collect();
+		let schema: InputSchemaOf<T> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+		Pallet::<T>::create(origin.clone(), schema, authorization_id.clone())?;
+		Pallet::<T>::freeze(origin.clone(), schema_id.clone(), authorization_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(origin, schema_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Unfrozen { identifier: schema_id, authority: FreezeAuthority::Admin }.into());
+	}
+
+	force_freeze {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(T::MaxEncodedSchemaLength::get() as usize).collect();
+		let schema: InputSchemaOf<T> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+		Pallet::<T>::create(origin.clone(), schema, authorization_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(RawOrigin::Root.into(), schema_id.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::Frozen { identifier: schema_id, authority: FreezeAuthority::Council }.into());
+	}
+
+	force_unfreeze {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(T::MaxEncodedSchemaLength::get() as usize).collect();
+		let schema: InputSchemaOf<T> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+		Pallet::<T>::create(origin.clone(), schema, authorization_id.clone())?;
+		Pallet::<T>::force_freeze(RawOrigin::Root.into(), schema_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(RawOrigin::Root.into(), schema_id.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::Unfrozen { identifier: schema_id, authority: FreezeAuthority::Council }.into());
+	}
+
 	impl_benchmark_test_suite! (
 		Pallet,
 		crate::mock::new_test_ext(),
@@ -32,6 +32,22 @@
 //!   semantics, of the set of claims comprising a Stream/Verifiable Credential. A shared Schema
 //!   allows all parties to reference data in a known way. An identifier can optionally link to a
 //!   valid schema identifier.
+//! - **Schema Version:**: A schema anchored with `version_of` supersedes an existing schema in the
+//!   same space. Each schema can be superseded once, so following the links from any version yields
+//!   a single lineage from the first version to the latest one.
+//! - **Deprecated Schema:**: A schema marked with `deprecate` can no longer be referenced by new
+//!   statements. Statements already anchored against it remain valid and verifiable.
+//! - **Preimage Schema:**: A schema too large to be anchored inline can be noted as a preimage and
+//!   anchored with `create_from_preimage`. Only the preimage hash is kept in the schema entry,
+//!   while the full definition stays retrievable on chain through `schema_definition`.
+//! - **Digest Policy:**: A schema declares with `set_digest_policy` whether statements anchored
+//!   under it may share a digest across controllers, or whether each digest must be anchored only
+//!   once.
+//! - **Frozen Schema:**: A schema frozen with `freeze` by a space admin, or with `force_freeze` by
+//!   the council, cannot anchor or update statements until it is unfrozen. A council freeze can
+//!   only be lifted by the council.
+//! - **Schema Deposit:**: Anchoring a schema reserves a deposit from the paying account, made of
+//!   `SchemaDepositBase` plus `SchemaDepositPerByte` for each byte of the stored schema entry.
 //!
 //! ## Assumptions
 //!
@@ -52,17 +68,30 @@ pub mod benchmarking;
 #[cfg(test)]
 pub mod tests;
 
+use codec::Encode;
+use cord_primitives::{AnchorRefusal, AuthorizeAnchor};
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::traits::UniqueSaturatedInto;
+pub use pallet_chain_space::FreezeAuthority;
+use sp_runtime::{
+	traits::{Hash, UniqueSaturatedInto},
+	DispatchResult,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
 
 /// Extra Types for Schema
 pub mod types;
 
+/// Storage migrations for Schemas
+pub mod migrations;
+
 pub use crate::{types::*, weights::WeightInfo};
-use frame_support::ensure;
+use frame_support::{
+	ensure,
+	traits::{Currency, Get, QueryPreimage, ReservableCurrency, StorePreimage},
+};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -71,10 +100,10 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 	pub use identifier::{IdentifierCreator, IdentifierTimeline, IdentifierType, Ss58Identifier};
-	use sp_runtime::{traits::Hash, SaturatedConversion};
+	use sp_runtime::SaturatedConversion;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	/// Space Identifier
 	pub type SpaceIdOf = Ss58Identifier;
@@ -82,6 +111,8 @@ pub mod pallet {
 	pub type SchemaIdOf = Ss58Identifier;
 	/// Authorization Identifier
 	pub type AuthorizationIdOf = Ss58Identifier;
+	/// Code of the space a schema is anchored in.
+	pub type SpaceCodeOf<T> = pallet_chain_space::SpaceCodeOf<T>;
 	/// Hash of the schema.
 	pub type SchemaHashOf<T> = <T as frame_system::Config>::Hash;
 	/// Type of a CORD account.
@@ -93,6 +124,14 @@ pub mod pallet {
 	/// Type for a schema entry
 	pub type SchemaEntryOf<T> =
 		SchemaEntry<InputSchemaOf<T>, SchemaHashOf<T>, SchemaCreatorOf<T>, SpaceIdOf>;
+	/// Type of the balance used for schema deposits
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	/// Type of the deposit held for a schema
+	pub type SchemaDepositOf<T> = SchemaDeposit<AccountIdOf<T>, BalanceOf<T>>;
+	/// Type for the schema details exposed to verifiers
+	pub type SchemaInfoOf<T> =
+		SchemaInfo<SchemaIdOf, SchemaEntryOf<T>, BlockNumberFor<T>, FreezeAuthority, EventEntryOf>;
 
 	#[pallet::config]
 	pub trait Config:
@@ -107,6 +146,19 @@ pub mod pallet {
 		type SchemaCreatorId: Parameter + MaxEncodedLen;
 		#[pallet::constant]
 		type MaxEncodedSchemaLength: Get<u32>;
+		/// Maximum length in bytes of a schema anchored from a preimage
+		#[pallet::constant]
+		type MaxPreimageSchemaLength: Get<u32>;
+		/// The preimage provider holding the definitions of preimage schemas.
+		type Preimages: QueryPreimage<H = <Self as frame_system::Config>::Hashing> + StorePreimage;
+		/// Currency used to hold schema deposits
+		type Currency: ReservableCurrency<AccountIdOf<Self>>;
+		/// Base deposit held for each anchored schema
+		#[pallet::constant]
+		type SchemaDepositBase: Get<BalanceOf<Self>>;
+		/// Additional deposit held per byte of the stored schema entry
+		#[pallet::constant]
+		type SchemaDepositPerByte: Get<BalanceOf<Self>>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -122,12 +174,94 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Schemas<T> = StorageMap<_, Blake2_128Concat, SchemaIdOf, SchemaEntryOf<T>>;
 
+	/// schema versions stored on chain.
+	/// It maps from a schema identifier to the version it supersedes.
+	#[pallet::storage]
+	pub type SchemaParent<T> = StorageMap<_, Blake2_128Concat, SchemaIdOf, SchemaIdOf>;
+
+	/// schema versions stored on chain.
+	/// It maps from a schema identifier to the version superseding it.
+	#[pallet::storage]
+	pub type SchemaSuccessor<T> = StorageMap<_, Blake2_128Concat, SchemaIdOf, SchemaIdOf>;
+
+	/// deprecated schemas stored on chain.
+	/// It maps from a schema identifier to the block it was deprecated at.
+	#[pallet::storage]
+	pub type DeprecatedSchemas<T: Config> =
+		StorageMap<_, Blake2_128Concat, SchemaIdOf, BlockNumberFor<T>>;
+
+	/// schemas anchored from a preimage.
+	/// It maps from a schema identifier to the length of the preimage
+	/// holding its definition.
+	#[pallet::storage]
+	pub type PreimageSchemas<T> = StorageMap<_, Blake2_128Concat, SchemaIdOf, u32>;
+
+	/// deposits held for anchored schemas.
+	/// It maps from a schema identifier to the account the deposit was
+	/// reserved from and the amount reserved. Genesis schemas have no entry.
+	#[pallet::storage]
+	pub type SchemaDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, SchemaIdOf, SchemaDepositOf<T>>;
+
+	/// digest policies declared by schemas.
+	/// It maps from a schema identifier to its policy. Schemas without a
+	/// declared policy allow shared digests.
+	#[pallet::storage]
+	pub type DigestPolicies<T> =
+		StorageMap<_, Blake2_128Concat, SchemaIdOf, DigestPolicy, ValueQuery>;
+
+	/// frozen schemas stored on chain.
+	/// It maps from a schema identifier to the authority that froze it.
+	/// Statements cannot be anchored or updated under a frozen schema.
+	#[pallet::storage]
+	pub type FrozenSchemas<T> = StorageMap<_, Blake2_128Concat, SchemaIdOf, FreezeAuthority>;
+
+	/// schema identifiers stored on chain by schema hash.
+	/// It maps from a schema hash and identifier to nothing. The same hash
+	/// is anchored once per space and creator, so a hash may resolve to
+	/// several schemas.
+	#[pallet::storage]
+	pub type SchemaLookup<T> =
+		StorageDoubleMap<_, Blake2_128Concat, SchemaHashOf<T>, Blake2_128Concat, SchemaIdOf, ()>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Schemas anchored at genesis, as `(space code, creator, schema)`.
+		/// The space must be seeded at genesis by the same creator.
+		pub schemas: Vec<(SpaceCodeOf<T>, SchemaCreatorOf<T>, Vec<u8>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (space_code, creator, schema) in &self.schemas {
+				Pallet::<T>::genesis_schema(space_code, creator, schema.clone())
+					.expect("Genesis schemas must be unique and anchored in a genesis space");
+			}
+		}
+	}
+
 	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new schema has been created.
 		/// \[schema identifier, digest, author\]
 		Created { identifier: SchemaIdOf, creator: SchemaCreatorOf<T> },
+		/// A new version of an existing schema has been created.
+		/// \[schema identifier, parent schema identifier, author\]
+		Versioned { identifier: SchemaIdOf, parent: SchemaIdOf, creator: SchemaCreatorOf<T> },
+		/// A schema has been deprecated.
+		/// \[schema identifier, author\]
+		Deprecated { identifier: SchemaIdOf, author: SchemaCreatorOf<T> },
+		/// A schema has declared its digest policy.
+		/// \[schema identifier, policy, author\]
+		DigestPolicySet { identifier: SchemaIdOf, policy: DigestPolicy, author: SchemaCreatorOf<T> },
+		/// A schema has been frozen.
+		/// \[schema identifier, authority\]
+		Frozen { identifier: SchemaIdOf, authority: FreezeAuthority },
+		/// A schema has been unfrozen.
+		/// \[schema identifier, authority\]
+		Unfrozen { identifier: SchemaIdOf, authority: FreezeAuthority },
 	}
 
 	#[pallet::error]
@@ -146,6 +280,26 @@ pub mod pallet {
 		MaxEncodedSchemaLimitExceeded,
 		/// Empty transaction.
 		EmptyTransaction,
+		/// Schema is not part of the authorized space.
+		SchemaSpaceMismatch,
+		/// Schema has already been superseded by a newer version.
+		SchemaAlreadyVersioned,
+		/// Schema has already been deprecated.
+		SchemaAlreadyDeprecated,
+		/// Schema preimage has not been noted.
+		SchemaPreimageNotFound,
+		/// Schema has already declared its digest policy.
+		DigestPolicyAlreadySet,
+		/// Schema is frozen.
+		Frozen,
+		/// Schema is already frozen.
+		AlreadyFrozen,
+		/// Schema is not frozen.
+		NotFrozen,
+		/// Schema was frozen by the council and can only be unfrozen by it.
+		FrozenByCouncil,
+		/// The payer cannot cover the deposit of the schema.
+		InsufficientDeposit,
 	}
 
 	#[pallet::call]
@@ -168,7 +322,8 @@ pub mod pallet {
 			tx_schema: InputSchemaOf<T>,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
-			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let sources = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let (payer, creator) = (sources.sender(), sources.subject());
 
 			ensure!(tx_schema.len() > 0, Error::<T>::EmptyTransaction);
 			ensure!(
@@ -176,60 +331,478 @@ pub mod pallet {
 				Error::<T>::MaxEncodedSchemaLimitExceeded
 			);
 
+			let space_id =
+				pallet_chain_space::Pallet::<T>::ensure_anchoring_origin(&authorization, &creator)
+					.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let identifier = Self::anchor_schema(tx_schema, &space_id, &creator, Some(&payer))?;
+
+			Self::deposit_indexed_event(
+				Event::Created { identifier: identifier.clone(), creator },
+				&identifier,
+				&space_id,
+			);
+
+			Ok(())
+		}
+
+		/// Create a new version of an existing schema.
+		///
+		/// The new schema is anchored in the same way as `create` and is
+		/// linked to `parent_schema`, which it supersedes. The parent must
+		/// belong to the space the caller is authorized for and must not have
+		/// been superseded already.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The origin of the transaction.
+		/// * `parent_schema`: The identifier of the schema being superseded.
+		/// * `tx_schema`: The new version of the schema.
+		/// * `authorization`: The authorization identifier of the creator.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::version_of(tx_schema.len().saturated_into()))]
+		pub fn version_of(
+			origin: OriginFor<T>,
+			parent_schema: SchemaIdOf,
+			tx_schema: InputSchemaOf<T>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let sources = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let (payer, creator) = (sources.sender(), sources.subject());
+
+			ensure!(tx_schema.len() > 0, Error::<T>::EmptyTransaction);
+			ensure!(
+				tx_schema.len() <= T::MaxEncodedSchemaLength::get() as usize,
+				Error::<T>::MaxEncodedSchemaLimitExceeded
+			);
+
+			let space_id =
+				pallet_chain_space::Pallet::<T>::ensure_anchoring_origin(&authorization, &creator)
+					.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let parent = <Schemas<T>>::get(&parent_schema).ok_or(Error::<T>::SchemaNotFound)?;
+			ensure!(parent.space == space_id, Error::<T>::SchemaSpaceMismatch);
+			ensure!(!Self::is_frozen(&parent_schema), Error::<T>::Frozen);
+			ensure!(
+				!<SchemaSuccessor<T>>::contains_key(&parent_schema),
+				Error::<T>::SchemaAlreadyVersioned
+			);
+
+			let identifier = Self::anchor_schema(tx_schema, &space_id, &creator, Some(&payer))?;
+
+			<SchemaParent<T>>::insert(&identifier, &parent_schema);
+			<SchemaSuccessor<T>>::insert(&parent_schema, &identifier);
+
+			Self::update_activity(&parent_schema, CallTypeOf::Update).map_err(<Error<T>>::from)?;
+
+			Self::deposit_indexed_event(
+				Event::Versioned { identifier: identifier.clone(), parent: parent_schema, creator },
+				&identifier,
+				&space_id,
+			);
+
+			Ok(())
+		}
+
+		/// Deprecate a schema.
+		///
+		/// A deprecated schema stays on chain and statements already
+		/// anchored against it remain valid, but new statements can no
+		/// longer reference it. This allows a format to be retired
+		/// gracefully while issuers migrate to a newer version.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The origin of the transaction.
+		/// * `schema_id`: The identifier of the schema to deprecate.
+		/// * `authorization`: The authorization identifier of the caller.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::deprecate())]
+		pub fn deprecate(
+			origin: OriginFor<T>,
+			schema_id: SchemaIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let author = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
 			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
 				&authorization,
-				&creator,
+				&author,
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
-			// Id Digest = concat (H(<scale_encoded_schema_input>,
-			// <<scale_encoded_space_identifier>, scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&tx_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()
-					[..],
+			let schema = <Schemas<T>>::get(&schema_id).ok_or(Error::<T>::SchemaNotFound)?;
+			ensure!(schema.space == space_id, Error::<T>::SchemaSpaceMismatch);
+			ensure!(
+				!<DeprecatedSchemas<T>>::contains_key(&schema_id),
+				Error::<T>::SchemaAlreadyDeprecated
 			);
 
-			let identifier = Ss58Identifier::create_identifier(
-				&(id_digest).encode()[..],
-				IdentifierType::Schema,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+			<DeprecatedSchemas<T>>::insert(&schema_id, frame_system::Pallet::<T>::block_number());
 
-			ensure!(!<Schemas<T>>::contains_key(&identifier), Error::<T>::SchemaAlreadyAnchored);
+			Self::update_activity(&schema_id, CallTypeOf::Archive).map_err(<Error<T>>::from)?;
 
-			let digest = <T as frame_system::Config>::Hashing::hash(&tx_schema[..]);
-			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_indexed_event(
+				Event::Deprecated { identifier: schema_id.clone(), author },
+				&schema_id,
+				&space_id,
+			);
 
-			log::debug!(
-				"Schema created with identifier: {:?}, schema: {:?} digest: {:?}, creator:
-			{:?}, block_number: {:?}",
-				identifier,
-				tx_schema,
-				digest,
-				creator,
-				block_number
+			Ok(())
+		}
+
+		/// Create a new schema from a noted preimage.
+		///
+		/// The schema definition must have been noted with the preimage
+		/// pallet beforehand. The preimage is requested so that it remains
+		/// available for as long as the schema is anchored, and the schema
+		/// entry only keeps its hash as the digest. The full definition can
+		/// be retrieved with `schema_definition`.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The origin of the transaction.
+		/// * `schema_hash`: The hash of the noted schema preimage.
+		/// * `authorization`: The authorization identifier of the creator.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_from_preimage())]
+		pub fn create_from_preimage(
+			origin: OriginFor<T>,
+			schema_hash: SchemaHashOf<T>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let sources = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let (payer, creator) = (sources.sender(), sources.subject());
+
+			let len = T::Preimages::len(&schema_hash).ok_or(Error::<T>::SchemaPreimageNotFound)?;
+			ensure!(len > 0, Error::<T>::EmptyTransaction);
+			ensure!(
+				len <= T::MaxPreimageSchemaLength::get(),
+				Error::<T>::MaxEncodedSchemaLimitExceeded
 			);
 
-			<Schemas<T>>::insert(
+			let space_id =
+				pallet_chain_space::Pallet::<T>::ensure_anchoring_origin(&authorization, &creator)
+					.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let identifier = Self::store_schema(
+				&schema_hash.encode(),
+				InputSchemaOf::<T>::default(),
+				schema_hash,
+				&space_id,
+				&creator,
+				Some(&payer),
+			)?;
+
+			<PreimageSchemas<T>>::insert(&identifier, len);
+			T::Preimages::request(&schema_hash);
+
+			Self::deposit_indexed_event(
+				Event::Created { identifier: identifier.clone(), creator },
 				&identifier,
-				SchemaEntryOf::<T> {
-					schema: tx_schema,
-					digest,
-					creator: creator.clone(),
-					space: space_id,
-				},
+				&space_id,
+			);
+
+			Ok(())
+		}
+
+		/// Declare whether statements anchored under a schema may share
+		/// digests.
+		///
+		/// Schemas allow shared digests until they declare a policy. With the
+		/// `Unique` policy, registering or updating a statement under the
+		/// schema fails if its digest is already anchored in any space, or
+		/// is held by a statement awaiting witness approvals. The policy can
+		/// be declared once and applies to statements anchored afterwards.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The origin of the transaction.
+		/// * `schema_id`: The identifier of the schema.
+		/// * `policy`: The digest policy of the schema.
+		/// * `authorization`: The authorization identifier of the caller.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_digest_policy())]
+		pub fn set_digest_policy(
+			origin: OriginFor<T>,
+			schema_id: SchemaIdOf,
+			policy: DigestPolicy,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let author = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&author,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let schema = <Schemas<T>>::get(&schema_id).ok_or(Error::<T>::SchemaNotFound)?;
+			ensure!(schema.space == space_id, Error::<T>::SchemaSpaceMismatch);
+			ensure!(!Self::is_frozen(&schema_id), Error::<T>::Frozen);
+			ensure!(
+				!<DigestPolicies<T>>::contains_key(&schema_id),
+				Error::<T>::DigestPolicyAlreadySet
 			);
 
-			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
+			<DigestPolicies<T>>::insert(&schema_id, policy);
+
+			Self::update_activity(&schema_id, CallTypeOf::Update).map_err(<Error<T>>::from)?;
 
-			Self::deposit_event(Event::Created { identifier, creator });
+			Self::deposit_indexed_event(
+				Event::DigestPolicySet { identifier: schema_id.clone(), policy, author },
+				&schema_id,
+				&space_id,
+			);
 
 			Ok(())
 		}
+
+		/// Freeze a schema.
+		///
+		/// Statements cannot be anchored or updated under a frozen schema
+		/// until it is unfrozen, while existing statements stay valid and
+		/// can still be revoked. This is meant for incident response, for
+		/// instance when an issuer key is suspected to be compromised.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The origin of the transaction.
+		/// * `schema_id`: The identifier of the schema to freeze.
+		/// * `authorization`: The admin authorization of the caller in the schema's space.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::freeze())]
+		pub fn freeze(
+			origin: OriginFor<T>,
+			schema_id: SchemaIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let author = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_admin_origin(
+				&authorization,
+				&author,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			Self::schema_freeze(schema_id, Some(space_id), FreezeAuthority::Admin)
+		}
+
+		/// Unfreeze a schema frozen by an admin of its space.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The origin of the transaction.
+		/// * `schema_id`: The identifier of the schema to unfreeze.
+		/// * `authorization`: The admin authorization of the caller in the schema's space.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unfreeze())]
+		pub fn unfreeze(
+			origin: OriginFor<T>,
+			schema_id: SchemaIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let author = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_admin_origin(
+				&authorization,
+				&author,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			Self::schema_unfreeze(schema_id, Some(space_id), FreezeAuthority::Admin)
+		}
+
+		/// Freeze a schema on behalf of the council. A schema frozen by the
+		/// council can only be unfrozen with `force_unfreeze`.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The `ChainSpaceOrigin` of the chain space pallet.
+		/// * `schema_id`: The identifier of the schema to freeze.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_freeze())]
+		pub fn force_freeze(origin: OriginFor<T>, schema_id: SchemaIdOf) -> DispatchResult {
+			<T as pallet_chain_space::Config>::ChainSpaceOrigin::ensure_origin(origin)?;
+
+			Self::schema_freeze(schema_id, None, FreezeAuthority::Council)
+		}
+
+		/// Unfreeze a schema on behalf of the council, whoever froze it.
+		///
+		/// Arguments:
+		///
+		/// * `origin`: The `ChainSpaceOrigin` of the chain space pallet.
+		/// * `schema_id`: The identifier of the schema to unfreeze.
+		///
+		/// Returns:
+		///
+		/// DispatchResult
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_unfreeze())]
+		pub fn force_unfreeze(origin: OriginFor<T>, schema_id: SchemaIdOf) -> DispatchResult {
+			<T as pallet_chain_space::Config>::ChainSpaceOrigin::ensure_origin(origin)?;
+
+			Self::schema_unfreeze(schema_id, None, FreezeAuthority::Council)
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Deposits a schema event indexed by the schema and its space. See
+	/// `pallet_chain_space::Pallet::identifier_topic` for how topics are
+	/// derived.
+	fn deposit_indexed_event(event: Event<T>, schema_id: &SchemaIdOf, space_id: &SpaceIdOf) {
+		let topics = [
+			pallet_chain_space::Pallet::<T>::identifier_topic(schema_id),
+			pallet_chain_space::Pallet::<T>::identifier_topic(space_id),
+		];
+
+		let event: <T as Config>::RuntimeEvent = event.into();
+		frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+	}
+
+	/// Anchors a schema seeded at genesis in the space `creator` seeded with
+	/// `space_code`.
+	fn genesis_schema(
+		space_code: &SpaceCodeOf<T>,
+		creator: &SchemaCreatorOf<T>,
+		schema: Vec<u8>,
+	) -> DispatchResult {
+		let tx_schema: InputSchemaOf<T> =
+			schema.try_into().map_err(|_| Error::<T>::MaxEncodedSchemaLimitExceeded)?;
+		ensure!(tx_schema.len() > 0, Error::<T>::EmptyTransaction);
+
+		let space_id = pallet_chain_space::Pallet::<T>::ensure_genesis_space(space_code, creator)?;
+
+		Self::anchor_schema(tx_schema, &space_id, creator, None)?;
+
+		Ok(())
+	}
+
+	/// Derives the schema identifier, stores the schema entry and records
+	/// the genesis activity. Shared by `create` and `version_of`.
+	fn anchor_schema(
+		tx_schema: InputSchemaOf<T>,
+		space_id: &SpaceIdOf,
+		creator: &SchemaCreatorOf<T>,
+		payer: Option<&AccountIdOf<T>>,
+	) -> Result<SchemaIdOf, Error<T>> {
+		let digest = <T as frame_system::Config>::Hashing::hash(&tx_schema[..]);
+
+		Self::store_schema(&tx_schema.encode(), tx_schema, digest, space_id, creator, payer)
+	}
+
+	/// Stores a schema entry under the identifier derived from `source`,
+	/// the space and the creator, and records the genesis activity. Inline
+	/// schemas are identified by the encoded schema, preimage schemas by
+	/// the encoded preimage hash. The deposit of the entry is reserved from
+	/// `payer`, if any.
+	fn store_schema(
+		source: &[u8],
+		tx_schema: InputSchemaOf<T>,
+		digest: SchemaHashOf<T>,
+		space_id: &SpaceIdOf,
+		creator: &SchemaCreatorOf<T>,
+		payer: Option<&AccountIdOf<T>>,
+	) -> Result<SchemaIdOf, Error<T>> {
+		// Id Digest = concat (H(<scale_encoded_schema_input>,
+		// <<scale_encoded_space_identifier>, scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[source, &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		let identifier =
+			Ss58Identifier::create_identifier(&(id_digest).encode()[..], IdentifierType::Schema)
+				.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		ensure!(!<Schemas<T>>::contains_key(&identifier), Error::<T>::SchemaAlreadyAnchored);
+
+		let block_number = frame_system::Pallet::<T>::block_number();
+
+		log::debug!(
+			"Schema created with identifier: {:?}, schema: {:?} digest: {:?}, creator:
+			{:?}, block_number: {:?}",
+			identifier,
+			tx_schema,
+			digest,
+			creator,
+			block_number
+		);
+
+		let entry = SchemaEntryOf::<T> {
+			schema: tx_schema,
+			digest,
+			creator: creator.clone(),
+			space: space_id.clone(),
+		};
+		if let Some(payer) = payer {
+			Self::hold_schema_deposit(&identifier, &entry, payer)?;
+		}
+		<SchemaLookup<T>>::insert(digest, &identifier, ());
+		<Schemas<T>>::insert(&identifier, entry);
+
+		Self::update_activity(&identifier, CallTypeOf::Genesis)?;
+
+		Ok(identifier)
+	}
+
+	/// Returns the deposit held for a schema with the given entry.
+	pub fn schema_deposit(entry: &SchemaEntryOf<T>) -> BalanceOf<T> {
+		let bytes: BalanceOf<T> = (entry.encoded_size() as u32).into();
+		T::SchemaDepositBase::get()
+			.saturating_add(T::SchemaDepositPerByte::get().saturating_mul(bytes))
+	}
+
+	/// Reserves the deposit of a new schema from `payer`. Nothing is
+	/// recorded when the deposit is zero. Schemas cannot be removed, so the
+	/// deposit is held for as long as the schema stays anchored.
+	fn hold_schema_deposit(
+		schema_id: &SchemaIdOf,
+		entry: &SchemaEntryOf<T>,
+		payer: &AccountIdOf<T>,
+	) -> Result<(), Error<T>> {
+		let amount = Self::schema_deposit(entry);
+		if amount.is_zero() {
+			return Ok(());
+		}
+		<T as Config>::Currency::reserve(payer, amount)
+			.map_err(|_| Error::<T>::InsufficientDeposit)?;
+		<SchemaDeposits<T>>::insert(
+			schema_id,
+			SchemaDepositOf::<T> { depositor: payer.clone(), amount },
+		);
+		Ok(())
+	}
+
 	/// `ensure!` is a macro that takes a boolean expression and an error type.
 	/// If the expression is false, it returns the error
 	///
@@ -245,6 +818,131 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Returns `true` if the schema has been deprecated and can no longer be
+	/// referenced by new statements.
+	pub fn is_deprecated(tx_ident: &SchemaIdOf) -> bool {
+		<DeprecatedSchemas<T>>::contains_key(tx_ident)
+	}
+
+	/// Returns `true` if statements cannot be anchored or updated under the
+	/// schema because it is frozen.
+	pub fn is_frozen(tx_ident: &SchemaIdOf) -> bool {
+		<FrozenSchemas<T>>::contains_key(tx_ident)
+	}
+
+	/// Freezes a schema on behalf of `authority`. Admins must belong to the
+	/// schema's space, given as `admin_space`. The council may take over a
+	/// freeze set by an admin, so that the admin can no longer lift it.
+	fn schema_freeze(
+		schema_id: SchemaIdOf,
+		admin_space: Option<SpaceIdOf>,
+		authority: FreezeAuthority,
+	) -> DispatchResult {
+		let schema = <Schemas<T>>::get(&schema_id).ok_or(Error::<T>::SchemaNotFound)?;
+		if let Some(space_id) = admin_space {
+			ensure!(schema.space == space_id, Error::<T>::SchemaSpaceMismatch);
+		}
+		ensure!(
+			<FrozenSchemas<T>>::get(&schema_id).map_or(true, |current| {
+				current == FreezeAuthority::Admin && authority == FreezeAuthority::Council
+			}),
+			Error::<T>::AlreadyFrozen
+		);
+
+		<FrozenSchemas<T>>::insert(&schema_id, authority);
+
+		Self::update_activity(&schema_id, CallTypeOf::Freeze)?;
+
+		Self::deposit_indexed_event(
+			Event::Frozen { identifier: schema_id.clone(), authority },
+			&schema_id,
+			&schema.space,
+		);
+
+		Ok(())
+	}
+
+	/// Lifts the freeze of a schema on behalf of `authority`. Admins must
+	/// belong to the schema's space and cannot lift a freeze set by the
+	/// council.
+	fn schema_unfreeze(
+		schema_id: SchemaIdOf,
+		admin_space: Option<SpaceIdOf>,
+		authority: FreezeAuthority,
+	) -> DispatchResult {
+		let schema = <Schemas<T>>::get(&schema_id).ok_or(Error::<T>::SchemaNotFound)?;
+		if let Some(space_id) = admin_space {
+			ensure!(schema.space == space_id, Error::<T>::SchemaSpaceMismatch);
+		}
+		let current = <FrozenSchemas<T>>::get(&schema_id).ok_or(Error::<T>::NotFrozen)?;
+		ensure!(
+			current == FreezeAuthority::Admin || authority == FreezeAuthority::Council,
+			Error::<T>::FrozenByCouncil
+		);
+
+		<FrozenSchemas<T>>::remove(&schema_id);
+
+		Self::update_activity(&schema_id, CallTypeOf::Unfreeze)?;
+
+		Self::deposit_indexed_event(
+			Event::Unfrozen { identifier: schema_id.clone(), authority },
+			&schema_id,
+			&schema.space,
+		);
+
+		Ok(())
+	}
+
+	/// Returns the digest policy of a schema. Schemas which have not
+	/// declared a policy allow shared digests.
+	pub fn digest_policy(tx_ident: &SchemaIdOf) -> DigestPolicy {
+		<DigestPolicies<T>>::get(tx_ident)
+	}
+
+	/// Returns the full definition of a schema, fetching it from the
+	/// preimage provider for schemas anchored with `create_from_preimage`.
+	/// Returns `None` if the schema does not exist or its preimage is not
+	/// available.
+	pub fn schema_definition(tx_ident: &SchemaIdOf) -> Option<Vec<u8>> {
+		let schema = <Schemas<T>>::get(tx_ident)?;
+		match <PreimageSchemas<T>>::get(tx_ident) {
+			Some(len) =>
+				T::Preimages::fetch(&schema.digest, Some(len)).ok().map(|b| b.into_owned()),
+			None => Some(schema.schema.into_inner()),
+		}
+	}
+
+	/// Returns a schema together with its status, version links and
+	/// activity timeline, or `None` if it does not exist. Used by the
+	/// `SchemaApi` runtime API.
+	pub fn schema_info(tx_ident: &SchemaIdOf) -> Option<SchemaInfoOf<T>> {
+		let details = <Schemas<T>>::get(tx_ident)?;
+		let timeline = identifier::Identifiers::<T>::get(tx_ident, IdentifierTypeOf::Schema)
+			.map(|events| events.into_inner())
+			.unwrap_or_default();
+
+		Some(SchemaInfoOf::<T> {
+			identifier: tx_ident.clone(),
+			details,
+			preimage: <PreimageSchemas<T>>::contains_key(tx_ident),
+			deprecated_at: <DeprecatedSchemas<T>>::get(tx_ident),
+			frozen_by: <FrozenSchemas<T>>::get(tx_ident),
+			digest_policy: <DigestPolicies<T>>::get(tx_ident),
+			parent: <SchemaParent<T>>::get(tx_ident),
+			successor: <SchemaSuccessor<T>>::get(tx_ident),
+			timeline,
+		})
+	}
+
+	/// Returns every schema anchored with the given schema hash, in no
+	/// particular order. For schemas anchored from a preimage this is the
+	/// preimage hash. Used by the `SchemaApi` runtime API.
+	pub fn schema_info_by_hash(digest: &SchemaHashOf<T>) -> Vec<SchemaInfoOf<T>> {
+		<SchemaLookup<T>>::iter_key_prefix(digest)
+			.filter_map(|identifier| Self::schema_info(&identifier))
+			.collect()
+	}
+
 	/// Updates the global timeline with a new activity event for a schema.
 	///
 	/// An `EventEntryOf` struct is created, encapsulating the type of action
@@ -284,3 +982,40 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 }
+
+/// Anchor authorization backed by the schema registry. New anchors are
+/// refused under deprecated or frozen schemas and updates under frozen
+/// ones, while the digest policy of the schema decides whether digests must
+/// be unique. Anchors without a schema are always allowed.
+pub struct SchemaAuthorization<T>(PhantomData<T>);
+
+impl<T: Config, Creator, SpaceId> AuthorizeAnchor<Creator, SpaceId, SchemaIdOf>
+	for SchemaAuthorization<T>
+{
+	fn authorize_register(
+		_creator: &Creator,
+		_space: &SpaceId,
+		schema: Option<&SchemaIdOf>,
+	) -> Result<(), AnchorRefusal> {
+		if let Some(schema) = schema {
+			ensure!(!Pallet::<T>::is_deprecated(schema), AnchorRefusal::Deprecated);
+			ensure!(!Pallet::<T>::is_frozen(schema), AnchorRefusal::Frozen);
+		}
+		Ok(())
+	}
+
+	fn authorize_update(
+		_creator: &Creator,
+		_space: &SpaceId,
+		schema: Option<&SchemaIdOf>,
+	) -> Result<(), AnchorRefusal> {
+		if let Some(schema) = schema {
+			ensure!(!Pallet::<T>::is_frozen(schema), AnchorRefusal::Frozen);
+		}
+		Ok(())
+	}
+
+	fn requires_unique_digest(schema: &SchemaIdOf) -> bool {
+		Pallet::<T>::digest_policy(schema) == DigestPolicy::Unique
+	}
+}
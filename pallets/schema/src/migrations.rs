@@ -0,0 +1,59 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the schema pallet.
+
+use crate::{Config, Pallet, SchemaLookup, Schemas};
+use frame_support::{
+	migrations::VersionedMigration,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+/// Migration from storage version 1 to 2, which introduced `SchemaLookup`.
+pub mod v2 {
+	use super::*;
+
+	/// Indexes the schemas anchored before `SchemaLookup` was introduced by
+	/// their schema hash.
+	pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count = 0u64;
+
+			for (identifier, entry) in Schemas::<T>::iter() {
+				SchemaLookup::<T>::insert(entry.digest, identifier, ());
+				count += 1;
+			}
+
+			T::DbWeight::get().reads_writes(count, count)
+		}
+	}
+
+	/// [`InnerMigrateV1ToV2`] wrapped in a [`VersionedMigration`], which
+	/// only runs when the on-chain storage version is 1.
+	pub type MigrateV1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		InnerMigrateV1ToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -19,12 +19,17 @@
 use crate as pallet_schema;
 use cord_primitives::IsPermissioned;
 use cord_utilities::mock::{mock_origin, SubjectId};
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, FetchResult, QueryPreimage, StorePreimage},
+};
 use frame_system::EnsureRoot;
+use sp_core::H256;
 use sp_runtime::{
-	traits::{IdentifyAccount, IdentityLookup, Verify},
-	BuildStorage, MultiSignature,
+	traits::{BlakeTwo256, Hash, IdentifyAccount, IdentityLookup, Verify},
+	BuildStorage, DispatchError, MultiSignature,
 };
+use sp_std::{borrow::Cow, cell::RefCell, collections::btree_map::BTreeMap};
 
 type Signature = MultiSignature;
 type AccountPublic = <Signature as Verify>::Signer;
@@ -35,6 +40,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		Schema: pallet_schema,
 		Space: pallet_chain_space,
 		Identifier: identifier,
@@ -53,9 +59,15 @@ impl frame_system::Config for Test {
 	type Block = Block;
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u64>;
 	type SS58Prefix = SS58Prefix;
 }
 
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
 impl mock_origin::Config for Test {
 	type RuntimeOrigin = RuntimeOrigin;
 	type AccountId = AccountId;
@@ -64,6 +76,68 @@ impl mock_origin::Config for Test {
 
 parameter_types! {
 	pub const MaxEncodedSchemaLength: u32 = 15_360;
+	pub const MaxPreimageSchemaLength: u32 = 65_536;
+	pub static SchemaDepositBase: u64 = 0;
+	pub static SchemaDepositPerByte: u64 = 0;
+}
+
+thread_local! {
+	static PREIMAGES: RefCell<BTreeMap<H256, (Vec<u8>, bool)>> = RefCell::new(BTreeMap::new());
+}
+
+/// A preimage provider keeping preimages in memory, along with whether they
+/// have been requested.
+pub struct MockPreimages;
+
+impl QueryPreimage for MockPreimages {
+	type H = BlakeTwo256;
+
+	fn len(hash: &H256) -> Option<u32> {
+		PREIMAGES.with(|p| p.borrow().get(hash).map(|(bytes, _)| bytes.len() as u32))
+	}
+
+	fn fetch(hash: &H256, _len: Option<u32>) -> FetchResult {
+		PREIMAGES.with(|p| {
+			p.borrow()
+				.get(hash)
+				.map(|(bytes, _)| Cow::Owned(bytes.clone()))
+				.ok_or(DispatchError::Unavailable)
+		})
+	}
+
+	fn is_requested(hash: &H256) -> bool {
+		PREIMAGES.with(|p| p.borrow().get(hash).is_some_and(|(_, requested)| *requested))
+	}
+
+	fn request(hash: &H256) {
+		PREIMAGES.with(|p| {
+			if let Some((_, requested)) = p.borrow_mut().get_mut(hash) {
+				*requested = true;
+			}
+		})
+	}
+
+	fn unrequest(hash: &H256) {
+		PREIMAGES.with(|p| {
+			if let Some((_, requested)) = p.borrow_mut().get_mut(hash) {
+				*requested = false;
+			}
+		})
+	}
+}
+
+impl StorePreimage for MockPreimages {
+	const MAX_LENGTH: usize = usize::MAX;
+
+	fn note(bytes: Cow<[u8]>) -> Result<H256, DispatchError> {
+		let hash = BlakeTwo256::hash(&bytes[..]);
+		PREIMAGES.with(|p| p.borrow_mut().insert(hash, (bytes.into_owned(), false)));
+		Ok(hash)
+	}
+
+	fn unnote(hash: &H256) {
+		PREIMAGES.with(|p| p.borrow_mut().remove(hash));
+	}
 }
 
 impl pallet_schema::Config for Test {
@@ -73,6 +147,11 @@ impl pallet_schema::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
+	type MaxPreimageSchemaLength = MaxPreimageSchemaLength;
+	type Preimages = MockPreimages;
+	type Currency = Balances;
+	type SchemaDepositBase = SchemaDepositBase;
+	type SchemaDepositPerByte = SchemaDepositPerByte;
 }
 
 parameter_types! {
@@ -93,6 +172,11 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type CapacityAlertThresholds = ();
+	type MaxSpaceAnchorsPerBlock = ConstU32<0>;
+	type MaxDelegateAnchorsPerBlock = ConstU32<0>;
+	type MaxExpiryScanPerBlock = ConstU32<0>;
+	type MaxDelegateReassignments = ConstU32<0>;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type WeightInfo = ();
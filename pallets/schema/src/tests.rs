@@ -20,10 +20,14 @@ use super::*;
 use crate::mock::*;
 use codec::Encode;
 use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{QueryPreimage, StorePreimage},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use sp_core::H256;
-use sp_runtime::{traits::Hash, AccountId32};
+use sp_runtime::{traits::Hash, AccountId32, BuildStorage};
 use sp_std::prelude::*;
 const DEFAULT_SCHEMA_HASH_SEED: u64 = 1u64;
 const ALTERNATIVE_SCHEMA_HASH_SEED: u64 = 2u64;
@@ -136,7 +140,7 @@ fn check_successful_schema_creation() {
 			space_digest,
 		));
 
-		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
 
 		// Author Transaction
 		assert_ok!(Schema::create(
@@ -153,6 +157,13 @@ fn check_successful_schema_creation() {
 		assert_eq!(stored_schema.creator, creator);
 		// Verify the Schema digest is mapped correctly
 		assert_eq!(stored_schema.digest, digest);
+
+		// Verify the event is indexed by the Schema and its Space
+		let record = System::events().pop().expect("Schema creation should emit an event.");
+		assert_eq!(
+			record.topics,
+			vec![Space::identifier_topic(&schema_id), Space::identifier_topic(&space_id)]
+		);
 	});
 }
 
@@ -314,3 +325,623 @@ fn test_schema_lookup() {
 		}
 	});
 }
+
+// This test verifies that a schema can be superseded by a new version and that
+// the lineage is recorded in both directions. A second attempt to supersede the
+// same parent fails with the `SchemaAlreadyVersioned` error.
+#[test]
+fn check_schema_versioning() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let parent_schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let parent_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&parent_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let parent_id: SchemaIdOf = generate_schema_id::<Test>(&parent_id_digest);
+
+	let new_schema: InputSchemaOf<Test> = BoundedVec::try_from([3u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let new_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&new_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let new_id: SchemaIdOf = generate_schema_id::<Test>(&new_id_digest);
+
+	let other_schema: InputSchemaOf<Test> = BoundedVec::try_from([4u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		// Versioning a schema that does not exist should fail.
+		assert_noop!(
+			Schema::version_of(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				parent_id.clone(),
+				new_schema.clone(),
+				authorization_id.clone()
+			),
+			Error::<Test>::SchemaNotFound
+		);
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			parent_schema,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Schema::version_of(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			parent_id.clone(),
+			new_schema,
+			authorization_id.clone()
+		));
+
+		assert!(Schemas::<Test>::contains_key(&new_id));
+		assert_eq!(SchemaParent::<Test>::get(&new_id), Some(parent_id.clone()));
+		assert_eq!(SchemaSuccessor::<Test>::get(&parent_id), Some(new_id));
+
+		// The parent has already been superseded.
+		assert_noop!(
+			Schema::version_of(
+				DoubleOrigin(author, creator).into(),
+				parent_id,
+				other_schema,
+				authorization_id
+			),
+			Error::<Test>::SchemaAlreadyVersioned
+		);
+	});
+}
+
+// This test verifies that a schema resolves by identifier and by hash with its
+// controller, status and version links.
+#[test]
+fn schema_info_should_resolve_by_identifier_and_by_hash() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let parent_schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let parent_digest = <Test as frame_system::Config>::Hashing::hash(&parent_schema[..]);
+	let parent_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&parent_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let parent_id: SchemaIdOf = generate_schema_id::<Test>(&parent_id_digest);
+
+	let new_schema: InputSchemaOf<Test> = BoundedVec::try_from([3u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let new_digest = <Test as frame_system::Config>::Hashing::hash(&new_schema[..]);
+	let new_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&new_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let new_id: SchemaIdOf = generate_schema_id::<Test>(&new_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert!(Schema::schema_info(&parent_id).is_none());
+		assert!(Schema::schema_info_by_hash(&parent_digest).is_empty());
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			parent_schema,
+			authorization_id.clone()
+		));
+		assert_ok!(Schema::version_of(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			parent_id.clone(),
+			new_schema.clone(),
+			authorization_id.clone()
+		));
+		assert_ok!(Schema::deprecate(
+			DoubleOrigin(author, creator.clone()).into(),
+			parent_id.clone(),
+			authorization_id
+		));
+
+		let parent = Schema::schema_info(&parent_id).expect("parent schema should resolve");
+		assert_eq!(parent.details.digest, parent_digest);
+		assert_eq!(parent.details.creator, creator);
+		assert_eq!(parent.details.space, space_id);
+		assert_eq!(parent.deprecated_at, Some(System::block_number()));
+		assert_eq!(parent.frozen_by, None);
+		assert_eq!(parent.parent, None);
+		assert_eq!(parent.successor, Some(new_id.clone()));
+		assert!(!parent.timeline.is_empty());
+
+		let by_hash = Schema::schema_info_by_hash(&new_digest);
+		assert_eq!(by_hash.len(), 1);
+		assert_eq!(by_hash[0].identifier, new_id);
+		assert_eq!(by_hash[0].details.schema, new_schema);
+		assert!(!by_hash[0].preimage);
+		assert_eq!(by_hash[0].deprecated_at, None);
+		assert_eq!(by_hash[0].parent, Some(parent_id));
+		assert_eq!(by_hash[0].successor, None);
+	});
+}
+
+// This test verifies that a schema can be deprecated once by a delegate of its
+// space, and that deprecating it again fails with `SchemaAlreadyDeprecated`.
+#[test]
+fn check_schema_deprecation() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema,
+			authorization_id.clone()
+		));
+		assert!(!Schema::is_deprecated(&schema_id));
+
+		assert_ok!(Schema::deprecate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_id.clone(),
+			authorization_id.clone()
+		));
+
+		// The schema entry is kept on chain.
+		assert!(Schemas::<Test>::contains_key(&schema_id));
+		assert!(Schema::is_deprecated(&schema_id));
+
+		assert_noop!(
+			Schema::deprecate(DoubleOrigin(author, creator).into(), schema_id, authorization_id),
+			Error::<Test>::SchemaAlreadyDeprecated
+		);
+	});
+}
+
+// This test verifies that a schema allows shared digests until it declares a
+// policy, and that the policy can only be declared once.
+#[test]
+fn check_schema_digest_policy() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema,
+			authorization_id.clone()
+		));
+		assert_eq!(Schema::digest_policy(&schema_id), DigestPolicy::Shared);
+
+		assert_ok!(Schema::set_digest_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_id.clone(),
+			DigestPolicy::Unique,
+			authorization_id.clone()
+		));
+		assert_eq!(Schema::digest_policy(&schema_id), DigestPolicy::Unique);
+
+		assert_noop!(
+			Schema::set_digest_policy(
+				DoubleOrigin(author, creator).into(),
+				schema_id,
+				DigestPolicy::Shared,
+				authorization_id
+			),
+			Error::<Test>::DigestPolicyAlreadySet
+		);
+	});
+}
+
+#[test]
+fn genesis_schemas_should_be_anchored_in_genesis_spaces() {
+	let creator = DID_00;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&[2u8; 256].encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_schema = [2u8; 256].to_vec();
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&raw_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let mut t: sp_runtime::Storage =
+		frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_chain_space::GenesisConfig::<Test> { spaces: vec![(space_digest, creator.clone(), 5)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	crate::GenesisConfig::<Test> { schemas: vec![(space_digest, creator.clone(), raw_schema)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		let entry = Schemas::<Test>::get(&schema_id).expect("genesis schema should exist");
+		assert_eq!(entry.space, space_id);
+		assert_eq!(entry.creator, creator);
+		assert_eq!(pallet_chain_space::Spaces::<Test>::get(&space_id).unwrap().txn_count, 1);
+	});
+}
+
+#[test]
+#[should_panic(expected = "Genesis schemas must be unique and anchored in a genesis space")]
+fn genesis_schemas_should_require_a_genesis_space() {
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&[2u8; 256].encode()[..]);
+
+	let mut t: sp_runtime::Storage =
+		frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	crate::GenesisConfig::<Test> { schemas: vec![(space_digest, DID_00, [2u8; 256].to_vec())] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+}
+
+// This test verifies that a schema exceeding the inline limit can be anchored
+// from a noted preimage and that its full definition stays retrievable.
+#[test]
+fn schema_should_be_anchored_from_a_noted_preimage() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 3u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_schema = [3u8; 20_000].to_vec();
+	let schema_hash: SchemaHashOf<Test> =
+		<Test as frame_system::Config>::Hashing::hash(&raw_schema[..]);
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema_hash.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_noop!(
+			Schema::create_from_preimage(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				schema_hash,
+				authorization_id.clone()
+			),
+			Error::<Test>::SchemaPreimageNotFound
+		);
+
+		assert_ok!(MockPreimages::note(raw_schema.clone().into()));
+
+		assert_ok!(Schema::create_from_preimage(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_hash,
+			authorization_id.clone()
+		));
+
+		let stored_schema = Schemas::<Test>::get(&schema_id)
+			.expect("Schema Identifier should be present on chain.");
+		assert_eq!(stored_schema.digest, schema_hash);
+		assert!(stored_schema.schema.is_empty());
+		assert_eq!(PreimageSchemas::<Test>::get(&schema_id), Some(20_000));
+		assert!(MockPreimages::is_requested(&schema_hash));
+		assert_eq!(Schema::schema_definition(&schema_id), Some(raw_schema));
+
+		assert_noop!(
+			Schema::create_from_preimage(
+				DoubleOrigin(author, creator).into(),
+				schema_hash,
+				authorization_id
+			),
+			Error::<Test>::SchemaAlreadyAnchored
+		);
+	});
+}
+
+#[test]
+fn frozen_schema_should_only_be_unfrozen_by_its_freezing_authority() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Schema::freeze(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_id.clone(),
+			authorization_id.clone()
+		));
+		assert!(Schema::is_frozen(&schema_id));
+
+		assert_noop!(
+			Schema::set_digest_policy(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				schema_id.clone(),
+				DigestPolicy::Unique,
+				authorization_id.clone()
+			),
+			Error::<Test>::Frozen
+		);
+
+		// The council takes over the freeze, which the admin can no longer lift
+		assert_ok!(Schema::force_freeze(RawOrigin::Root.into(), schema_id.clone()));
+		assert_noop!(
+			Schema::unfreeze(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				schema_id.clone(),
+				authorization_id.clone()
+			),
+			Error::<Test>::FrozenByCouncil
+		);
+
+		assert_ok!(Schema::force_unfreeze(RawOrigin::Root.into(), schema_id.clone()));
+		assert!(!Schema::is_frozen(&schema_id));
+		assert_noop!(
+			Schema::unfreeze(DoubleOrigin(author, creator).into(), schema_id, authorization_id),
+			Error::<Test>::NotFrozen
+		);
+	});
+}
+
+#[test]
+fn anchoring_a_schema_should_hold_a_deposit() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		SchemaDepositBase::set(100);
+		SchemaDepositPerByte::set(1);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_noop!(
+			Schema::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				schema.clone(),
+				authorization_id.clone()
+			),
+			Error::<Test>::InsufficientDeposit
+		);
+
+		Balances::make_free_balance_be(&author, 10_000);
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema,
+			authorization_id
+		));
+
+		let deposit = Schema::schema_deposit(&Schemas::<Test>::get(&schema_id).unwrap());
+		assert!(deposit > 100);
+		assert_eq!(Balances::reserved_balance(&author), deposit);
+		assert_eq!(
+			SchemaDeposits::<Test>::get(&schema_id),
+			Some(SchemaDepositOf::<Test> { depositor: author, amount: deposit })
+		);
+	});
+}
+
+#[test]
+fn schema_authorization_should_follow_deprecation_and_digest_policy() {
+	type Authorization = SchemaAuthorization<Test>;
+
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Authorization::authorize_register(&creator, &space_id, Some(&schema_id)));
+		assert_ok!(Authorization::authorize_register(&creator, &space_id, None));
+		assert!(
+			!<Authorization as AuthorizeAnchor<SubjectId, SpaceIdOf, _>>::requires_unique_digest(
+				&schema_id
+			)
+		);
+
+		assert_ok!(Schema::set_digest_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_id.clone(),
+			DigestPolicy::Unique,
+			authorization_id.clone()
+		));
+		assert!(
+			<Authorization as AuthorizeAnchor<SubjectId, SpaceIdOf, _>>::requires_unique_digest(
+				&schema_id
+			)
+		);
+
+		// Deprecation only stops new statements from referencing the schema.
+		assert_ok!(Schema::deprecate(
+			DoubleOrigin(author, creator.clone()).into(),
+			schema_id.clone(),
+			authorization_id
+		));
+		assert_eq!(
+			Authorization::authorize_register(&creator, &space_id, Some(&schema_id)),
+			Err(AnchorRefusal::Deprecated)
+		);
+		assert_ok!(Authorization::authorize_update(&creator, &space_id, Some(&schema_id)));
+	});
+}
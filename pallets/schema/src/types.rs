@@ -18,6 +18,7 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
 
 /// An on-chain schema details mapped to an identifier.
 #[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
@@ -31,3 +32,55 @@ pub struct SchemaEntry<InputSchemaOf, SchemaHashOf, SchemaCreatorOf, SpaceIdOf>
 	/// Identifier of the space asscoaiated with the schema
 	pub space: SpaceIdOf,
 }
+
+/// Whether the same statement digest may be anchored more than once under a
+/// schema.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo,
+)]
+pub enum DigestPolicy {
+	/// A digest may be anchored by several controllers, e.g. for documents
+	/// shared between issuers.
+	#[default]
+	Shared,
+	/// A digest may only be anchored once across all spaces.
+	Unique,
+}
+
+/// Deposit held for a schema while it is anchored.
+///
+/// The deposit is reserved from the account that paid for anchoring the
+/// schema.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SchemaDeposit<AccountIdOf, BalanceOf> {
+	/// The account the deposit was reserved from.
+	pub depositor: AccountIdOf,
+	/// The amount reserved for the schema.
+	pub amount: BalanceOf,
+}
+
+/// A schema as seen by verifiers: the schema entry together with its
+/// status, its place in the version lineage and the recorded activity
+/// timeline.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SchemaInfo<SchemaIdOf, SchemaEntryOf, BlockNumber, FreezeAuthority, EventEntryOf> {
+	/// The schema identifier.
+	pub identifier: SchemaIdOf,
+	/// The schema entry, carrying the schema hash and its controller.
+	pub details: SchemaEntryOf,
+	/// Indicates whether the schema was anchored from a preimage, in which
+	/// case `details` only carries the preimage hash.
+	pub preimage: bool,
+	/// Block at which the schema was deprecated, if any.
+	pub deprecated_at: Option<BlockNumber>,
+	/// The authority that froze the schema, if it is frozen.
+	pub frozen_by: Option<FreezeAuthority>,
+	/// The digest policy of statements anchored under the schema.
+	pub digest_policy: DigestPolicy,
+	/// The version superseded by the schema, if any.
+	pub parent: Option<SchemaIdOf>,
+	/// The version superseding the schema, if any.
+	pub successor: Option<SchemaIdOf>,
+	/// Activity timeline of the schema, oldest first.
+	pub timeline: Vec<EventEntryOf>,
+}
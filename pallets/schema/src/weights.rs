@@ -50,6 +50,14 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_schema`.
 pub trait WeightInfo {
 	fn create(l: u32, ) -> Weight;
+	fn version_of(l: u32, ) -> Weight;
+	fn deprecate() -> Weight;
+	fn create_from_preimage() -> Weight;
+	fn set_digest_policy() -> Weight;
+	fn freeze() -> Weight;
+	fn unfreeze() -> Weight;
+	fn force_freeze() -> Weight;
+	fn force_unfreeze() -> Weight;
 }
 
 /// Weights for `pallet_schema` using the CORD node and recommended hardware.
@@ -75,6 +83,63 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn version_of(l: u32, ) -> Weight {
+		Weight::from_parts(50_217_845, 37024)
+			.saturating_add(Weight::from_parts(3_241, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn deprecate() -> Weight {
+		Weight::from_parts(34_870_000, 19007)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn create_from_preimage() -> Weight {
+		Weight::from_parts(43_180_000, 19007)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn set_digest_policy() -> Weight {
+		Weight::from_parts(33_010_000, 19007)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn freeze() -> Weight {
+		Weight::from_parts(32_270_000, 19007)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn unfreeze() -> Weight {
+		Weight::from_parts(32_530_000, 19007)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_freeze() -> Weight {
+		Weight::from_parts(25_390_000, 19007)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_unfreeze() -> Weight {
+		Weight::from_parts(25_620_000, 19007)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -99,4 +164,61 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn version_of(l: u32, ) -> Weight {
+		Weight::from_parts(50_217_845, 37024)
+			.saturating_add(Weight::from_parts(3_241, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn deprecate() -> Weight {
+		Weight::from_parts(34_870_000, 19007)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn create_from_preimage() -> Weight {
+		Weight::from_parts(43_180_000, 19007)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn set_digest_policy() -> Weight {
+		Weight::from_parts(33_010_000, 19007)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn freeze() -> Weight {
+		Weight::from_parts(32_270_000, 19007)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn unfreeze() -> Weight {
+		Weight::from_parts(32_530_000, 19007)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_freeze() -> Weight {
+		Weight::from_parts(25_390_000, 19007)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_unfreeze() -> Weight {
+		Weight::from_parts(25_620_000, 19007)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
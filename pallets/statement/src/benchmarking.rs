@@ -8,6 +8,7 @@ use frame_support::sp_runtime::traits::Hash;
 use frame_system::RawOrigin;
 use identifier::{IdentifierType, Ss58Identifier};
 use pallet_chain_space::SpaceCodeOf;
+use sp_runtime::traits::Bounded;
 
 const SEED: u32 = 0;
 const MAX_PAYLOAD_BYTE_LENGTH: u32 = 5 * 1024;
@@ -40,6 +41,7 @@ benchmarks! {
 	register {
 
 		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 		let did: T::SpaceCreatorId = account("did", 0, SEED);
 		let capacity = 3u64;
 
@@ -73,7 +75,7 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
-	}: _<T::RuntimeOrigin>(origin, statement_digest, authorization_id, None)
+	}: _<T::RuntimeOrigin>(origin, statement_digest, authorization_id, None, ContentTypeOf::Generic)
 	verify {
 		assert_last_event::<T>(Event::Register { identifier, digest: statement_digest, author: did}.into());
 	}
@@ -81,6 +83,7 @@ benchmarks! {
 	update {
 
 		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 		let did: T::SpaceCreatorId = account("did", 0, SEED);
 		let capacity = 5u64;
 
@@ -118,9 +121,9 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
 		/* register the entry before update */
-		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
 
-	}: _<T::RuntimeOrigin>(origin, identifier.clone(), update_digest, authorization_id)
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), update_digest, 0, authorization_id)
 	verify {
 		assert_last_event::<T>(Event::Update { identifier, digest: update_digest, author: did}.into());
 	}
@@ -128,6 +131,7 @@ benchmarks! {
 	revoke {
 
 		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 		let did: T::SpaceCreatorId = account("did", 0, SEED);
 		let capacity = 5u64;
 
@@ -158,7 +162,7 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
 		/* register the entry before update */
-		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
 
 	}: _<T::RuntimeOrigin>(origin, identifier.clone(), authorization_id)
 	verify {
@@ -168,6 +172,7 @@ benchmarks! {
 	restore {
 
 		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 		let did: T::SpaceCreatorId = account("did", 0, SEED);
 		let capacity = 6u64;
 
@@ -198,7 +203,7 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
 		/* register the entry before update */
-		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
 		let _ = Pallet::<T>::revoke(origin.clone(), identifier.clone(), authorization_id.clone());
 
 	}: _<T::RuntimeOrigin>(origin, identifier.clone(), authorization_id)
@@ -210,6 +215,7 @@ benchmarks! {
 		let l in 1 .. MAX_PAYLOAD_BYTE_LENGTH;
 
 		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 		let did: T::SpaceCreatorId = account("did", 0, SEED);
 		let capacity = 5u64;
 
@@ -241,7 +247,7 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
 		/* register the entry before update */
-		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
 
 	}: _<T::RuntimeOrigin>(origin, identifier.clone(), authorization_id)
 	verify {
@@ -252,6 +258,7 @@ benchmarks! {
 		let l in 1 .. MAX_PAYLOAD_BYTE_LENGTH;
 
 		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 		let did: T::SpaceCreatorId = account("did", 0, SEED);
 		let capacity = 3u64;
 
@@ -281,7 +288,7 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
-	}: _<T::RuntimeOrigin>(origin, vec![statement_digest0, statement_digest1, statement_digest2], authorization_id, None)
+	}: _<T::RuntimeOrigin>(origin, vec![statement_digest0, statement_digest1, statement_digest2], authorization_id, None, ContentTypeOf::Generic)
 	verify {
 		assert_last_event::<T>(Event::RegisterBatch { successful: 3, failed: 0, indices: [].to_vec(), author: did}.into());
 	}
@@ -289,6 +296,7 @@ benchmarks! {
 	add_presentation {
 
 		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 		let did: T::SpaceCreatorId = account("did", 0, SEED);
 		let capacity = 3u64;
 
@@ -320,7 +328,7 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
 		/* register the entry before update */
-		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
 
 	}: _<T::RuntimeOrigin>(origin, identifier.clone(), statement_digest, PresentationTypeOf::PDF, authorization_id)
 	verify {
@@ -330,6 +338,7 @@ benchmarks! {
 	remove_presentation {
 
 		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 		let did: T::SpaceCreatorId = account("did", 0, SEED);
 		let capacity = 4u64;
 
@@ -361,13 +370,435 @@ benchmarks! {
 		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
 
 		/* register the entry before update */
-		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
 		let _ = Pallet::<T>::add_presentation(origin.clone(), identifier.clone(), statement_digest, PresentationTypeOf::PDF, authorization_id.clone());
 	}: _<T::RuntimeOrigin>(origin, identifier.clone(), statement_digest, authorization_id)
 	verify {
 		assert_last_event::<T>(Event::PresentationRemoved { identifier, digest: statement_digest, author: did}.into());
 	}
 
+	set_expiry {
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+		let statement_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&statement_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+		let expires_at: BlockNumberFor<T> = 1_000u32.into();
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		/* register the entry before setting the expiry */
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
+
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), Some(expires_at), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::ExpirySet { identifier, expires_at: Some(expires_at), author: did}.into());
+	}
+
+	register_with_witnesses {
+		let w in 1 .. T::MaxStatementWitnesses::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let witnesses: WitnessListOf<T> = (0..w)
+			.map(|i| account("witness", i, SEED))
+			.collect::<Vec<T::SpaceCreatorId>>()
+			.try_into()
+			.expect("Witness count is within bounds.");
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+	}: _<T::RuntimeOrigin>(origin, statement_digest, authorization_id, None, ContentTypeOf::Generic, witnesses, w)
+	verify {
+		assert_last_event::<T>(Event::WitnessesRequired { identifier, required: w, author: did}.into());
+	}
+
+	witness_approve {
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let witness: T::SpaceCreatorId = account("witness", 0, SEED);
+		let capacity = 3u64;
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let witnesses: WitnessListOf<T> =
+			vec![witness.clone()].try_into().expect("Witness count is within bounds.");
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+		let witness_origin = <T as Config>::EnsureOrigin::generate_origin(caller, witness);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		/* register the entry pending a single witness approval */
+		let _ = Pallet::<T>::register_with_witnesses(origin, statement_digest, authorization_id, None, ContentTypeOf::Generic, witnesses, 1);
+
+	}: _<T::RuntimeOrigin>(witness_origin, identifier.clone())
+	verify {
+		assert_last_event::<T>(Event::Anchored { identifier }.into());
+	}
+
+	create_status_list {
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+		let length = T::MaxStatusListLength::get();
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
+
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), length, authorization_id)
+	verify {
+		assert_last_event::<T>(Event::StatusListCreated { identifier, length, author: did}.into());
+	}
+
+	set_status_at {
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+		let length = T::MaxStatusListLength::get();
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
+		let _ = Pallet::<T>::create_status_list(origin.clone(), identifier.clone(), length, authorization_id.clone());
+
+		let index = length - 1;
+
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), index, true, authorization_id)
+	verify {
+		assert_last_event::<T>(Event::StatusUpdated { identifier, index, revoked: true, author: did}.into());
+	}
+
+	remark {
+		let l in 1 .. T::MaxRemarkLength::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 3u64;
+
+		let remark: RemarkOf<T> = vec![1u8; l as usize].try_into().expect("Remark length is within bounds.");
+		let content_hash = <T as frame_system::Config>::Hashing::hash(&remark[..]);
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id.clone(), capacity ).expect("Approval should not fail.");
+
+	}: _<T::RuntimeOrigin>(origin, remark, authorization_id)
+	verify {
+		assert_last_event::<T>(Event::Remarked { space: space_id, content_hash, author: did}.into());
+	}
+
+	schedule_revoke {
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+		let at_block: BlockNumberFor<T> = 10u32.into();
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
+
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), at_block, authorization_id)
+	verify {
+		assert_last_event::<T>(Event::RevocationScheduled { identifier, at_block, author: did}.into());
+	}
+
+	cancel_scheduled_revoke {
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+		let at_block: BlockNumberFor<T> = 10u32.into();
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
+		let _ = Pallet::<T>::schedule_revoke(origin.clone(), identifier.clone(), at_block, authorization_id.clone());
+
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::RevocationCancelled { identifier, author: did}.into());
+	}
+
+	set_metadata {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+		let statement_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&statement_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+		let key: MetadataKeyOf<T> =
+			vec![1u8; T::MaxMetadataKeyLength::get() as usize].try_into().expect("Key should fit.");
+		let value: MetadataValueOf<T> =
+			vec![2u8; T::MaxMetadataValueLength::get() as usize].try_into().expect("Value should fit.");
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
+
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), key.clone(), value, authorization_id)
+	verify {
+		assert_last_event::<T>(Event::MetadataSet { identifier, key, author: did}.into());
+	}
+
+	clear_metadata {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		<T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+		let statement_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&statement_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+		let key: MetadataKeyOf<T> =
+			vec![1u8; T::MaxMetadataKeyLength::get() as usize].try_into().expect("Key should fit.");
+		let value: MetadataValueOf<T> =
+			vec![2u8; T::MaxMetadataValueLength::get() as usize].try_into().expect("Value should fit.");
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None, ContentTypeOf::Generic);
+		let _ = Pallet::<T>::set_metadata(origin.clone(), identifier.clone(), key.clone(), value, authorization_id.clone());
+
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), key.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::MetadataCleared { identifier, key, author: did}.into());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 
 }
@@ -43,6 +43,22 @@
 //!   privacy concerns and complies with data protection regulations.
 //! - **Activity Logging**: All statement activities are timestamped and recorded on-chain,
 //!   providing an immutable history of actions without revealing the actual data.
+//! - **Selective Disclosure**: A statement digest can be the root of a Merkle tree of attributes,
+//!   so holders can prove individual attributes against the anchored root.
+//! - **Single-Read Verification**: The statement, schema, revocation status and anchoring block of
+//!   every digest are kept together in `AnchorState`, so light clients can verify a digest with a
+//!   single storage proof.
+//! - **Storage Deposits**: Registering a statement reserves a deposit proportional to the size of
+//!   the stored details from the paying account, which is returned once the statement is removed.
+//! - **Bridge Attestations**: Every `AttestationPeriod` blocks the Keccak-256 hashes of newly
+//!   anchored digests are committed as a Merkle root in an `AttestationCommitted` event, so
+//!   Ethereum light-client bridges can verify CORD anchors with an inclusion proof.
+//! - **Anchoring Statistics**: Registrations, updates and revocations are counted for the whole
+//!   chain, per schema and per `StatsPeriod` with constant-time writes, and exposed through the
+//!   `StatementApi` runtime API.
+//! - **Content Types**: Each statement declares whether its digest covers a JSON-LD document, a
+//!   CBOR document, a PDF hash or generic content. The `OnAnchored` hook sees the declared type of
+//!   every registered or updated digest and can reject content that fails validation.
 //!
 //! ## Interface
 //!
@@ -54,6 +70,15 @@
 //! - `revoke`: Marks a statement's reference as inactive.
 //! - `restore`: Reactivates a revoked statement's reference.
 //! - `remove`: Removes a statement's reference from the blockchain.
+//! - `set_expiry`: Sets or clears the validity window of a statement.
+//! - `register_with_witnesses`: References off-chain data that only becomes authoritative once
+//!   enough witnesses have approved it.
+//! - `witness_approve`: Records a witness approval for a pending statement.
+//! - `create_status_list`: Anchors a bitstring status list against a statement.
+//! - `set_status_at`: Updates the revocation status of one index of a status list.
+//! - `remark`: Attaches a remark to a space without storing it.
+//! - `set_metadata`: Attaches a small key-value tag to a statement against a deposit.
+//! - `clear_metadata`: Removes a tag from a statement and returns its deposit.
 //!
 //!## Related Modules
 //!
@@ -80,9 +105,21 @@ pub mod mock;
 #[cfg(test)]
 pub mod tests;
 
-use cord_primitives::StatusOf;
-use frame_support::{ensure, storage::types::StorageMap};
-use sp_runtime::traits::UniqueSaturatedInto;
+#[cfg(any(feature = "try-runtime", test))]
+mod try_state;
+
+pub mod migrations;
+
+use codec::Encode;
+use cord_primitives::{AnchorRefusal, AnchoringReceipt, AuthorizeAnchor, CommitType, StatusOf};
+use cord_utilities::merkle;
+use frame_support::{
+	ensure,
+	storage::types::StorageMap,
+	traits::{Currency, Get, ReservableCurrency},
+	weights::Weight,
+};
+use sp_runtime::traits::{Keccak256, UniqueSaturatedInto, Zero};
 use sp_std::{prelude::Clone, str};
 pub mod types;
 pub mod weights;
@@ -92,9 +129,37 @@ use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::SaturatedConversion;
+use sp_runtime::{DispatchResult, SaturatedConversion};
 use sp_std::{vec, vec::Vec};
 
+/// Prefix of the off-chain index keys under which attestation leaves are
+/// written. The key of a leaf is the SCALE encoding of `(prefix, leaf
+/// number)`.
+pub const ATTESTATION_LEAF_PREFIX: &[u8] = b"cord::statement::attestation";
+
+/// Hook called whenever a statement digest is registered or updated, with
+/// the content type declared for the statement. Runtimes can route it to
+/// validators for specific content types; an error rejects the call.
+pub trait OnStatementAnchored<StatementId, Digest, SchemaId> {
+	fn on_anchored(
+		identifier: &StatementId,
+		digest: &Digest,
+		schema: Option<&SchemaId>,
+		content_type: ContentTypeOf,
+	) -> DispatchResult;
+}
+
+impl<StatementId, Digest, SchemaId> OnStatementAnchored<StatementId, Digest, SchemaId> for () {
+	fn on_anchored(
+		_identifier: &StatementId,
+		_digest: &Digest,
+		_schema: Option<&SchemaId>,
+		_content_type: ContentTypeOf,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 
@@ -106,7 +171,7 @@ pub mod pallet {
 	use sp_runtime::traits::Hash;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 	/// Space Identifier
 	pub type SpaceIdOf = Ss58Identifier;
@@ -118,6 +183,8 @@ pub mod pallet {
 	pub type AuthorizationIdOf = Ss58Identifier;
 	/// Type of a creator identifier.
 	pub type StatementCreatorOf<T> = pallet_chain_space::SpaceCreatorOf<T>;
+	/// Code of the space a statement is anchored in.
+	pub type SpaceCodeOf<T> = pallet_chain_space::SpaceCodeOf<T>;
 	/// Hash of the statement.
 	pub type StatementDigestOf<T> = <T as frame_system::Config>::Hash;
 	/// Type of the identitiy.
@@ -133,6 +200,46 @@ pub mod pallet {
 		StatementDigestOf<T>,
 		SpaceIdOf,
 	>;
+	/// Type for the statement details exposed to verifiers
+	pub type StatementInfoOf<T> =
+		StatementInfo<StatementIdOf, StatementDetailsOf<T>, BlockNumberFor<T>, EventEntryOf>;
+	/// Type for the list of witnesses of a statement
+	pub type WitnessListOf<T> =
+		BoundedVec<StatementCreatorOf<T>, <T as Config>::MaxStatementWitnesses>;
+	/// Type for the witness requirement of a statement
+	pub type StatementWitnessesOf<T> = StatementWitnesses<WitnessListOf<T>>;
+	/// Type for a remark attached to a space
+	pub type RemarkOf<T> = BoundedVec<u8, <T as Config>::MaxRemarkLength>;
+	/// Type for the verification state of an anchored digest
+	pub type AnchorStateOf<T> = AnchorStateEntry<StatementIdOf, SchemaIdOf, BlockNumberFor<T>>;
+	/// Type for the statement commit a digest resolves to
+	pub type DigestCommitOf = DigestCommit<StatementIdOf>;
+	/// Type of an anchoring receipt of a statement
+	pub type ReceiptOf<T> =
+		AnchoringReceipt<StatementIdOf, <T as frame_system::Config>::Hash, BlockNumberFor<T>>;
+	/// Type of the balance used for metadata deposits
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	/// Key of a statement metadata entry
+	pub type MetadataKeyOf<T> = BoundedVec<u8, <T as Config>::MaxMetadataKeyLength>;
+	/// Value of a statement metadata entry
+	pub type MetadataValueOf<T> = BoundedVec<u8, <T as Config>::MaxMetadataValueLength>;
+	/// Hash of an attestation leaf or root
+	pub type AttestationHashOf = <Keccak256 as sp_runtime::traits::Hash>::Output;
+	/// Type of an attestation commitment
+	pub type AttestationCommitmentOf<T> =
+		AttestationCommitment<AttestationHashOf, BlockNumberFor<T>>;
+	/// Type of a statement metadata entry
+	pub type MetadataEntryOf<T> = MetadataEntry<MetadataValueOf<T>, AccountIdOf<T>, BalanceOf<T>>;
+	/// Type of the deposit held for a statement
+	pub type StatementDepositOf<T> = StatementDeposit<AccountIdOf<T>, BalanceOf<T>>;
+	/// A chunk of a status list, holding one status bit per index
+	pub type StatusListChunkOf = [u8; STATUS_LIST_CHUNK_BYTES];
+
+	/// Number of bytes kept in a single status list chunk.
+	pub const STATUS_LIST_CHUNK_BYTES: usize = 32;
+	/// Number of status indices covered by a single status list chunk.
+	pub const STATUS_LIST_CHUNK_BITS: u32 = (STATUS_LIST_CHUNK_BYTES * 8) as u32;
 
 	#[pallet::config]
 	pub trait Config:
@@ -150,6 +257,64 @@ pub mod pallet {
 		/// Maximum removals per call
 		#[pallet::constant]
 		type MaxRemoveEntries: Get<u16>;
+		/// Maximum number of witnesses that can be required for a statement
+		#[pallet::constant]
+		type MaxStatementWitnesses: Get<u32>;
+		/// Maximum number of indices a status list can track
+		#[pallet::constant]
+		type MaxStatusListLength: Get<u32>;
+		/// Maximum length in bytes of a remark attached to a space
+		#[pallet::constant]
+		type MaxRemarkLength: Get<u32>;
+		/// Maximum number of revocations that can be scheduled for a block
+		#[pallet::constant]
+		type MaxScheduledRevocationsPerBlock: Get<u32>;
+		/// Currency used to hold statement and metadata deposits
+		type Currency: ReservableCurrency<AccountIdOf<Self>>;
+		/// Base deposit held for each registered statement
+		#[pallet::constant]
+		type StatementDepositBase: Get<BalanceOf<Self>>;
+		/// Additional deposit held per byte of the stored statement details
+		#[pallet::constant]
+		type StatementDepositPerByte: Get<BalanceOf<Self>>;
+		/// Base deposit held for each metadata entry of a statement
+		#[pallet::constant]
+		type MetadataDepositBase: Get<BalanceOf<Self>>;
+		/// Additional deposit held per byte of a metadata key and value
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<BalanceOf<Self>>;
+		/// Maximum number of metadata entries per statement
+		#[pallet::constant]
+		type MaxMetadataEntries: Get<u32>;
+		/// Maximum length in bytes of a metadata key
+		#[pallet::constant]
+		type MaxMetadataKeyLength: Get<u32>;
+		/// Maximum length in bytes of a metadata value
+		#[pallet::constant]
+		type MaxMetadataValueLength: Get<u32>;
+		/// Number of blocks between attestation commitments. `0` disables
+		/// attestations.
+		#[pallet::constant]
+		type AttestationPeriod: Get<BlockNumberFor<Self>>;
+		/// Maximum number of leaves covered by a single attestation
+		/// commitment. Further leaves wait for the next commitment.
+		#[pallet::constant]
+		type MaxAttestationLeaves: Get<u32>;
+		/// Number of blocks covered by each set of period statistics. `0`
+		/// disables period statistics, chain and schema totals are always
+		/// kept.
+		#[pallet::constant]
+		type StatsPeriod: Get<BlockNumberFor<Self>>;
+		/// Number of periods for which statistics are retained, including
+		/// the current one.
+		#[pallet::constant]
+		type StatsHistoryDepth: Get<u32>;
+		/// Hook called for every registered or updated digest. `()` accepts
+		/// all content.
+		type OnAnchored: OnStatementAnchored<StatementIdOf, StatementDigestOf<Self>, SchemaIdOf>;
+		/// Decides whether a creator may anchor statements in a space and
+		/// under a schema, and whether the digests must be unique.
+		type AuthorizeAnchor: AuthorizeAnchor<StatementCreatorOf<Self>, SpaceIdOf, SchemaIdOf>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -159,7 +324,22 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			if n > BlockNumberFor::<T>::zero() {
+				Self::execute_scheduled_revocations(n)
+					.saturating_add(Self::commit_attestations(n))
+					.saturating_add(Self::prune_period_stats(n))
+			} else {
+				Weight::zero()
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			crate::try_state::do_try_state::<T>()
+		}
+	}
 
 	/// statement identifiers stored on chain.
 	/// It maps from an identifier to its details.
@@ -208,7 +388,9 @@ pub mod pallet {
 	>;
 
 	/// Storage for Identifier lookup.
-	/// It maps from a statement entry digest and registry id to an identifier.
+	/// It maps from a statement entry digest and space id to the statement
+	/// identifier and the commit that anchored the digest. Entries are never
+	/// overwritten, so previous states keep resolving to their statement.
 	#[pallet::storage]
 	pub type IdentifierLookup<T> = StorageDoubleMap<
 		_,
@@ -216,10 +398,180 @@ pub mod pallet {
 		StatementDigestOf<T>,
 		Twox64Concat,
 		SpaceIdOf,
+		DigestCommitOf,
+		OptionQuery,
+	>;
+
+	/// Number of commits of a statement.
+	/// It maps from a statement identifier to the number of digests it has
+	/// anchored as its state, including the genesis digest.
+	#[pallet::storage]
+	pub type CommitCount<T> = StorageMap<_, Blake2_128Concat, StatementIdOf, u32, ValueQuery>;
+
+	/// Verification state of anchored statement digests.
+	/// It maps from a statement digest and space identifier to the
+	/// statement, schema, revocation status and anchoring block of the
+	/// digest. Statements pending witness approvals are only added once
+	/// they are anchored.
+	#[pallet::storage]
+	pub type AnchorState<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		StatementDigestOf<T>,
+		Twox64Concat,
+		SpaceIdOf,
+		AnchorStateOf<T>,
+		OptionQuery,
+	>;
+
+	/// Attestation leaves not yet covered by a commitment.
+	/// It maps from a leaf number to the Keccak-256 hash of an anchored
+	/// digest. Leaves are also written to the off-chain index under
+	/// `ATTESTATION_LEAF_PREFIX`, so relayers can build proofs after they
+	/// are removed from state.
+	#[pallet::storage]
+	pub type AttestationLeaves<T> =
+		StorageMap<_, Twox64Concat, u64, AttestationHashOf, OptionQuery>;
+
+	/// Number of attestation leaves recorded so far.
+	#[pallet::storage]
+	pub type AttestationLeafCount<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Number of attestation leaves covered by commitments so far.
+	#[pallet::storage]
+	pub type CommittedLeafCount<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Attestation commitments made so far.
+	/// It maps from a commitment number to its root and leaf range.
+	#[pallet::storage]
+	pub type AttestationCommitments<T: Config> =
+		StorageMap<_, Twox64Concat, u64, AttestationCommitmentOf<T>, OptionQuery>;
+
+	/// Number of the next attestation commitment.
+	#[pallet::storage]
+	pub type NextAttestationCommitment<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Statement activity counted since genesis.
+	#[pallet::storage]
+	pub type AnchoringTotals<T> = StorageValue<_, AnchoringStats, ValueQuery>;
+
+	/// Statement activity counted per schema.
+	/// It maps from a schema identifier to its counters.
+	#[pallet::storage]
+	pub type SchemaAnchoringStats<T> =
+		StorageMap<_, Blake2_128Concat, SchemaIdOf, AnchoringStats, ValueQuery>;
+
+	/// Statement activity counted per period.
+	/// It maps from a period index, the block number divided by
+	/// `StatsPeriod`, to its counters. Only the last `StatsHistoryDepth`
+	/// periods are kept.
+	#[pallet::storage]
+	pub type PeriodAnchoringStats<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, AnchoringStats, OptionQuery>;
+
+	/// Validity window of statements stored on chain.
+	/// It maps from a statement identifier to the block number after which
+	/// the statement is considered expired.
+	#[pallet::storage]
+	pub type StatementExpiry<T: Config> =
+		StorageMap<_, Blake2_128Concat, StatementIdOf, BlockNumberFor<T>, OptionQuery>;
+
+	/// Statements awaiting witness approvals.
+	/// It maps from a statement identifier to its witness requirement. A
+	/// statement is anchored once its entry is removed from this map.
+	#[pallet::storage]
+	pub type PendingWitnesses<T: Config> =
+		StorageMap<_, Blake2_128Concat, StatementIdOf, StatementWitnessesOf<T>, OptionQuery>;
+
+	/// Status lists anchored against statements.
+	/// It maps from a statement identifier to the number of indices tracked
+	/// by its status list.
+	#[pallet::storage]
+	pub type StatusLists<T: Config> =
+		StorageMap<_, Blake2_128Concat, StatementIdOf, u32, OptionQuery>;
+
+	/// Status list bits, stored sparsely in fixed size chunks.
+	/// It maps from a statement identifier and a chunk index to the chunk
+	/// bits. Chunks without any set bit are not stored.
+	#[pallet::storage]
+	pub type StatusListChunks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		StatementIdOf,
+		Twox64Concat,
+		u32,
+		StatusListChunkOf,
+		OptionQuery,
+	>;
+
+	/// Revocations scheduled to be executed at a future block.
+	/// It maps from a block number to the statements, along with the
+	/// identity that scheduled the revocation and the authorization it
+	/// used, to be revoked in that block.
+	#[pallet::storage]
+	pub type ScheduledRevocations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<
+			(StatementIdOf, StatementCreatorOf<T>, AuthorizationIdOf),
+			T::MaxScheduledRevocationsPerBlock,
+		>,
+		ValueQuery,
+	>;
+
+	/// Pending revocation of statements.
+	/// It maps from a statement identifier to the block at which the
+	/// statement is scheduled to be revoked.
+	#[pallet::storage]
+	pub type RevocationSchedule<T: Config> =
+		StorageMap<_, Blake2_128Concat, StatementIdOf, BlockNumberFor<T>, OptionQuery>;
+
+	/// Metadata attached to statements.
+	/// It maps from a statement identifier and a key to the value and the
+	/// deposit held for it.
+	#[pallet::storage]
+	pub type Metadata<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
 		StatementIdOf,
+		Blake2_128Concat,
+		MetadataKeyOf<T>,
+		MetadataEntryOf<T>,
 		OptionQuery,
 	>;
 
+	/// Number of metadata entries attached to a statement.
+	#[pallet::storage]
+	pub type MetadataCount<T> = StorageMap<_, Blake2_128Concat, StatementIdOf, u32, ValueQuery>;
+
+	/// Deposits held for registered statements.
+	/// It maps from a statement identifier to the account the deposit was
+	/// reserved from and the amount reserved. Statements registered
+	/// without a deposit, such as genesis statements, have no entry.
+	#[pallet::storage]
+	pub type Deposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, StatementIdOf, StatementDepositOf<T>, OptionQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Statements anchored at genesis, as `(space code, creator,
+		/// digest)`. The space must be seeded at genesis by the same creator.
+		/// Genesis statements do not reference a schema.
+		pub statements: Vec<(SpaceCodeOf<T>, StatementCreatorOf<T>, StatementDigestOf<T>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (space_code, creator, digest) in &self.statements {
+				Pallet::<T>::genesis_statement(space_code, creator, *digest)
+					.expect("Genesis statements must be unique and anchored in a genesis space");
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -272,6 +624,81 @@ pub mod pallet {
 			indices: Vec<u16>,
 			author: StatementCreatorOf<T>,
 		},
+		/// A statement expiry has been set or cleared.
+		/// \[statement identifier, expiry block, controller\]
+		ExpirySet {
+			identifier: StatementIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			author: StatementCreatorOf<T>,
+		},
+		/// A statement has been registered pending witness approvals.
+		/// \[statement identifier, required approvals, controller\]
+		WitnessesRequired {
+			identifier: StatementIdOf,
+			required: u32,
+			author: StatementCreatorOf<T>,
+		},
+		/// A witness has approved a pending statement.
+		/// \[statement identifier, witness\]
+		WitnessApproved { identifier: StatementIdOf, witness: StatementCreatorOf<T> },
+		/// A statement has received all required witness approvals.
+		/// \[statement identifier\]
+		Anchored { identifier: StatementIdOf },
+		/// A status list has been anchored against a statement.
+		/// \[statement identifier, length, author\]
+		StatusListCreated { identifier: StatementIdOf, length: u32, author: StatementCreatorOf<T> },
+		/// An index of a status list has been updated.
+		/// \[statement identifier, index, status, author\]
+		StatusUpdated {
+			identifier: StatementIdOf,
+			index: u32,
+			revoked: StatusOf,
+			author: StatementCreatorOf<T>,
+		},
+		/// A remark has been attached to a space.
+		/// \[space identifier, remark hash, author\]
+		Remarked {
+			space: SpaceIdOf,
+			content_hash: StatementDigestOf<T>,
+			author: StatementCreatorOf<T>,
+		},
+		/// A statement revocation has been scheduled.
+		/// \[statement identifier, block, author\]
+		RevocationScheduled {
+			identifier: StatementIdOf,
+			at_block: BlockNumberFor<T>,
+			author: StatementCreatorOf<T>,
+		},
+		/// A scheduled statement revocation has been cancelled.
+		/// \[statement identifier, author\]
+		RevocationCancelled { identifier: StatementIdOf, author: StatementCreatorOf<T> },
+		/// A scheduled statement revocation has been dropped because the
+		/// identity that scheduled it no longer holds revoke rights.
+		/// \[statement identifier, author\]
+		ScheduledRevocationSkipped { identifier: StatementIdOf, author: StatementCreatorOf<T> },
+		/// A metadata entry has been set on a statement.
+		/// \[statement identifier, key, author\]
+		MetadataSet {
+			identifier: StatementIdOf,
+			key: MetadataKeyOf<T>,
+			author: StatementCreatorOf<T>,
+		},
+		/// A metadata entry has been cleared from a statement.
+		/// \[statement identifier, key, author\]
+		MetadataCleared {
+			identifier: StatementIdOf,
+			key: MetadataKeyOf<T>,
+			author: StatementCreatorOf<T>,
+		},
+		/// A Merkle root of newly anchored digests has been committed for
+		/// export to external chains.
+		/// \[commitment number, root, first leaf, leaf count\]
+		AttestationCommitted {
+			commitment: u64,
+			root: AttestationHashOf,
+			first_leaf: u64,
+			leaf_count: u32,
+		},
 	}
 
 	#[pallet::error]
@@ -336,6 +763,63 @@ pub mod pallet {
 		PresentationNotFound,
 		/// Statement digest already present on the chain.
 		StatementDigestAlreadyAnchored,
+		/// Statement validity window has elapsed
+		StatementExpired,
+		/// Expiry block is not in the future
+		InvalidExpiry,
+		/// Schema is deprecated and cannot be used for new statements
+		SchemaDeprecated,
+		/// Schema is frozen and does not accept new or updated statements
+		SchemaFrozen,
+		/// Required approvals must be between one and the number of witnesses
+		InvalidWitnessThreshold,
+		/// Witnesses must be unique
+		DuplicateWitness,
+		/// Statement is awaiting witness approvals
+		StatementPendingWitnesses,
+		/// Statement is not awaiting witness approvals
+		StatementNotPending,
+		/// Caller is not a witness of the statement
+		NotAWitness,
+		/// Witness has already approved the statement
+		WitnessAlreadyApproved,
+		/// Status list already exists for the statement
+		StatusListAlreadyExists,
+		/// Status list not found for the statement
+		StatusListNotFound,
+		/// Status list length is zero or exceeds the allowed maximum
+		InvalidStatusListLength,
+		/// Status index is outside of the status list
+		StatusIndexOutOfBounds,
+		/// Remark is empty
+		EmptyRemark,
+		/// Statement already has a revocation scheduled
+		RevocationAlreadyScheduled,
+		/// Statement has no revocation scheduled
+		RevocationNotScheduled,
+		/// Scheduled revocation block is not in the future
+		InvalidRevocationBlock,
+		/// Maximum number of revocations scheduled for the block is reached
+		MaxScheduledRevocationsExceeded,
+		/// The schema requires unique digests and the digest is already
+		/// anchored. The prior anchor can be looked up with `verify_batch`.
+		DigestNotUnique,
+		/// The statement has been updated since the expected revision
+		StaleRevision,
+		/// The payer cannot cover the deposit of the statement
+		InsufficientDeposit,
+		/// The anchoring hook rejected the digest for its content type
+		ContentRejected,
+	}
+
+	impl<T> From<AnchorRefusal> for Error<T> {
+		fn from(refusal: AnchorRefusal) -> Self {
+			match refusal {
+				AnchorRefusal::Deprecated => Self::SchemaDeprecated,
+				AnchorRefusal::Frozen => Self::SchemaFrozen,
+				AnchorRefusal::Unauthorized => Self::UnauthorizedOperation,
+			}
+		}
 	}
 
 	#[pallet::call]
@@ -370,6 +854,7 @@ pub mod pallet {
 		/// - `digest`: The digest of the statement, serving as a unique identifier.
 		/// - `authorization`: The authorization ID, verifying the creator's delegation status.
 		/// - `schema_id`: An optional schema identifier to be associated with the statement.
+		/// - `content_type`: The kind of content the digest was computed over.
 		///
 		/// # Returns
 		/// A `DispatchResult` indicating the success or failure of the
@@ -379,8 +864,8 @@ pub mod pallet {
 		/// # Errors
 		/// The function can fail for several reasons including unauthorized
 		/// origin, the creator not being a delegate, space capacity being
-		/// exceeded, invalid statement identifier, or the statement already
-		/// being anchored. Errors related to incrementing space usage or
+		/// exceeded, invalid statement identifier, a deprecated schema, or the
+		/// statement already being anchored. Errors related to incrementing space usage or
 		/// updating the activity log may also occur.
 		///
 		/// # Events
@@ -393,46 +878,23 @@ pub mod pallet {
 			digest: StatementDigestOf<T>,
 			authorization: AuthorizationIdOf,
 			schema_id: Option<SchemaIdOf>,
+			content_type: ContentTypeOf,
 		) -> DispatchResult {
-			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
-				&authorization,
-				&creator,
-			)
-			.map_err(<pallet_chain_space::Error<T>>::from)?;
-
-			// Id Digest = concat (H(<scale_encoded_statement_digest>,
-			// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
-			);
-
-			let identifier = Ss58Identifier::create_identifier(
-				&(id_digest).encode()[..],
-				IdentifierType::Statement,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			ensure!(
-				!<Statements<T>>::contains_key(&identifier),
-				Error::<T>::StatementAlreadyAnchored
-			);
-
-			<Statements<T>>::insert(
-				&identifier,
-				StatementDetailsOf::<T> {
-					digest,
-					schema: schema_id.clone(),
-					space: space_id.clone(),
-				},
-			);
-
-			<Entries<T>>::insert(&identifier, digest, creator.clone());
-			<IdentifierLookup<T>>::insert(digest, &space_id, &identifier);
-
-			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
+			let sources = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let (payer, creator) = (sources.sender(), sources.subject());
+			let space_id =
+				pallet_chain_space::Pallet::<T>::ensure_anchoring_origin(&authorization, &creator)
+					.map_err(<pallet_chain_space::Error<T>>::from)?;
 
-			Self::deposit_event(Event::Register { identifier, digest, author: creator });
+			let identifier = Self::statement_registration(
+				digest,
+				space_id.clone(),
+				creator,
+				schema_id.clone(),
+				content_type,
+				Some(&payer),
+			)?;
+			Self::anchor_digest(digest, &space_id, identifier, schema_id);
 
 			Ok(())
 		}
@@ -461,11 +923,17 @@ pub mod pallet {
 		/// emitted, which includes the statement identifier, the new digest,
 		/// and the authoring updater's details.
 		///
+		/// Updates are compare-and-swap: the caller passes the revision it
+		/// last observed, and the update is rejected if another update has
+		/// landed in the meantime. This keeps concurrent updaters from
+		/// silently overwriting each other.
+		///
 		/// # Parameters
 		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
 		///   updater.
 		/// - `statement_id`: The identifier of the statement to be updated.
 		/// - `new_statement_digest`: The new digest to replace the existing one for the statement.
+		/// - `expected_revision`: The current revision of the statement, as returned by `query`.
 		/// - `authorization`: The authorization ID, verifying the updater's delegation status.
 		///
 		/// # Returns
@@ -476,8 +944,8 @@ pub mod pallet {
 		/// # Errors
 		/// The function can fail due to several reasons including an
 		/// unauthorized origin, the statement not found, the statement being
-		/// revoked, the new digest being the same as the existing one, or the
-		/// updater not being authorized for the operation.
+		/// revoked or expired, a stale `expected_revision`, the new digest being the same
+		/// as the existing one, or the updater not being authorized for the operation.
 		///
 		/// # Events
 		/// - `Update`: Emitted when a statement is successfully updated, containing the
@@ -489,14 +957,13 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			statement_id: StatementIdOf,
 			new_statement_digest: StatementDigestOf<T>,
+			expected_revision: u32,
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
 			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
-				&authorization,
-				&updater,
-			)
-			.map_err(<pallet_chain_space::Error<T>>::from)?;
+			let space_id =
+				pallet_chain_space::Pallet::<T>::ensure_anchoring_origin(&authorization, &updater)
+					.map_err(<pallet_chain_space::Error<T>>::from)?;
 
 			let statement_details =
 				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
@@ -508,23 +975,52 @@ pub mod pallet {
 				Error::<T>::StatementRevoked
 			);
 
+			ensure!(!Self::is_expired(&statement_id), Error::<T>::StatementExpired);
+			ensure!(
+				!<PendingWitnesses<T>>::contains_key(&statement_id),
+				Error::<T>::StatementPendingWitnesses
+			);
+
+			ensure!(Self::revision(&statement_id) == expected_revision, Error::<T>::StaleRevision);
+
 			ensure!(
 				!<Entries<T>>::contains_key(&statement_id, new_statement_digest),
 				Error::<T>::StatementDigestAlreadyAnchored
 			);
+			ensure!(
+				!<IdentifierLookup<T>>::contains_key(
+					new_statement_digest,
+					&statement_details.space
+				),
+				Error::<T>::StatementDigestAlreadyAnchored
+			);
+			Self::ensure_update_authorized(&updater, &space_id, &statement_details.schema)?;
+			Self::ensure_digest_policy(&new_statement_digest, &statement_details.schema)?;
+			T::OnAnchored::on_anchored(
+				&statement_id,
+				&new_statement_digest,
+				statement_details.schema.as_ref(),
+				statement_details.content_type,
+			)
+			.map_err(|_| Error::<T>::ContentRejected)?;
 
 			<RevocationList<T>>::insert(
 				&statement_id,
 				statement_details.digest,
 				StatementEntryStatusOf::<T> { creator: updater.clone(), revoked: true },
 			);
+			Self::set_anchor_revoked(&statement_details.digest, &statement_details.space, true);
 
 			<Entries<T>>::insert(&statement_id, new_statement_digest, updater.clone());
 
-			<IdentifierLookup<T>>::insert(
+			Self::commit_digest(new_statement_digest, &statement_details.space, &statement_id);
+
+			let schema_id = statement_details.schema.clone();
+			Self::anchor_digest(
 				new_statement_digest,
-				statement_details.space.clone(),
-				&statement_id,
+				&statement_details.space,
+				statement_id.clone(),
+				schema_id.clone(),
 			);
 
 			<Statements<T>>::insert(
@@ -533,12 +1029,17 @@ pub mod pallet {
 			);
 
 			Self::update_activity(&statement_id, CallTypeOf::Update).map_err(<Error<T>>::from)?;
+			Self::record_stats(schema_id.as_ref(), CallTypeOf::Update);
 
-			Self::deposit_event(Event::Update {
-				identifier: statement_id,
-				digest: new_statement_digest,
-				author: updater,
-			});
+			Self::deposit_indexed_event(
+				Event::Update {
+					identifier: statement_id,
+					digest: new_statement_digest,
+					author: updater,
+				},
+				&space_id,
+				&schema_id,
+			);
 
 			Ok(())
 		}
@@ -600,7 +1101,7 @@ pub mod pallet {
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
 			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_revoke_origin(
 				&authorization,
 				&updater,
 			)
@@ -616,14 +1117,7 @@ pub mod pallet {
 
 			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
 
-			<RevocationList<T>>::insert(
-				&statement_id,
-				statement_details.digest,
-				StatementEntryStatusOf::<T> { creator: updater.clone(), revoked: true },
-			);
-
-			Self::update_activity(&statement_id, CallTypeOf::Revoke).map_err(<Error<T>>::from)?;
-			Self::deposit_event(Event::Revoke { identifier: statement_id, author: updater });
+			Self::revoke_statement(statement_id, &statement_details, updater)?;
 
 			Ok(())
 		}
@@ -684,7 +1178,7 @@ pub mod pallet {
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
 			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_revoke_origin(
 				&authorization,
 				&updater,
 			)
@@ -701,9 +1195,14 @@ pub mod pallet {
 			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
 
 			<RevocationList<T>>::remove(&statement_id, statement_details.digest);
+			Self::set_anchor_revoked(&statement_details.digest, &statement_details.space, false);
 
 			Self::update_activity(&statement_id, CallTypeOf::Restore).map_err(<Error<T>>::from)?;
-			Self::deposit_event(Event::Restore { identifier: statement_id, author: updater });
+			Self::deposit_indexed_event(
+				Event::Restore { identifier: statement_id, author: updater },
+				&space_id,
+				&statement_details.schema,
+			);
 
 			Ok(())
 		}
@@ -718,19 +1217,26 @@ pub mod pallet {
 		/// the provided `statement_id`. If the statement cannot be found, the
 		/// function fails with an error. An early authorization check is
 		/// performed to ensure that the updater has the proper delegation
-		/// status for the space associated with the statement.
+		/// status for the space associated with the statement. Statements
+		/// belong to their space, so only an admin of the space may remove
+		/// them.
 		///
-		/// The function counts the number of entries linked to the statement
-		/// and compares this to the maximum number of entries that can be
-		/// removed in a single operation, as specified by `MaxRemoveEntries`.
-		/// If the count is less than or equal to the maximum, a complete
-		/// removal is initiated; otherwise, a partial removal is performed.
+		/// The function counts the number of entries and presentations linked
+		/// to the statement and compares this to the maximum number of records
+		/// that can be removed in a single operation, as specified by
+		/// `MaxRemoveEntries`. If the count is less than or equal to the
+		/// maximum, a complete removal is initiated; otherwise, a partial
+		/// removal is performed.
 		///
-		/// In a complete removal, all entries and their lookups are removed,
-		/// the statement is deleted, and the space usage is decremented
-		/// accordingly. In a partial removal, only up to the maximum number of
-		/// entries are removed, and the space usage is decremented by the
-		/// number of entries actually removed.
+		/// The first call releases the statement and metadata deposits and
+		/// clears the expiry, witness requirement, scheduled revocation and
+		/// status list of the statement, whichever path it takes. In a
+		/// complete removal, all entries, presentations and their lookups are
+		/// removed, the statement is deleted, and the space usage is
+		/// decremented accordingly. In a partial removal, only up to the
+		/// maximum number of presentations and entries are removed, and the
+		/// space usage is decremented by the number of records actually
+		/// removed.
 		///
 		/// After the removal process, the function updates the activity log to
 		/// record the event. It then emits either a `Removed` event for a
@@ -768,44 +1274,63 @@ pub mod pallet {
 			authorization: AuthorizationIdOf,
 		) -> DispatchResultWithPostInfo {
 			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
-				&authorization,
-				&updater,
-			)
-			.map_err(<pallet_chain_space::Error<T>>::from)?;
+			let space_id =
+				pallet_chain_space::Pallet::<T>::ensure_authorization_admin_remove_origin(
+					&authorization,
+					&updater,
+				)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
 
 			let statement_details =
 				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
 
 			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
 
-			// Count the entries in `Entries`.
+			// Count the entries in `Entries` and the presentations.
 			let entries_count = <Entries<T>>::iter_prefix(&statement_id).count();
+			let presentations_count = <Presentations<T>>::iter_prefix(&statement_id).count();
+			let records_count = entries_count.saturating_add(presentations_count);
 			let max_removals = T::MaxRemoveEntries::get() as usize;
 
 			// Determine if a complete or partial removal is needed.
-			let is_complete_removal = entries_count <= max_removals;
+			let is_complete_removal = records_count <= max_removals;
+
+			Self::clear_statement_attachments(&statement_id);
 
 			// Start the removal process.
 			let mut removed_count = 0;
 			if is_complete_removal {
 				// Perform a complete removal.
+				for (digest, _) in <Presentations<T>>::drain_prefix(&statement_id) {
+					<IdentifierLookup<T>>::remove(digest, &space_id);
+				}
 				for (digest, _) in <Entries<T>>::iter_prefix(&statement_id) {
 					<IdentifierLookup<T>>::remove(digest, &space_id);
+					<AnchorState<T>>::remove(digest, &space_id);
 				}
 				let _ =
 					<RevocationList<T>>::clear_prefix(&statement_id, entries_count as u32, None);
 				let _ = <Entries<T>>::clear_prefix(&statement_id, entries_count as u32, None);
 				<Statements<T>>::remove(&statement_id);
+				<CommitCount<T>>::remove(&statement_id);
 				pallet_chain_space::Pallet::<T>::decrement_usage_entries(
 					&space_id,
-					entries_count as u16,
+					records_count as u16,
 				)
 				.map_err(<pallet_chain_space::Error<T>>::from)?;
 			} else {
-				// Perform a partial removal.
-				for (digest, _) in <Entries<T>>::iter_prefix(&statement_id).take(max_removals) {
+				// Perform a partial removal, presentations first.
+				for (digest, _) in
+					<Presentations<T>>::drain_prefix(&statement_id).take(max_removals)
+				{
+					<IdentifierLookup<T>>::remove(digest, &space_id);
+					removed_count += 1;
+				}
+				for (digest, _) in
+					<Entries<T>>::iter_prefix(&statement_id).take(max_removals - removed_count)
+				{
 					<IdentifierLookup<T>>::remove(digest, &space_id);
+					<AnchorState<T>>::remove(digest, &space_id);
 					<RevocationList<T>>::remove(&statement_id, digest);
 					<Entries<T>>::remove(&statement_id, digest);
 					removed_count += 1;
@@ -834,9 +1359,9 @@ pub mod pallet {
 				}
 			};
 
-			Self::deposit_event(event);
+			Self::deposit_indexed_event(event, &space_id, &statement_details.schema);
 
-			Ok(Some(<T as Config>::WeightInfo::remove(entries_count as u32)).into())
+			Ok(Some(<T as Config>::WeightInfo::remove(records_count as u32)).into())
 		}
 
 		/// Creates multiple statements in a batch operation. This function
@@ -868,6 +1393,7 @@ pub mod pallet {
 		/// - `digests`: A vector of statement digests to be processed in the batch operation.
 		/// - `authorization`: The authorization ID, verifying the creator's delegation status.
 		/// - `schema_id`: An optional schema identifier that may be associated with the statements.
+		/// - `content_type`: The kind of content the digests were computed over.
 		///
 		/// # Returns
 		/// A `DispatchResult` indicating the success or failure of the batch
@@ -892,9 +1418,11 @@ pub mod pallet {
 			digests: Vec<StatementDigestOf<T>>,
 			authorization: AuthorizationIdOf,
 			schema_id: Option<SchemaIdOf>,
+			content_type: ContentTypeOf,
 		) -> DispatchResult {
-			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let sources = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let (payer, creator) = (sources.sender(), sources.subject());
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_anchoring_origin(
 				&authorization,
 				&creator,
 			)
@@ -905,47 +1433,61 @@ pub mod pallet {
 				Error::<T>::MaxDigestLimitExceeded
 			);
 
+			Self::ensure_register_authorized(&creator, &space_id, &schema_id)?;
+
 			let mut success = 0u32;
 			let mut fail = 0u32;
 			let mut indices: Vec<u16> = Vec::new();
 
 			for (index, digest) in digests.iter().enumerate() {
-				let id_digest = <T as frame_system::Config>::Hashing::hash(
-					&[
-						&digest.encode()[..],
-						&space_id.clone().encode()[..],
-						&creator.clone().encode()[..],
-					]
-					.concat()[..],
-				);
-
-				let identifier_result = Ss58Identifier::create_identifier(
-					&(id_digest).encode()[..],
-					IdentifierType::Statement,
-				);
+				let identifier_result = identifier::statement_identifier::<
+					<T as frame_system::Config>::Hashing,
+				>(digest, &space_id, &creator);
 
 				match identifier_result {
 					Ok(identifier) => {
-						if <Statements<T>>::contains_key(&identifier) {
+						if <Statements<T>>::contains_key(&identifier) ||
+							<IdentifierLookup<T>>::contains_key(digest, &space_id) ||
+							Self::ensure_digest_policy(digest, &schema_id).is_err() ||
+							T::OnAnchored::on_anchored(
+								&identifier,
+								digest,
+								schema_id.as_ref(),
+								content_type,
+							)
+							.is_err()
+						{
+							fail += 1;
+							indices.push(index as u16);
+							continue;
+						}
+
+						let details = StatementDetailsOf::<T> {
+							digest: *digest,
+							schema: schema_id.clone(),
+							space: space_id.clone(),
+							content_type,
+						};
+						if Self::hold_statement_deposit(&identifier, &details, &payer).is_err() {
 							fail += 1;
 							indices.push(index as u16);
 						} else {
-							<Statements<T>>::insert(
-								&identifier,
-								StatementDetailsOf::<T> {
-									digest: *digest,
-									schema: schema_id.clone(),
-									space: space_id.clone(),
-								},
-							);
+							<Statements<T>>::insert(&identifier, details);
 
 							<Entries<T>>::insert(&identifier, digest, creator.clone());
-							<IdentifierLookup<T>>::insert(digest, &space_id, &identifier);
+							Self::commit_digest(*digest, &space_id, &identifier);
+							Self::anchor_digest(
+								*digest,
+								&space_id,
+								identifier.clone(),
+								schema_id.clone(),
+							);
 
 							if Self::update_activity(&identifier, CallTypeOf::Genesis).is_err() {
 								fail += 1;
 								indices.push(index as u16);
 							} else {
+								Self::record_stats(schema_id.as_ref(), CallTypeOf::Genesis);
 								success += 1;
 							}
 						}
@@ -961,16 +1503,22 @@ pub mod pallet {
 			if digests.len() > 1 {
 				let increment = (digests.len() - 1) as u16;
 
+				pallet_chain_space::Pallet::<T>::note_anchors(&space_id, &creator, increment.into())
+					.map_err(<pallet_chain_space::Error<T>>::from)?;
 				pallet_chain_space::Pallet::<T>::increment_usage_entries(&space_id, increment)
 					.map_err(<pallet_chain_space::Error<T>>::from)?;
 			}
 
-			Self::deposit_event(Event::RegisterBatch {
-				successful: success,
-				failed: fail,
-				indices,
-				author: creator,
-			});
+			Self::deposit_indexed_event(
+				Event::RegisterBatch {
+					successful: success,
+					failed: fail,
+					indices,
+					author: creator,
+				},
+				&space_id,
+				&schema_id,
+			);
 
 			Ok(())
 		}
@@ -997,6 +1545,7 @@ pub mod pallet {
 		///   statement.
 		/// - Returns `StatementRevoked` if the statement associated with the `statement_id` has
 		///   been revoked.
+		/// - Returns `StatementExpired` if the validity window of the statement has elapsed.
 		/// - Returns `UnauthorizedOperation` if the operation is not authorized within the
 		///   associated space.
 		/// - Returns `PresentationDigestAlreadyAnchored` if the `presentation_digest` is not
@@ -1030,14 +1579,32 @@ pub mod pallet {
 
 			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
 
+			ensure!(!Self::is_expired(&statement_id), Error::<T>::StatementExpired);
+			ensure!(
+				!<PendingWitnesses<T>>::contains_key(&statement_id),
+				Error::<T>::StatementPendingWitnesses
+			);
+			Self::ensure_update_authorized(&creator, &space_id, &statement_details.schema)?;
+
 			// Check for presentation digest uniqueness to fail early if the digest is
 			// already present.
 			ensure!(
 				!<Presentations<T>>::contains_key(&statement_id, presentation_digest),
 				Error::<T>::PresentationDigestAlreadyAnchored
 			);
+			ensure!(
+				!<IdentifierLookup<T>>::contains_key(presentation_digest, &space_id),
+				Error::<T>::PresentationDigestAlreadyAnchored
+			);
 
-			<IdentifierLookup<T>>::insert(presentation_digest, &space_id, &statement_id);
+			<IdentifierLookup<T>>::insert(
+				presentation_digest,
+				&space_id,
+				DigestCommitOf {
+					identifier: statement_id.clone(),
+					index: <CommitCount<T>>::get(&statement_id).saturating_sub(1),
+				},
+			);
 
 			<Presentations<T>>::insert(
 				&statement_id,
@@ -1122,10 +1689,1271 @@ pub mod pallet {
 
 			Ok(())
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
+		/// Sets or clears the validity window of a statement.
+		///
+		/// Once the current block number passes `expires_at`, the statement
+		/// can no longer be updated and no presentations can be added to it.
+		/// Revocation, restoration and removal remain possible so that an
+		/// expired statement can still be cleaned up by its space delegates.
+		/// Passing `None` clears a previously set expiry.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from a
+		///   space delegate.
+		/// - `statement_id`: The identifier of the statement.
+		/// - `expires_at`: The last block at which the statement is valid, or `None`.
+		/// - `authorization`: The authorization ID, verifying the caller's delegation status.
+		///
+		/// # Errors
+		/// - Returns `StatementNotFound` if the statement does not exist.
+		/// - Returns `UnauthorizedOperation` if the statement is not part of the authorized space.
+		/// - Returns `StatementRevoked` if the statement has been revoked.
+		/// - Returns `StatementExpired` if the statement has already expired.
+		/// - Returns `InvalidExpiry` if `expires_at` is not after the current block.
+		///
+		/// # Events
+		/// - Emits `ExpirySet` with the new expiry on success.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_expiry())]
+		pub fn set_expiry(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&updater,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+
+			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			ensure!(
+				!<RevocationList<T>>::contains_key(&statement_id, statement_details.digest),
+				Error::<T>::StatementRevoked
+			);
+
+			ensure!(!Self::is_expired(&statement_id), Error::<T>::StatementExpired);
+
+			match expires_at {
+				Some(block) => {
+					ensure!(
+						block > frame_system::Pallet::<T>::block_number(),
+						Error::<T>::InvalidExpiry
+					);
+					<StatementExpiry<T>>::insert(&statement_id, block);
+				},
+				None => <StatementExpiry<T>>::remove(&statement_id),
+			}
+
+			Self::update_activity(&statement_id, CallTypeOf::Update).map_err(<Error<T>>::from)?;
+
+			Self::deposit_event(Event::ExpirySet {
+				identifier: statement_id,
+				expires_at,
+				author: updater,
+			});
+
+			Ok(())
+		}
+
+		/// Registers a statement that only becomes authoritative once a
+		/// number of witnesses have approved it.
+		///
+		/// The statement is anchored in the same way as with `register`, but
+		/// is kept in a pending state until `required` of the given
+		/// `witnesses` have called `witness_approve`. Pending statements
+		/// cannot be updated and no presentations can be added to them. They
+		/// can still be revoked or removed by the space delegates.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   creator.
+		/// - `digest`: The digest of the statement, serving as a unique identifier.
+		/// - `authorization`: The authorization ID, verifying the creator's delegation status.
+		/// - `schema_id`: An optional schema identifier to be associated with the statement.
+		/// - `content_type`: The kind of content the digest was computed over.
+		/// - `witnesses`: The identities allowed to approve the statement.
+		/// - `required`: The number of approvals needed to anchor the statement.
+		///
+		/// # Errors
+		/// - Returns `InvalidWitnessThreshold` if `required` is zero or larger than the number of
+		///   witnesses.
+		/// - Returns `DuplicateWitness` if a witness is listed more than once.
+		/// - Fails for the same reasons as `register`.
+		///
+		/// # Events
+		/// - Emits `Register` and `WitnessesRequired` on success.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_with_witnesses(witnesses.len() as u32))]
+		pub fn register_with_witnesses(
+			origin: OriginFor<T>,
+			digest: StatementDigestOf<T>,
+			authorization: AuthorizationIdOf,
+			schema_id: Option<SchemaIdOf>,
+			content_type: ContentTypeOf,
+			witnesses: WitnessListOf<T>,
+			required: u32,
+		) -> DispatchResult {
+			let sources = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let (payer, creator) = (sources.sender(), sources.subject());
+			let space_id =
+				pallet_chain_space::Pallet::<T>::ensure_anchoring_origin(&authorization, &creator)
+					.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			ensure!(
+				required > 0 && required as usize <= witnesses.len(),
+				Error::<T>::InvalidWitnessThreshold
+			);
+			for (index, witness) in witnesses.iter().enumerate() {
+				ensure!(!witnesses[..index].contains(witness), Error::<T>::DuplicateWitness);
+			}
+
+			let identifier = Self::statement_registration(
+				digest,
+				space_id,
+				creator.clone(),
+				schema_id,
+				content_type,
+				Some(&payer),
+			)?;
+
+			<PendingWitnesses<T>>::insert(
+				&identifier,
+				StatementWitnessesOf::<T> { required, witnesses, approvals: BoundedVec::default() },
+			);
+
+			Self::deposit_event(Event::WitnessesRequired { identifier, required, author: creator });
+
+			Ok(())
+		}
+
+		/// Approves a statement that is pending witness approvals.
+		///
+		/// Once the number of approvals reaches the requirement set at
+		/// registration, the pending state is cleared and the statement is
+		/// anchored.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from one
+		///   of the witnesses.
+		/// - `statement_id`: The identifier of the pending statement.
+		///
+		/// # Errors
+		/// - Returns `StatementNotPending` if the statement is not awaiting approvals.
+		/// - Returns `NotAWitness` if the caller is not listed as a witness.
+		/// - Returns `WitnessAlreadyApproved` if the caller has already approved.
+		///
+		/// # Events
+		/// - Emits `WitnessApproved`, followed by `Anchored` once the requirement is met.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::witness_approve())]
+		pub fn witness_approve(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+		) -> DispatchResult {
+			let witness = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let mut pending =
+				<PendingWitnesses<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotPending)?;
+
+			ensure!(pending.witnesses.contains(&witness), Error::<T>::NotAWitness);
+			ensure!(!pending.approvals.contains(&witness), Error::<T>::WitnessAlreadyApproved);
+
+			// The approvals are a subset of the witnesses, so they always fit.
+			pending
+				.approvals
+				.try_push(witness.clone())
+				.map_err(|_| Error::<T>::WitnessAlreadyApproved)?;
+
+			Self::deposit_event(Event::WitnessApproved {
+				identifier: statement_id.clone(),
+				witness,
+			});
+
+			if pending.approvals.len() as u32 >= pending.required {
+				<PendingWitnesses<T>>::remove(&statement_id);
+
+				let statement_details =
+					<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+				Self::anchor_digest(
+					statement_details.digest,
+					&statement_details.space,
+					statement_id.clone(),
+					statement_details.schema.clone(),
+				);
+				// Pending statements can be revoked before they are anchored.
+				if <RevocationList<T>>::contains_key(&statement_id, statement_details.digest) {
+					Self::set_anchor_revoked(
+						&statement_details.digest,
+						&statement_details.space,
+						true,
+					);
+				}
+
+				Self::update_activity(&statement_id, CallTypeOf::Approved)
+					.map_err(<Error<T>>::from)?;
+
+				Self::deposit_event(Event::Anchored { identifier: statement_id });
+			} else {
+				<PendingWitnesses<T>>::insert(&statement_id, pending);
+			}
+
+			Ok(())
+		}
+
+		/// Anchors a status list against an existing statement.
+		///
+		/// A status list tracks the revocation status of a large number of
+		/// credentials issued under the statement, one bit per credential
+		/// index, following the status list model used by verifiable
+		/// credentials. All indices start out as not revoked.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   issuer.
+		/// - `statement_id`: The identifier of the statement the list belongs to.
+		/// - `length`: The number of indices tracked by the list.
+		/// - `authorization`: The authorization ID, verifying the issuer's delegation status.
+		///
+		/// # Errors
+		/// - Returns `StatementNotFound` if the statement does not exist.
+		/// - Returns `UnauthorizedOperation` if the statement belongs to a different space.
+		/// - Returns `StatementRevoked` if the statement is revoked.
+		/// - Returns `StatusListAlreadyExists` if the statement already has a status list.
+		/// - Returns `InvalidStatusListLength` if `length` is zero or exceeds
+		///   `MaxStatusListLength`.
+		///
+		/// # Events
+		/// - Emits `StatusListCreated` on success.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_status_list())]
+		pub fn create_status_list(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			length: u32,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&creator,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+
+			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			ensure!(
+				!<RevocationList<T>>::contains_key(&statement_id, statement_details.digest),
+				Error::<T>::StatementRevoked
+			);
+
+			ensure!(
+				!<StatusLists<T>>::contains_key(&statement_id),
+				Error::<T>::StatusListAlreadyExists
+			);
+
+			ensure!(
+				length > 0 && length <= T::MaxStatusListLength::get(),
+				Error::<T>::InvalidStatusListLength
+			);
+
+			<StatusLists<T>>::insert(&statement_id, length);
+
+			Self::update_activity(&statement_id, CallTypeOf::Update).map_err(<Error<T>>::from)?;
+
+			Self::deposit_event(Event::StatusListCreated {
+				identifier: statement_id,
+				length,
+				author: creator,
+			});
+
+			Ok(())
+		}
+
+		/// Sets the revocation status of a single index of a status list.
+		///
+		/// Delegates holding either the `ASSERT` or the `REVOKE` permission in
+		/// the statement's space can update the list. Setting an index to its
+		/// current value is allowed and leaves the list unchanged.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   issuer.
+		/// - `statement_id`: The identifier of the statement the list belongs to.
+		/// - `index`: The index within the status list.
+		/// - `revoked`: The new status of the index.
+		/// - `authorization`: The authorization ID, verifying the issuer's delegation status.
+		///
+		/// # Errors
+		/// - Returns `StatusListNotFound` if the statement has no status list.
+		/// - Returns `StatusIndexOutOfBounds` if `index` is not covered by the list.
+		/// - Returns `UnauthorizedOperation` if the statement belongs to a different space.
+		///
+		/// # Events
+		/// - Emits `StatusUpdated` on success.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_status_at())]
+		pub fn set_status_at(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			index: u32,
+			revoked: StatusOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_revoke_origin(
+				&authorization,
+				&updater,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+
+			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			let length =
+				<StatusLists<T>>::get(&statement_id).ok_or(Error::<T>::StatusListNotFound)?;
+			ensure!(index < length, Error::<T>::StatusIndexOutOfBounds);
+
+			let chunk_index = index / STATUS_LIST_CHUNK_BITS;
+			let bit = index % STATUS_LIST_CHUNK_BITS;
+			let mask = 1u8 << (bit % 8);
+			let byte = (bit / 8) as usize;
+
+			let mut chunk = <StatusListChunks<T>>::get(&statement_id, chunk_index)
+				.unwrap_or([0u8; STATUS_LIST_CHUNK_BYTES]);
+			if revoked {
+				chunk[byte] |= mask;
+			} else {
+				chunk[byte] &= !mask;
+			}
+
+			if chunk.iter().all(|b| *b == 0) {
+				<StatusListChunks<T>>::remove(&statement_id, chunk_index);
+			} else {
+				<StatusListChunks<T>>::insert(&statement_id, chunk_index, chunk);
+			}
+
+			Self::deposit_event(Event::StatusUpdated {
+				identifier: statement_id,
+				index,
+				revoked,
+				author: updater,
+			});
+
+			Ok(())
+		}
+
+		/// Attaches a remark to the space linked to the authorization.
+		///
+		/// Nothing is kept in storage. The remark is only carried by the
+		/// extrinsic, and a `Remarked` event with its hash is emitted. The
+		/// hash is also used as an event topic, so audit tools can look up
+		/// remarks by content. Each remark counts towards the transaction
+		/// capacity of the space.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from a
+		///   space delegate.
+		/// - `remark`: The remark to attach, limited to `MaxRemarkLength` bytes.
+		/// - `authorization`: The authorization ID, verifying the delegate's status.
+		///
+		/// # Errors
+		/// - Returns `EmptyRemark` if the remark is empty.
+		/// - Fails if the authorization is not valid for anchoring in the space.
+		///
+		/// # Events
+		/// - Emits `Remarked` on success.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remark(remark.len() as u32))]
+		pub fn remark(
+			origin: OriginFor<T>,
+			remark: RemarkOf<T>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let author = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			ensure!(!remark.is_empty(), Error::<T>::EmptyRemark);
+
+			let space = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&author,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let content_hash = <T as frame_system::Config>::Hashing::hash(&remark[..]);
+
+			let event: <T as Config>::RuntimeEvent =
+				Event::Remarked { space, content_hash, author }.into();
+			frame_system::Pallet::<T>::deposit_event_indexed(&[content_hash], event.into());
+
+			Ok(())
+		}
+
+		/// Schedules the revocation of a statement at a future block.
+		///
+		/// The revocation is executed automatically at the start of
+		/// `at_block`, on behalf of the identity that scheduled it. The same
+		/// checks as `revoke` are applied when scheduling. If the statement
+		/// has been revoked or removed by the time the revocation is due, the
+		/// scheduled revocation is dropped. The authority of the updater is
+		/// checked again when the revocation is due: if the authorization was
+		/// removed or has expired, the revocation is skipped and
+		/// `ScheduledRevocationSkipped` is emitted. A freeze of the space does
+		/// not block the revocation.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   updater.
+		/// - `statement_id`: The identifier of the statement to be revoked.
+		/// - `at_block`: The block at which the statement is revoked.
+		/// - `authorization`: The authorization ID, verifying the updater's delegation status.
+		///
+		/// # Errors
+		/// - Returns `StatementNotFound` if the statement does not exist.
+		/// - Returns `StatementRevoked` if the statement has already been revoked.
+		/// - Returns `UnauthorizedOperation` if the statement is not part of the authorized space.
+		/// - Returns `RevocationAlreadyScheduled` if a revocation is already pending.
+		/// - Returns `InvalidRevocationBlock` if `at_block` is not after the current block.
+		/// - Returns `MaxScheduledRevocationsExceeded` if `at_block` has no room left.
+		///
+		/// # Events
+		/// - Emits `RevocationScheduled` on success.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::schedule_revoke())]
+		pub fn schedule_revoke(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			at_block: BlockNumberFor<T>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_revoke_origin(
+				&authorization,
+				&updater,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+
+			ensure!(
+				!<RevocationList<T>>::contains_key(&statement_id, statement_details.digest),
+				Error::<T>::StatementRevoked
+			);
+
+			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			ensure!(
+				!<RevocationSchedule<T>>::contains_key(&statement_id),
+				Error::<T>::RevocationAlreadyScheduled
+			);
+
+			ensure!(
+				at_block > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidRevocationBlock
+			);
+
+			<ScheduledRevocations<T>>::try_mutate(at_block, |revocations| {
+				revocations
+					.try_push((statement_id.clone(), updater.clone(), authorization.clone()))
+					.map_err(|_| Error::<T>::MaxScheduledRevocationsExceeded)
+			})?;
+			<RevocationSchedule<T>>::insert(&statement_id, at_block);
+
+			Self::deposit_event(Event::RevocationScheduled {
+				identifier: statement_id,
+				at_block,
+				author: updater,
+			});
+
+			Ok(())
+		}
+
+		/// Cancels a pending scheduled revocation of a statement.
+		///
+		/// Any delegate allowed to revoke the statement can cancel the
+		/// scheduled revocation, not only the one who scheduled it.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   updater.
+		/// - `statement_id`: The identifier of the statement.
+		/// - `authorization`: The authorization ID, verifying the updater's delegation status.
+		///
+		/// # Errors
+		/// - Returns `StatementNotFound` if the statement does not exist.
+		/// - Returns `UnauthorizedOperation` if the statement is not part of the authorized space.
+		/// - Returns `RevocationNotScheduled` if there is no pending revocation.
+		///
+		/// # Events
+		/// - Emits `RevocationCancelled` on success.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_scheduled_revoke())]
+		pub fn cancel_scheduled_revoke(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_revoke_origin(
+				&authorization,
+				&updater,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+
+			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			let at_block = <RevocationSchedule<T>>::take(&statement_id)
+				.ok_or(Error::<T>::RevocationNotScheduled)?;
+			<ScheduledRevocations<T>>::mutate(at_block, |revocations| {
+				revocations.retain(|(identifier, _, _)| identifier != &statement_id)
+			});
+
+			Self::deposit_event(Event::RevocationCancelled {
+				identifier: statement_id,
+				author: updater,
+			});
+
+			Ok(())
+		}
+
+		/// Sets a metadata entry on a statement.
+		///
+		/// Metadata entries are small key-value tags, such as a jurisdiction
+		/// or a category, that can be attached to a statement without
+		/// anchoring a new state. A deposit proportional to the size of the
+		/// key and value is reserved from the sender of the call. Setting an
+		/// existing key replaces its value and moves the deposit to the new
+		/// sender.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from a
+		///   space delegate.
+		/// - `statement_id`: The identifier of the statement.
+		/// - `key`: The key of the metadata entry.
+		/// - `value`: The value of the metadata entry.
+		/// - `authorization`: The authorization ID, verifying the caller's delegation status.
+		///
+		/// # Errors
+		/// - Returns `StatementNotFound` if the statement does not exist.
+		/// - Returns `UnauthorizedOperation` if the statement is not part of the authorized space.
+		/// - Returns `StatementRevoked` if the statement has been revoked.
+		/// - Returns `MetadataLimitExceeded` if the statement already has the maximum number of
+		///   metadata entries.
+		/// - Fails if the deposit cannot be reserved.
+		///
+		/// # Events
+		/// - Emits `MetadataSet` on success.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_metadata())]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			key: MetadataKeyOf<T>,
+			value: MetadataValueOf<T>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let sources = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let (payer, updater) = (sources.sender(), sources.subject());
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&updater,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+
+			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			ensure!(
+				!<RevocationList<T>>::contains_key(&statement_id, statement_details.digest),
+				Error::<T>::StatementRevoked
+			);
+
+			let previous = <Metadata<T>>::get(&statement_id, &key);
+			if previous.is_none() {
+				ensure!(
+					<MetadataCount<T>>::get(&statement_id) < T::MaxMetadataEntries::get(),
+					Error::<T>::MetadataLimitExceeded
+				);
+			}
+
+			let deposit = Self::metadata_deposit(key.len(), value.len());
+			<T as Config>::Currency::reserve(&payer, deposit)?;
+			match previous {
+				Some(entry) => {
+					<T as Config>::Currency::unreserve(&entry.depositor, entry.deposit);
+				},
+				None => <MetadataCount<T>>::mutate(&statement_id, |count| {
+					*count = count.saturating_add(1)
+				}),
+			}
+			<Metadata<T>>::insert(
+				&statement_id,
+				&key,
+				MetadataEntryOf::<T> { value, depositor: payer, deposit },
+			);
+
+			Self::deposit_indexed_event(
+				Event::MetadataSet { identifier: statement_id, key, author: updater },
+				&space_id,
+				&statement_details.schema,
+			);
+
+			Ok(())
+		}
+
+		/// Clears a metadata entry from a statement and returns its deposit
+		/// to the account it was reserved from.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from a
+		///   space delegate.
+		/// - `statement_id`: The identifier of the statement.
+		/// - `key`: The key of the metadata entry to clear.
+		/// - `authorization`: The authorization ID, verifying the caller's delegation status.
+		///
+		/// # Errors
+		/// - Returns `StatementNotFound` if the statement does not exist.
+		/// - Returns `UnauthorizedOperation` if the statement is not part of the authorized space.
+		/// - Returns `MetadataNotFound` if the statement has no entry for the key.
+		///
+		/// # Events
+		/// - Emits `MetadataCleared` on success.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::clear_metadata())]
+		pub fn clear_metadata(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			key: MetadataKeyOf<T>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&updater,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+
+			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			let entry =
+				<Metadata<T>>::take(&statement_id, &key).ok_or(Error::<T>::MetadataNotFound)?;
+			<T as Config>::Currency::unreserve(&entry.depositor, entry.deposit);
+			<MetadataCount<T>>::mutate_exists(&statement_id, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0)
+			});
+
+			Self::deposit_indexed_event(
+				Event::MetadataCleared { identifier: statement_id, key, author: updater },
+				&space_id,
+				&statement_details.schema,
+			);
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns `true` if the statement has an expiry set and the current
+	/// block number is past it. Statements without an expiry never expire.
+	pub fn is_expired(statement_id: &StatementIdOf) -> bool {
+		<StatementExpiry<T>>::get(statement_id)
+			.is_some_and(|expires_at| frame_system::Pallet::<T>::block_number() > expires_at)
+	}
+
+	/// Returns the latest details of a statement along with its revocation
+	/// status, expiry, witness state and activity timeline, or `None` if the
+	/// statement does not exist. Used by the `StatementApi` runtime API.
+	pub fn statement_info(statement_id: &StatementIdOf) -> Option<StatementInfoOf<T>> {
+		let details = <Statements<T>>::get(statement_id)?;
+		let revoked = <RevocationList<T>>::contains_key(statement_id, details.digest);
+		let expires_at = <StatementExpiry<T>>::get(statement_id);
+		let pending = <PendingWitnesses<T>>::contains_key(statement_id);
+		let revision = Self::revision(statement_id);
+		let timeline = identifier::Identifiers::<T>::get(statement_id, IdentifierTypeOf::Statement)
+			.map(|events| events.into_inner())
+			.unwrap_or_default();
+
+		Some(StatementInfoOf::<T> {
+			identifier: statement_id.clone(),
+			details,
+			revoked,
+			expires_at,
+			pending,
+			revision,
+			timeline,
+		})
+	}
+
+	/// Returns the anchoring receipt of the latest digest of a statement, or
+	/// `None` if the statement does not exist or still awaits witness
+	/// approvals. Used by the `StatementApi` runtime API.
+	pub fn receipt(statement_id: &StatementIdOf) -> Option<ReceiptOf<T>> {
+		if <PendingWitnesses<T>>::contains_key(statement_id) {
+			return None;
+		}
+		let details = <Statements<T>>::get(statement_id)?;
+		let state = <AnchorState<T>>::get(details.digest, &details.space)?;
+		let block_hash = frame_system::BlockHash::<T>::try_get(state.block).ok();
+		let commit_type = if Self::revision(statement_id) == 0 {
+			CommitType::Genesis
+		} else {
+			CommitType::Update
+		};
+
+		Some(ReceiptOf::<T> {
+			identifier: statement_id.clone(),
+			digest: details.digest,
+			block_hash,
+			block_number: state.block,
+			commit_type,
+		})
+	}
+
+	/// Returns the revision of a statement, which starts at zero when the
+	/// statement is registered and is incremented by every update. It is
+	/// the index of the latest commit of the statement.
+	pub fn revision(statement_id: &StatementIdOf) -> u32 {
+		<CommitCount<T>>::get(statement_id).saturating_sub(1)
+	}
+
+	/// Resolves a statement from a digest anchored within the given space.
+	/// The digest may be any state of the statement or one of its
+	/// presentations.
+	pub fn statement_info_by_digest(
+		digest: &StatementDigestOf<T>,
+		space_id: &SpaceIdOf,
+	) -> Option<StatementInfoOf<T>> {
+		<IdentifierLookup<T>>::get(digest, space_id)
+			.and_then(|commit| Self::statement_info(&commit.identifier))
+	}
+
+	/// Returns the revocation status of an index of the statement's status
+	/// list, or `None` if the statement has no status list or the index is
+	/// not covered by it. Used by the `StatementApi` runtime API.
+	pub fn status_at(statement_id: &StatementIdOf, index: u32) -> Option<StatusOf> {
+		let length = <StatusLists<T>>::get(statement_id)?;
+		if index >= length {
+			return None;
+		}
+
+		let bit = index % STATUS_LIST_CHUNK_BITS;
+		let revoked = <StatusListChunks<T>>::get(statement_id, index / STATUS_LIST_CHUNK_BITS)
+			.is_some_and(|chunk| chunk[(bit / 8) as usize] & (1u8 << (bit % 8)) != 0);
+
+		Some(revoked)
+	}
+
+	/// Verifies that `leaf` is included in the Merkle tree whose root is the
+	/// latest digest of the statement. This allows holders to selectively
+	/// disclose individual attributes of a credential anchored by its root.
+	/// Returns `false` if the statement does not exist. The revocation
+	/// status is not taken into account. Used by the `StatementApi` runtime
+	/// API.
+	pub fn verify_leaf(
+		statement_id: &StatementIdOf,
+		leaf: StatementDigestOf<T>,
+		proof: &[StatementDigestOf<T>],
+	) -> bool {
+		<Statements<T>>::get(statement_id).is_some_and(|details| {
+			merkle::verify_proof::<<T as frame_system::Config>::Hashing>(
+				&details.digest,
+				leaf,
+				proof,
+			)
+		})
+	}
+
+	/// Returns the anchor state of a digest in every space it has been
+	/// anchored in. Used by the `StatementApi` runtime API to verify batches
+	/// of documents without knowing their spaces upfront.
+	pub fn anchors_of(digest: &StatementDigestOf<T>) -> Vec<(SpaceIdOf, AnchorStateOf<T>)> {
+		<AnchorState<T>>::iter_prefix(digest).collect()
+	}
+
+	/// Anchors a new statement in a space on behalf of an authorized
+	/// creator and returns its identifier. Shared by `register` and
+	/// `register_with_witnesses`.
+	fn statement_registration(
+		digest: StatementDigestOf<T>,
+		space_id: SpaceIdOf,
+		creator: StatementCreatorOf<T>,
+		schema_id: Option<SchemaIdOf>,
+		content_type: ContentTypeOf,
+		payer: Option<&AccountIdOf<T>>,
+	) -> Result<StatementIdOf, Error<T>> {
+		Self::ensure_register_authorized(&creator, &space_id, &schema_id)?;
+		Self::ensure_digest_policy(&digest, &schema_id)?;
+
+		// Id Digest = concat (H(<scale_encoded_statement_digest>,
+		// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
+		let identifier = identifier::statement_identifier::<<T as frame_system::Config>::Hashing>(
+			&digest, &space_id, &creator,
+		)
+		.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		ensure!(!<Statements<T>>::contains_key(&identifier), Error::<T>::StatementAlreadyAnchored);
+		ensure!(
+			!<IdentifierLookup<T>>::contains_key(digest, &space_id),
+			Error::<T>::StatementDigestAlreadyAnchored
+		);
+		T::OnAnchored::on_anchored(&identifier, &digest, schema_id.as_ref(), content_type)
+			.map_err(|_| Error::<T>::ContentRejected)?;
+
+		let details = StatementDetailsOf::<T> {
+			digest,
+			schema: schema_id.clone(),
+			space: space_id.clone(),
+			content_type,
+		};
+		if let Some(payer) = payer {
+			Self::hold_statement_deposit(&identifier, &details, payer)?;
+		}
+		<Statements<T>>::insert(&identifier, details);
+
+		<Entries<T>>::insert(&identifier, digest, creator.clone());
+		Self::commit_digest(digest, &space_id, &identifier);
+
+		Self::update_activity(&identifier, CallTypeOf::Genesis)?;
+		Self::record_stats(schema_id.as_ref(), CallTypeOf::Genesis);
+
+		Self::deposit_indexed_event(
+			Event::Register { identifier: identifier.clone(), digest, author: creator },
+			&space_id,
+			&schema_id,
+		);
+
+		Ok(identifier)
+	}
+
+	/// Records `digest` in `AnchorState` as anchored and not revoked in the
+	/// current block.
+	fn anchor_digest(
+		digest: StatementDigestOf<T>,
+		space_id: &SpaceIdOf,
+		identifier: StatementIdOf,
+		schema: Option<SchemaIdOf>,
+	) {
+		<AnchorState<T>>::insert(
+			digest,
+			space_id,
+			AnchorStateOf::<T> {
+				identifier,
+				schema,
+				revoked: false,
+				block: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+		Self::record_attestation_leaf(&digest);
+	}
+
+	/// Appends the Keccak-256 hash of an anchored digest to the attestation
+	/// leaves, unless attestations are disabled.
+	fn record_attestation_leaf(digest: &StatementDigestOf<T>) {
+		if T::AttestationPeriod::get().is_zero() {
+			return
+		}
+
+		let leaf = <Keccak256 as sp_runtime::traits::Hash>::hash(&digest.encode());
+		let number = <AttestationLeafCount<T>>::get();
+		<AttestationLeaves<T>>::insert(number, leaf);
+		<AttestationLeafCount<T>>::put(number.saturating_add(1));
+		// The off-chain index is not available while building genesis, leaves
+		// of genesis statements are read from state instead.
+		if !frame_system::Pallet::<T>::block_number().is_zero() {
+			sp_io::offchain_index::set(&(ATTESTATION_LEAF_PREFIX, number).encode(), &leaf.encode());
+		}
+	}
+
+	/// Commits the pending attestation leaves, up to `MaxAttestationLeaves`
+	/// of them, once every `AttestationPeriod` blocks. The root and leaf
+	/// range are stored and deposited as `AttestationCommitted` for bridge
+	/// relayers, and the committed leaves are removed from state.
+	fn commit_attestations(block_number: BlockNumberFor<T>) -> Weight {
+		let period = T::AttestationPeriod::get();
+		if period.is_zero() || !(block_number % period).is_zero() {
+			return Weight::zero()
+		}
+
+		let first_leaf = <CommittedLeafCount<T>>::get();
+		let pending = <AttestationLeafCount<T>>::get().saturating_sub(first_leaf);
+		let leaf_count = pending.min(T::MaxAttestationLeaves::get().into()) as u32;
+		if leaf_count == 0 {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut leaves = Vec::with_capacity(leaf_count as usize);
+		for number in first_leaf..first_leaf + u64::from(leaf_count) {
+			leaves.extend(<AttestationLeaves<T>>::take(number));
+		}
+		let root = merkle::merkle_root::<Keccak256>(&leaves);
+
+		let commitment = <NextAttestationCommitment<T>>::get();
+		<AttestationCommitments<T>>::insert(
+			commitment,
+			AttestationCommitmentOf::<T> { root, first_leaf, leaf_count, block: block_number },
+		);
+		<NextAttestationCommitment<T>>::put(commitment.saturating_add(1));
+		<CommittedLeafCount<T>>::put(first_leaf.saturating_add(leaf_count.into()));
+
+		Self::deposit_event(Event::AttestationCommitted {
+			commitment,
+			root,
+			first_leaf,
+			leaf_count,
+		});
+
+		T::DbWeight::get().reads_writes(3 + u64::from(leaf_count), 4 + u64::from(leaf_count))
+	}
+
+	/// Counts a registration, update or revocation of a statement under
+	/// `schema_id` in the chain totals, the totals of the schema and the
+	/// current period. Only the calls anchoring, updating or revoking a
+	/// statement record their activity here; other actions are not counted.
+	fn record_stats(schema_id: Option<&SchemaIdOf>, action: CallTypeOf) {
+		let count: fn(&mut AnchoringStats) = match action {
+			CallTypeOf::Genesis => |stats| stats.created = stats.created.saturating_add(1),
+			CallTypeOf::Update => |stats| stats.updated = stats.updated.saturating_add(1),
+			CallTypeOf::Revoke => |stats| stats.revoked = stats.revoked.saturating_add(1),
+			_ => return,
+		};
+
+		<AnchoringTotals<T>>::mutate(count);
+		if let Some(schema_id) = schema_id {
+			<SchemaAnchoringStats<T>>::mutate(schema_id, count);
+		}
+		let period = T::StatsPeriod::get();
+		if !period.is_zero() {
+			let index = frame_system::Pallet::<T>::block_number() / period;
+			<PeriodAnchoringStats<T>>::mutate(index, |stats| {
+				count(stats.get_or_insert_with(Default::default))
+			});
+		}
+	}
+
+	/// Removes the statistics of the period falling out of the history
+	/// window when a new period starts.
+	fn prune_period_stats(block_number: BlockNumberFor<T>) -> Weight {
+		let period = T::StatsPeriod::get();
+		if period.is_zero() || !(block_number % period).is_zero() {
+			return Weight::zero()
+		}
+
+		let index = block_number / period;
+		let depth = BlockNumberFor::<T>::from(T::StatsHistoryDepth::get());
+		if index >= depth {
+			<PeriodAnchoringStats<T>>::remove(index - depth);
+		}
+
+		T::DbWeight::get().writes(1)
+	}
+
+	/// Returns the statement activity counted since genesis, or for a
+	/// single schema if one is given. Used by the `StatementApi` runtime
+	/// API.
+	pub fn anchoring_stats(schema_id: Option<&SchemaIdOf>) -> AnchoringStats {
+		match schema_id {
+			Some(schema_id) => <SchemaAnchoringStats<T>>::get(schema_id),
+			None => <AnchoringTotals<T>>::get(),
+		}
+	}
+
+	/// Returns the statement activity counted in the period containing
+	/// `block_number`, or `None` if period statistics are disabled, nothing
+	/// was counted in the period or it has been pruned. Used by the
+	/// `StatementApi` runtime API.
+	pub fn period_stats(block_number: BlockNumberFor<T>) -> Option<AnchoringStats> {
+		let period = T::StatsPeriod::get();
+		if period.is_zero() {
+			return None
+		}
+		<PeriodAnchoringStats<T>>::get(block_number / period)
+	}
+
+	/// Updates the revocation status of a digest in `AnchorState`. Digests
+	/// that are not anchored yet, such as those of statements pending
+	/// witness approvals, are left untouched.
+	fn set_anchor_revoked(digest: &StatementDigestOf<T>, space_id: &SpaceIdOf, revoked: StatusOf) {
+		<AnchorState<T>>::mutate(digest, space_id, |state| {
+			if let Some(state) = state {
+				state.revoked = revoked;
+			}
+		});
+	}
+
+	/// Marks the latest state of a statement as revoked by `updater`,
+	/// records the activity and deposits the `Revoke` event. Callers are
+	/// responsible for the authorization checks.
+	fn revoke_statement(
+		statement_id: StatementIdOf,
+		statement_details: &StatementDetailsOf<T>,
+		updater: StatementCreatorOf<T>,
+	) -> Result<(), Error<T>> {
+		<RevocationList<T>>::insert(
+			&statement_id,
+			statement_details.digest,
+			StatementEntryStatusOf::<T> { creator: updater.clone(), revoked: true },
+		);
+		Self::set_anchor_revoked(&statement_details.digest, &statement_details.space, true);
+		<RevocationSchedule<T>>::remove(&statement_id);
+
+		Self::update_activity(&statement_id, CallTypeOf::Revoke)?;
+		Self::record_stats(statement_details.schema.as_ref(), CallTypeOf::Revoke);
+		Self::deposit_indexed_event(
+			Event::Revoke { identifier: statement_id, author: updater },
+			&statement_details.space,
+			&statement_details.schema,
+		);
+
+		Ok(())
+	}
+
+	/// Revokes the statements scheduled for revocation at `block_number`.
+	/// Entries for statements that were removed, already revoked or whose
+	/// revocation was rescheduled in the meantime are skipped. Entries whose
+	/// updater no longer holds revoke rights over the statement's space are
+	/// skipped with a `ScheduledRevocationSkipped` event.
+	fn execute_scheduled_revocations(block_number: BlockNumberFor<T>) -> Weight {
+		let mut total_weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+
+		for (statement_id, updater, authorization) in <ScheduledRevocations<T>>::take(block_number)
+		{
+			total_weight += T::DbWeight::get().reads(6);
+
+			if <RevocationSchedule<T>>::get(&statement_id) != Some(block_number) {
+				continue;
+			}
+			let Some(statement_details) = <Statements<T>>::get(&statement_id) else {
+				<RevocationSchedule<T>>::remove(&statement_id);
+				continue;
+			};
+			if <RevocationList<T>>::contains_key(&statement_id, statement_details.digest) {
+				<RevocationSchedule<T>>::remove(&statement_id);
+				continue;
+			}
+
+			let authorized =
+				pallet_chain_space::Pallet::<T>::ensure_revoke_authority(&authorization, &updater)
+					.map_or(false, |space_id| space_id == statement_details.space);
+			if !authorized {
+				<RevocationSchedule<T>>::remove(&statement_id);
+				Self::deposit_event(Event::ScheduledRevocationSkipped {
+					identifier: statement_id,
+					author: updater,
+				});
+				continue;
+			}
+
+			let _ = Self::revoke_statement(statement_id, &statement_details, updater);
+			total_weight += <T as Config>::WeightInfo::revoke();
+		}
+
+		total_weight
+	}
+
+	/// Anchors a statement seeded at genesis in the space `creator` seeded
+	/// with `space_code`.
+	fn genesis_statement(
+		space_code: &SpaceCodeOf<T>,
+		creator: &StatementCreatorOf<T>,
+		digest: StatementDigestOf<T>,
+	) -> DispatchResult {
+		let space_id = pallet_chain_space::Pallet::<T>::ensure_genesis_space(space_code, creator)?;
+
+		let identifier = Self::statement_registration(
+			digest,
+			space_id.clone(),
+			creator.clone(),
+			None,
+			ContentTypeOf::Generic,
+			None,
+		)?;
+		Self::anchor_digest(digest, &space_id, identifier, None);
+
+		Ok(())
+	}
+
+	/// Deposits a statement event indexed by the space and, if one is
+	/// referenced, the schema of the statement. See
+	/// `pallet_chain_space::Pallet::identifier_topic` for how topics are
+	/// derived.
+	fn deposit_indexed_event(
+		event: Event<T>,
+		space_id: &SpaceIdOf,
+		schema_id: &Option<SchemaIdOf>,
+	) {
+		let mut topics = vec![pallet_chain_space::Pallet::<T>::identifier_topic(space_id)];
+		if let Some(schema_id) = schema_id {
+			topics.push(pallet_chain_space::Pallet::<T>::identifier_topic(schema_id));
+		}
+
+		let event: <T as Config>::RuntimeEvent = event.into();
+		frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+	}
+
+	/// Ensures that `creator` may register a statement in the space under
+	/// the schema, if one is referenced, as decided by `AuthorizeAnchor`.
+	fn ensure_register_authorized(
+		creator: &StatementCreatorOf<T>,
+		space_id: &SpaceIdOf,
+		schema_id: &Option<SchemaIdOf>,
+	) -> Result<(), Error<T>> {
+		T::AuthorizeAnchor::authorize_register(creator, space_id, schema_id.as_ref())
+			.map_err(Error::<T>::from)
+	}
+
+	/// Ensures that `creator` may anchor further digests for a statement in
+	/// the space, as decided by `AuthorizeAnchor`.
+	fn ensure_update_authorized(
+		creator: &StatementCreatorOf<T>,
+		space_id: &SpaceIdOf,
+		schema_id: &Option<SchemaIdOf>,
+	) -> Result<(), Error<T>> {
+		T::AuthorizeAnchor::authorize_update(creator, space_id, schema_id.as_ref())
+			.map_err(Error::<T>::from)
+	}
+
+	/// Returns the deposit held for a metadata entry with a key and value of
+	/// the given lengths.
+	fn metadata_deposit(key_len: usize, value_len: usize) -> BalanceOf<T> {
+		let bytes: BalanceOf<T> = (key_len.saturating_add(value_len) as u32).into();
+		T::MetadataDepositBase::get()
+			.saturating_add(T::MetadataDepositPerByte::get().saturating_mul(bytes))
+	}
+
+	/// Returns the deposit held for a statement with the given details.
+	fn statement_deposit(details: &StatementDetailsOf<T>) -> BalanceOf<T> {
+		let bytes: BalanceOf<T> = (details.encoded_size() as u32).into();
+		T::StatementDepositBase::get()
+			.saturating_add(T::StatementDepositPerByte::get().saturating_mul(bytes))
+	}
+
+	/// Reserves the deposit of a new statement from `payer`. Nothing is
+	/// recorded when the deposit is zero.
+	fn hold_statement_deposit(
+		statement_id: &StatementIdOf,
+		details: &StatementDetailsOf<T>,
+		payer: &AccountIdOf<T>,
+	) -> Result<(), Error<T>> {
+		let amount = Self::statement_deposit(details);
+		if amount.is_zero() {
+			return Ok(());
+		}
+		<T as Config>::Currency::reserve(payer, amount)
+			.map_err(|_| Error::<T>::InsufficientDeposit)?;
+		<Deposits<T>>::insert(
+			statement_id,
+			StatementDepositOf::<T> { depositor: payer.clone(), amount },
+		);
+		Ok(())
+	}
+
+	/// Returns the deposit held for a statement, if any, to the account it
+	/// was reserved from.
+	fn release_statement_deposit(statement_id: &StatementIdOf) {
+		if let Some(deposit) = <Deposits<T>>::take(statement_id) {
+			<T as Config>::Currency::unreserve(&deposit.depositor, deposit.amount);
+		}
+	}
+
+	/// Releases the deposits of a statement and removes the state attached
+	/// to it besides its entries and presentations: metadata, expiry,
+	/// witness requirement, scheduled revocation and status list. Called
+	/// when the removal of a statement starts, so that the deposits are
+	/// returned even if the removal takes several calls.
+	fn clear_statement_attachments(statement_id: &StatementIdOf) {
+		Self::release_metadata(statement_id);
+		Self::release_statement_deposit(statement_id);
+		<StatementExpiry<T>>::remove(statement_id);
+		<PendingWitnesses<T>>::remove(statement_id);
+		if let Some(at_block) = <RevocationSchedule<T>>::take(statement_id) {
+			<ScheduledRevocations<T>>::mutate(at_block, |revocations| {
+				revocations.retain(|(identifier, _, _)| identifier != statement_id)
+			});
+		}
+		if let Some(length) = <StatusLists<T>>::take(statement_id) {
+			let _ = <StatusListChunks<T>>::clear_prefix(
+				statement_id,
+				length.div_ceil(STATUS_LIST_CHUNK_BITS),
+				None,
+			);
+		}
+	}
+
+	/// Removes all metadata entries of a statement and returns their
+	/// deposits. The number of entries is bounded by `MaxMetadataEntries`.
+	fn release_metadata(statement_id: &StatementIdOf) {
+		for (_, entry) in <Metadata<T>>::drain_prefix(statement_id) {
+			<T as Config>::Currency::unreserve(&entry.depositor, entry.deposit);
+		}
+		<MetadataCount<T>>::remove(statement_id);
+	}
+
+	/// Records `digest` as the next commit of a statement in
+	/// `IdentifierLookup`. Callers must ensure the digest is not yet mapped
+	/// in the space.
+	fn commit_digest(
+		digest: StatementDigestOf<T>,
+		space_id: &SpaceIdOf,
+		statement_id: &StatementIdOf,
+	) {
+		let index = <CommitCount<T>>::mutate(statement_id, |count| {
+			let index = *count;
+			*count = count.saturating_add(1);
+			index
+		});
+		<IdentifierLookup<T>>::insert(
+			digest,
+			space_id,
+			DigestCommitOf { identifier: statement_id.clone(), index },
+		);
+	}
+
+	/// Ensures a digest may be anchored under a schema. Schemas which
+	/// `AuthorizeAnchor` marks as requiring unique digests reject digests that
+	/// are already anchored in any space, whoever anchored them, as well as
+	/// digests of statements still awaiting witness approvals.
+	fn ensure_digest_policy(
+		digest: &StatementDigestOf<T>,
+		schema_id: &Option<SchemaIdOf>,
+	) -> Result<(), Error<T>> {
+		if let Some(schema_id) = schema_id {
+			if T::AuthorizeAnchor::requires_unique_digest(schema_id) {
+				ensure!(
+					<AnchorState<T>>::iter_key_prefix(digest).next().is_none(),
+					Error::<T>::DigestNotUnique
+				);
+				ensure!(
+					!<IdentifierLookup<T>>::iter_prefix_values(digest)
+						.any(|commit| <PendingWitnesses<T>>::contains_key(&commit.identifier)),
+					Error::<T>::DigestNotUnique
+				);
+			}
+		}
+		Ok(())
+	}
+
 	/// Updates the global timeline with a new activity event for a statement.
 	/// This function is called whenever a significant action is performed on a
 	/// statement, ensuring that all such activities are logged with a timestamp
@@ -0,0 +1,197 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the statement pallet.
+
+use crate::{
+	AnchorState, AnchorStateOf, CommitCount, Config, DigestCommitOf, Entries, IdentifierLookup,
+	Pallet, PendingWitnesses, Presentations, RevocationList, StatementIdOf,
+};
+use frame_support::{
+	migrations::VersionedMigration,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+/// Migration from storage version 1 to 2, which introduced `AnchorState`.
+pub mod v2 {
+	use super::*;
+
+	/// Populates `AnchorState` from the digests of statements anchored
+	/// before it was introduced. The original anchoring block of these
+	/// digests is not known, so the block of the migration is recorded
+	/// instead. Statements still pending witness approvals are skipped.
+	///
+	/// Statements are read in their layout before storage version 4, as
+	/// this migration runs ahead of [`v4::MigrateV3ToV4`].
+	pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let block = frame_system::Pallet::<T>::block_number();
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+
+			for (identifier, digest, _) in Entries::<T>::iter() {
+				reads += 3;
+				if PendingWitnesses::<T>::contains_key(&identifier) {
+					continue;
+				}
+				let Some(details) = v4::Statements::<T>::get(&identifier) else {
+					continue;
+				};
+				reads += 1;
+				let revoked = RevocationList::<T>::contains_key(&identifier, digest);
+
+				AnchorState::<T>::insert(
+					digest,
+					&details.space,
+					AnchorStateOf::<T> { identifier, schema: details.schema, revoked, block },
+				);
+				writes += 1;
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+
+	/// [`InnerMigrateV1ToV2`] wrapped in a [`VersionedMigration`], which
+	/// only runs when the on-chain storage version is 1.
+	pub type MigrateV1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		InnerMigrateV1ToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migration from storage version 2 to 3, which added commit indices to
+/// `IdentifierLookup` and introduced `CommitCount`.
+pub mod v3 {
+	use super::*;
+
+	/// Counts the commits of every statement and rewrites `IdentifierLookup`
+	/// entries with their commit index. The order in which earlier states
+	/// were anchored was not recorded, so only the latest digest and the
+	/// presentations receive the index of the latest commit, while earlier
+	/// states are given index 0.
+	///
+	/// Statements are read in their layout before storage version 4, as
+	/// this migration runs ahead of [`v4::MigrateV3ToV4`].
+	pub struct InnerMigrateV2ToV3<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+
+			for identifier in v4::Statements::<T>::iter_keys() {
+				let count = Entries::<T>::iter_prefix(&identifier).count() as u32;
+				reads += 1 + u64::from(count);
+				CommitCount::<T>::insert(&identifier, count);
+				writes += 1;
+			}
+
+			IdentifierLookup::<T>::translate::<StatementIdOf, _>(|digest, _, identifier| {
+				reads += 3;
+				writes += 1;
+				let latest = v4::Statements::<T>::get(&identifier).map(|details| details.digest);
+				let index = if latest == Some(digest) ||
+					Presentations::<T>::contains_key(&identifier, digest)
+				{
+					CommitCount::<T>::get(&identifier).saturating_sub(1)
+				} else {
+					0
+				};
+				Some(DigestCommitOf { identifier, index })
+			});
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+
+	/// [`InnerMigrateV2ToV3`] wrapped in a [`VersionedMigration`], which
+	/// only runs when the on-chain storage version is 2.
+	pub type MigrateV2ToV3<T> = VersionedMigration<
+		2,
+		3,
+		InnerMigrateV2ToV3<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migration from storage version 3 to 4, which added the declared content
+/// type to `StatementDetails`.
+pub mod v4 {
+	use super::*;
+	use crate::{ContentTypeOf, SchemaIdOf, SpaceIdOf, StatementDetailsOf, StatementDigestOf};
+	use codec::{Decode, Encode};
+	use frame_support::{storage_alias, Blake2_128Concat};
+
+	/// `StatementDetails` as stored before storage version 4.
+	#[derive(Encode, Decode)]
+	pub struct OldStatementDetails<StatementDigestOf, SchemaIdOf, SpaceIdOf> {
+		pub digest: StatementDigestOf,
+		pub space: SpaceIdOf,
+		pub schema: Option<SchemaIdOf>,
+	}
+
+	pub type OldStatementDetailsOf<T> =
+		OldStatementDetails<StatementDigestOf<T>, SchemaIdOf, SpaceIdOf>;
+
+	/// `Statements` as stored before storage version 4. Earlier migrations
+	/// read statements through it, since they run before the translation.
+	#[storage_alias]
+	pub type Statements<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, StatementIdOf, OldStatementDetailsOf<T>>;
+
+	/// Rewrites every statement with the `Generic` content type, as no
+	/// content type was declared for statements anchored before.
+	pub struct InnerMigrateV3ToV4<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+
+			crate::Statements::<T>::translate::<OldStatementDetailsOf<T>, _>(|_, old| {
+				translated += 1;
+				Some(StatementDetailsOf::<T> {
+					digest: old.digest,
+					space: old.space,
+					schema: old.schema,
+					content_type: ContentTypeOf::Generic,
+				})
+			});
+
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+	}
+
+	/// [`InnerMigrateV3ToV4`] wrapped in a [`VersionedMigration`], which
+	/// only runs when the on-chain storage version is 3.
+	pub type MigrateV3ToV4<T> = VersionedMigration<
+		3,
+		4,
+		InnerMigrateV3ToV4<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
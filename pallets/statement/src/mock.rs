@@ -19,13 +19,16 @@
 use super::*;
 use crate as pallet_statement;
 use cord_utilities::mock::{mock_origin, SubjectId};
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
 use pallet_chain_space::IsPermissioned;
 
 use frame_system::EnsureRoot;
 use sp_runtime::{
 	traits::{IdentifyAccount, IdentityLookup, Verify},
-	BuildStorage, MultiSignature,
+	BuildStorage, DispatchError, MultiSignature,
 };
 
 type Signature = MultiSignature;
@@ -36,6 +39,7 @@ pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
 frame_support::construct_runtime!(
 	pub enum Test {
 		System: frame_system,
+		Balances: pallet_balances,
 		Schema:pallet_schema,
 		Space: pallet_chain_space,
 		Identifier: identifier,
@@ -56,9 +60,15 @@ impl frame_system::Config for Test {
 	type Block = Block;
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u64>;
 	type SS58Prefix = SS58Prefix;
 }
 
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
 impl mock_origin::Config for Test {
 	type RuntimeOrigin = RuntimeOrigin;
 	type AccountId = AccountId;
@@ -69,6 +79,38 @@ parameter_types! {
 	#[derive(Debug, Clone)]
 	pub const MaxDigetsPerBatch: u16 = 5u16;
 	pub const MaxRemoveEntries: u16 = 5u16;
+	pub const MaxStatementWitnesses: u32 = 5u32;
+	pub const MaxStatusListLength: u32 = 1_024u32;
+	pub const MaxRemarkLength: u32 = 64u32;
+	pub const MaxScheduledRevocationsPerBlock: u32 = 5u32;
+	pub const MetadataDepositBase: u64 = 10;
+	pub const MetadataDepositPerByte: u64 = 1;
+	pub const MaxMetadataEntries: u32 = 2u32;
+	pub const MaxMetadataKeyLength: u32 = 64u32;
+	pub const MaxMetadataValueLength: u32 = 256u32;
+	pub static StatementDepositBase: u64 = 0;
+	pub static StatementDepositPerByte: u64 = 0;
+	pub static AttestationPeriod: u64 = 0;
+	pub const MaxAttestationLeaves: u32 = 2u32;
+	pub static StatsPeriod: u64 = 0;
+	pub const StatsHistoryDepth: u32 = 2u32;
+	pub static RejectedContentType: Option<ContentTypeOf> = None;
+}
+
+/// Anchoring hook rejecting the content type set in `RejectedContentType`.
+pub struct ContentTypeCheck;
+impl OnStatementAnchored<StatementIdOf, StatementDigestOf<Test>, SchemaIdOf> for ContentTypeCheck {
+	fn on_anchored(
+		_identifier: &StatementIdOf,
+		_digest: &StatementDigestOf<Test>,
+		_schema: Option<&SchemaIdOf>,
+		content_type: ContentTypeOf,
+	) -> DispatchResult {
+		if RejectedContentType::get() == Some(content_type) {
+			return Err(DispatchError::Other("content type rejected"))
+		}
+		Ok(())
+	}
 }
 
 impl Config for Test {
@@ -77,6 +119,24 @@ impl Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type MaxDigestsPerBatch = MaxDigetsPerBatch;
 	type MaxRemoveEntries = MaxRemoveEntries;
+	type MaxStatementWitnesses = MaxStatementWitnesses;
+	type MaxStatusListLength = MaxStatusListLength;
+	type MaxRemarkLength = MaxRemarkLength;
+	type MaxScheduledRevocationsPerBlock = MaxScheduledRevocationsPerBlock;
+	type Currency = Balances;
+	type StatementDepositBase = StatementDepositBase;
+	type StatementDepositPerByte = StatementDepositPerByte;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type MaxMetadataEntries = MaxMetadataEntries;
+	type MaxMetadataKeyLength = MaxMetadataKeyLength;
+	type MaxMetadataValueLength = MaxMetadataValueLength;
+	type AttestationPeriod = AttestationPeriod;
+	type MaxAttestationLeaves = MaxAttestationLeaves;
+	type StatsPeriod = StatsPeriod;
+	type StatsHistoryDepth = StatsHistoryDepth;
+	type OnAnchored = ContentTypeCheck;
+	type AuthorizeAnchor = pallet_schema::SchemaAuthorization<Test>;
 	type WeightInfo = weights::SubstrateWeight<Test>;
 }
 
@@ -98,6 +158,11 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type CapacityAlertThresholds = ();
+	type MaxSpaceAnchorsPerBlock = ConstU32<0>;
+	type MaxDelegateAnchorsPerBlock = ConstU32<0>;
+	type MaxExpiryScanPerBlock = ConstU32<0>;
+	type MaxDelegateReassignments = ConstU32<0>;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type WeightInfo = ();
@@ -114,6 +179,11 @@ impl pallet_schema::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
+	type MaxPreimageSchemaLength = MaxEncodedSchemaLength;
+	type Preimages = ();
+	type Currency = Balances;
+	type SchemaDepositBase = ConstU64<0>;
+	type SchemaDepositPerByte = ConstU64<0>;
 }
 
 parameter_types! {
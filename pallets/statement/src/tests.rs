@@ -2,11 +2,18 @@ use super::*;
 use crate::mock::*;
 use codec::Encode;
 use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
-use frame_support::{assert_err, assert_ok, BoundedVec};
+use frame_support::{
+	assert_err, assert_ok,
+	traits::{Hooks, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use pallet_chain_space::SpaceCodeOf;
 use pallet_schema::{InputSchemaOf, SchemaHashOf};
-use sp_runtime::{traits::Hash, AccountId32};
+use sp_runtime::{
+	traits::{Hash, Keccak256},
+	AccountId32, BuildStorage,
+};
 
 /// Generates a statement ID from a statement digest.
 pub fn generate_statement_id<T: Config>(digest: &StatementDigestOf<T>) -> StatementIdOf {
@@ -67,7 +74,7 @@ fn register_statement_should_succeed() {
 			space_digest,
 		));
 
-		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
 
 		assert_ok!(Schema::create(
 			DoubleOrigin(author.clone(), creator.clone()).into(),
@@ -79,8 +86,16 @@ fn register_statement_should_succeed() {
 			DoubleOrigin(author, creator).into(),
 			statement_digest,
 			authorization_id,
-			Some(schema_id)
+			Some(schema_id.clone()),
+			ContentTypeOf::Generic
 		));
+
+		// The event is indexed by the space and the schema of the statement
+		let record = System::events().pop().expect("Registration should emit an event.");
+		assert_eq!(
+			record.topics,
+			vec![Space::identifier_topic(&space_id), Space::identifier_topic(&schema_id)]
+		);
 	});
 }
 
@@ -117,7 +132,8 @@ fn trying_to_register_statement_to_a_non_existent_space_should_fail() {
 				DoubleOrigin(author, delegate).into(),
 				statement_digest,
 				authorization_id,
-				Some(schema_id)
+				Some(schema_id),
+				ContentTypeOf::Generic
 			),
 			pallet_chain_space::Error::<Test>::AuthorizationNotFound
 		);
@@ -172,7 +188,8 @@ fn trying_to_register_statement_by_a_non_delegate_should_fail() {
 				DoubleOrigin(author, delegate).into(),
 				statement_digest,
 				authorization_id,
-				Some(schema_id)
+				Some(schema_id),
+				ContentTypeOf::Generic
 			),
 			pallet_chain_space::Error::<Test>::UnauthorizedOperation
 		);
@@ -234,15 +251,50 @@ fn updating_a_registered_statement_should_succeed() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			ContentTypeOf::Generic
 		));
 
+		assert_eq!(
+			Statement::receipt(&statement_id).map(|receipt| receipt.commit_type),
+			Some(CommitType::Genesis)
+		);
+
+		System::set_block_number(2);
 		assert_ok!(Statement::update(
-			DoubleOrigin(author, creator).into(),
+			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_id.clone(),
 			new_statement_digest,
-			authorization_id,
+			0,
+			authorization_id.clone(),
 		));
+		assert_eq!(Statement::revision(&statement_id), 1);
+
+		// The hash of a block is recorded once the next block starts.
+		let block_hash = <Test as frame_system::Config>::Hashing::hash(&[2u8][..]);
+		frame_system::BlockHash::<Test>::insert(2, block_hash);
+		assert_eq!(
+			Statement::receipt(&statement_id),
+			Some(ReceiptOf::<Test> {
+				identifier: statement_id.clone(),
+				digest: new_statement_digest,
+				block_hash: Some(block_hash),
+				block_number: 2,
+				commit_type: CommitType::Update,
+			})
+		);
+
+		// A concurrent update based on the previous revision is rejected.
+		assert_err!(
+			Statement::update(
+				DoubleOrigin(author, creator).into(),
+				statement_id.clone(),
+				<Test as frame_system::Config>::Hashing::hash(&[99u8; 32][..]),
+				0,
+				authorization_id,
+			),
+			Error::<Test>::StaleRevision
+		);
 
 		let revoked_statements = RevocationList::<Test>::get(statement_id, statement_digest)
 			.expect("Old Statement digest should be present on the revoked list.");
@@ -312,19 +364,22 @@ fn updating_a_registered_statement_by_a_space_delegate_should_succeed() {
 			space_id,
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
 		));
 
 		assert_ok!(Statement::register(
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			ContentTypeOf::Generic
 		));
 
 		assert_ok!(Statement::update(
 			DoubleOrigin(author, delegate).into(),
 			statement_id,
 			new_statement_digest,
+			0,
 			delegate_authorization_id,
 		));
 	});
@@ -390,7 +445,8 @@ fn trying_to_update_a_registered_statement_by_a_non_space_delegate_should_fail()
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			ContentTypeOf::Generic
 		));
 
 		assert_err!(
@@ -398,6 +454,7 @@ fn trying_to_update_a_registered_statement_by_a_non_space_delegate_should_fail()
 				DoubleOrigin(author, delegate).into(),
 				statement_id,
 				new_statement_digest,
+				0,
 				delegate_authorization_id,
 			),
 			pallet_chain_space::Error::<Test>::AuthorizationNotFound
@@ -459,7 +516,8 @@ fn trying_to_update_a_non_registered_statement_should_fail() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			new_statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			ContentTypeOf::Generic
 		));
 
 		assert_err!(
@@ -467,6 +525,7 @@ fn trying_to_update_a_non_registered_statement_should_fail() {
 				DoubleOrigin(author, creator).into(),
 				statement_id,
 				statement_digest,
+				0,
 				authorization_id,
 			),
 			Error::<Test>::StatementNotFound
@@ -527,7 +586,8 @@ fn revoking_a_registered_statement_should_succeed() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			ContentTypeOf::Generic
 		));
 
 		assert_ok!(Statement::revoke(
@@ -617,13 +677,15 @@ fn revoking_a_registered_statement_by_a_non_delegate_should_fail() {
 			space_id,
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
 		));
 
 		assert_ok!(Statement::register(
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			ContentTypeOf::Generic
 		));
 
 		assert_err!(
@@ -690,7 +752,8 @@ fn restoring_a_revoked_statement_should_succeed() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			ContentTypeOf::Generic
 		));
 
 		assert_ok!(Statement::revoke(
@@ -766,7 +829,8 @@ fn trying_to_restore_a_non_revoked_statement_should_fail() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			ContentTypeOf::Generic
 		));
 
 		assert_err!(
@@ -854,13 +918,15 @@ fn trying_to_restore_a_revoked_statement_by_a_non_delegate_should_fail() {
 			space_id,
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
 		));
 
 		assert_ok!(Statement::register(
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			ContentTypeOf::Generic
 		));
 
 		assert_ok!(Statement::revoke(
@@ -928,13 +994,15 @@ fn registering_a_statement_again_should_fail() {
 			space_id,
 			delegate.clone(),
 			authorization_id.clone(),
+			None,
 		));
 
 		assert_ok!(Statement::register(
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id.clone())
+			Some(schema_id.clone()),
+			ContentTypeOf::Generic
 		));
 
 		assert_err!(
@@ -942,7 +1010,8 @@ fn registering_a_statement_again_should_fail() {
 				DoubleOrigin(author, creator).into(),
 				statement_digest,
 				authorization_id,
-				Some(schema_id)
+				Some(schema_id),
+				ContentTypeOf::Generic
 			),
 			Error::<Test>::StatementAlreadyAnchored
 		);
@@ -1004,13 +1073,15 @@ fn updating_a_registered_statement_again_should_fail() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			ContentTypeOf::Generic
 		));
 
 		assert_ok!(Statement::update(
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_id.clone(),
 			new_statement_digest,
+			0,
 			authorization_id.clone(),
 		));
 
@@ -1019,6 +1090,7 @@ fn updating_a_registered_statement_again_should_fail() {
 				DoubleOrigin(author, creator).into(),
 				statement_id,
 				new_statement_digest,
+				1,
 				authorization_id,
 			),
 			Error::<Test>::StatementDigestAlreadyAnchored
@@ -1064,7 +1136,8 @@ fn removing_nonexistent_presentation_should_fail() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			None
+			None,
+			ContentTypeOf::Generic
 		));
 
 		assert_err!(
@@ -1130,7 +1203,8 @@ fn bulk_registering_statements_with_same_digest_should_fail() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digests[0],
 			authorization_id.clone(),
-			Some(schema_id.clone())
+			Some(schema_id.clone()),
+			ContentTypeOf::Generic
 		));
 
 		assert_err!(
@@ -1138,7 +1212,8 @@ fn bulk_registering_statements_with_same_digest_should_fail() {
 				DoubleOrigin(author, creator).into(),
 				statement_digests,
 				authorization_id,
-				Some(schema_id)
+				Some(schema_id),
+				ContentTypeOf::Generic
 			),
 			Error::<Test>::BulkTransactionFailed
 		);
@@ -1199,7 +1274,8 @@ fn trying_to_update_or_revoke_or_add_presentation_for_revoked_statement_should_f
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			Some(schema_id)
+			Some(schema_id),
+			ContentTypeOf::Generic
 		));
 
 		// Revoke the statement
@@ -1215,6 +1291,7 @@ fn trying_to_update_or_revoke_or_add_presentation_for_revoked_statement_should_f
 				DoubleOrigin(author.clone(), creator.clone()).into(),
 				statement_id.clone(),
 				new_statement_digest,
+				0,
 				authorization_id.clone(),
 			),
 			Error::<Test>::StatementRevoked
@@ -1282,7 +1359,8 @@ fn nonexistent_presentation_should_fail() {
 			DoubleOrigin(author.clone(), creator.clone()).into(),
 			statement_digest,
 			authorization_id.clone(),
-			None
+			None,
+			ContentTypeOf::Generic
 		));
 
 		assert_err!(
@@ -1296,3 +1374,2285 @@ fn nonexistent_presentation_should_fail() {
 		);
 	});
 }
+
+#[test]
+fn updating_an_expired_statement_should_fail() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement = [88u8; 32];
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&new_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+
+		// An expiry at or before the current block is rejected
+		assert_err!(
+			Statement::set_expiry(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				Some(1),
+				authorization_id.clone(),
+			),
+			Error::<Test>::InvalidExpiry
+		);
+
+		assert_ok!(Statement::set_expiry(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			Some(10),
+			authorization_id.clone(),
+		));
+
+		System::set_block_number(10);
+		assert!(!Statement::is_expired(&statement_id));
+
+		System::set_block_number(11);
+		assert!(Statement::is_expired(&statement_id));
+
+		assert_err!(
+			Statement::update(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				new_statement_digest,
+				0,
+				authorization_id.clone(),
+			),
+			Error::<Test>::StatementExpired
+		);
+
+		// Revocation is still possible once expired
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author, creator).into(),
+			statement_id,
+			authorization_id,
+		));
+	});
+}
+
+#[test]
+fn resolving_a_statement_by_digest_should_return_latest_state() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement = [88u8; 32];
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&new_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert!(Statement::statement_info_by_digest(&statement_digest, &space_id).is_none());
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+
+		assert_ok!(Statement::update(
+			DoubleOrigin(author, creator).into(),
+			statement_id.clone(),
+			new_statement_digest,
+			0,
+			authorization_id,
+		));
+
+		// The original digest still resolves, to the latest state of the statement
+		let info = Statement::statement_info_by_digest(&statement_digest, &space_id)
+			.expect("Statement should resolve from its original digest.");
+		assert_eq!(info.identifier, statement_id);
+		assert_eq!(info.details.digest, new_statement_digest);
+		assert!(!info.revoked);
+		assert_eq!(info.expires_at, None);
+		assert_eq!(info.timeline.len(), 2);
+	});
+}
+
+#[test]
+fn registering_a_statement_against_a_deprecated_schema_should_fail() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement = [88u8; 32];
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&new_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_schema = [11u8; 256].to_vec();
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			Some(schema_id.clone()),
+			ContentTypeOf::Generic
+		));
+
+		assert_ok!(Schema::deprecate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_id.clone(),
+			authorization_id.clone()
+		));
+
+		assert_err!(
+			Statement::register(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				new_statement_digest,
+				authorization_id.clone(),
+				Some(schema_id),
+				ContentTypeOf::Generic
+			),
+			Error::<Test>::SchemaDeprecated
+		);
+
+		// Statements anchored before the deprecation remain usable
+		assert_ok!(Statement::update(
+			DoubleOrigin(author, creator).into(),
+			statement_id,
+			new_statement_digest,
+			0,
+			authorization_id,
+		));
+	});
+}
+
+#[test]
+fn a_revoke_delegate_should_revoke_but_not_register_statements() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest: StatementDigestOf<Test> =
+		<Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement = [88u8; 32];
+	let new_statement_digest: StatementDigestOf<Test> =
+		<Test as frame_system::Config>::Hashing::hash(&new_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let delegate_auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id: Ss58Identifier =
+		generate_authorization_id::<Test>(&delegate_auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Space::add_revoke_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id,
+			delegate.clone(),
+			authorization_id.clone(),
+		));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+
+		assert_err!(
+			Statement::register(
+				DoubleOrigin(author.clone(), delegate.clone()).into(),
+				new_statement_digest,
+				delegate_authorization_id.clone(),
+				None,
+				ContentTypeOf::Generic
+			),
+			pallet_chain_space::Error::<Test>::UnauthorizedOperation
+		);
+
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author.clone(), delegate.clone()).into(),
+			statement_id.clone(),
+			delegate_authorization_id.clone(),
+		));
+
+		assert_ok!(Statement::restore(
+			DoubleOrigin(author, delegate).into(),
+			statement_id,
+			delegate_authorization_id,
+		));
+	});
+}
+
+#[test]
+fn witnessed_statement_should_anchor_after_required_approvals() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let witness_a = DID_01;
+	let witness_b = SubjectId(AccountId32::new([6u8; 32]));
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement = [88u8; 32];
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&new_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	let witnesses: WitnessListOf<Test> =
+		BoundedVec::try_from(vec![witness_a.clone(), witness_b.clone()]).unwrap();
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		// The threshold must be reachable by the listed witnesses
+		assert_err!(
+			Statement::register_with_witnesses(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_digest,
+				authorization_id.clone(),
+				None,
+				ContentTypeOf::Generic,
+				witnesses.clone(),
+				3
+			),
+			Error::<Test>::InvalidWitnessThreshold
+		);
+
+		assert_ok!(Statement::register_with_witnesses(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic,
+			witnesses,
+			2
+		));
+		assert!(Statement::statement_info(&statement_id).unwrap().pending);
+
+		assert_err!(
+			Statement::update(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				new_statement_digest,
+				0,
+				authorization_id.clone(),
+			),
+			Error::<Test>::StatementPendingWitnesses
+		);
+
+		assert_err!(
+			Statement::witness_approve(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+			),
+			Error::<Test>::NotAWitness
+		);
+
+		assert_ok!(Statement::witness_approve(
+			DoubleOrigin(author.clone(), witness_a.clone()).into(),
+			statement_id.clone(),
+		));
+		assert_err!(
+			Statement::witness_approve(
+				DoubleOrigin(author.clone(), witness_a).into(),
+				statement_id.clone(),
+			),
+			Error::<Test>::WitnessAlreadyApproved
+		);
+		assert!(Statement::statement_info(&statement_id).unwrap().pending);
+		assert_ok!(crate::try_state::do_try_state::<Test>());
+
+		assert_ok!(Statement::witness_approve(
+			DoubleOrigin(author.clone(), witness_b.clone()).into(),
+			statement_id.clone(),
+		));
+		assert!(!Statement::statement_info(&statement_id).unwrap().pending);
+
+		// Once anchored, no further approvals are accepted and updates work
+		assert_err!(
+			Statement::witness_approve(
+				DoubleOrigin(author.clone(), witness_b).into(),
+				statement_id.clone(),
+			),
+			Error::<Test>::StatementNotPending
+		);
+		assert_ok!(Statement::update(
+			DoubleOrigin(author, creator).into(),
+			statement_id,
+			new_statement_digest,
+			0,
+			authorization_id,
+		));
+	});
+}
+
+#[test]
+fn status_list_indices_should_be_revocable_individually() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+
+		assert_err!(
+			Statement::set_status_at(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				0,
+				true,
+				authorization_id.clone(),
+			),
+			Error::<Test>::StatusListNotFound
+		);
+
+		assert_err!(
+			Statement::create_status_list(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				MaxStatusListLength::get() + 1,
+				authorization_id.clone(),
+			),
+			Error::<Test>::InvalidStatusListLength
+		);
+
+		assert_ok!(Statement::create_status_list(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			300,
+			authorization_id.clone(),
+		));
+		assert_eq!(Statement::status_at(&statement_id, 299), Some(false));
+		assert_eq!(Statement::status_at(&statement_id, 300), None);
+
+		assert_ok!(Statement::set_status_at(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			257,
+			true,
+			authorization_id.clone(),
+		));
+		assert_eq!(Statement::status_at(&statement_id, 257), Some(true));
+		assert_eq!(Statement::status_at(&statement_id, 256), Some(false));
+		assert_eq!(Statement::status_at(&statement_id, 1), Some(false));
+		assert_ok!(crate::try_state::do_try_state::<Test>());
+
+		assert_err!(
+			Statement::set_status_at(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				300,
+				true,
+				authorization_id.clone(),
+			),
+			Error::<Test>::StatusIndexOutOfBounds
+		);
+
+		// Clearing the only set bit drops the chunk from storage
+		assert_ok!(Statement::set_status_at(
+			DoubleOrigin(author, creator).into(),
+			statement_id.clone(),
+			257,
+			false,
+			authorization_id,
+		));
+		assert_eq!(Statement::status_at(&statement_id, 257), Some(false));
+		assert!(!StatusListChunks::<Test>::contains_key(&statement_id, 1));
+	});
+}
+
+#[test]
+fn verifying_a_disclosed_leaf_against_an_anchored_root_should_succeed() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let leaves: Vec<StatementDigestOf<Test>> = [[1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]]
+		.iter()
+		.map(|attribute| <Test as frame_system::Config>::Hashing::hash(&attribute[..]))
+		.collect();
+	let left = merkle::hash_pair::<<Test as frame_system::Config>::Hashing>(&leaves[0], &leaves[1]);
+	let right =
+		merkle::hash_pair::<<Test as frame_system::Config>::Hashing>(&leaves[2], &leaves[3]);
+	let root = merkle::hash_pair::<<Test as frame_system::Config>::Hashing>(&left, &right);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&root.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author, creator).into(),
+			root,
+			authorization_id,
+			None,
+			ContentTypeOf::Generic
+		));
+
+		assert!(Statement::verify_leaf(&statement_id, leaves[2], &[leaves[3], left]));
+		assert!(!Statement::verify_leaf(&statement_id, leaves[2], &[leaves[0], left]));
+		assert!(!Statement::verify_leaf(&statement_id, leaves[2], &[leaves[3]]));
+	});
+}
+
+#[test]
+fn attaching_a_remark_to_a_space_should_emit_its_hash() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let remark: RemarkOf<Test> =
+		BoundedVec::try_from(b"audit: batch 42 reviewed".to_vec()).unwrap();
+	let content_hash = <Test as frame_system::Config>::Hashing::hash(&remark[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_err!(
+			Statement::remark(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				BoundedVec::default(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::EmptyRemark
+		);
+
+		assert_ok!(Statement::remark(
+			DoubleOrigin(author, creator.clone()).into(),
+			remark,
+			authorization_id,
+		));
+
+		System::assert_last_event(
+			Event::Remarked { space: space_id, content_hash, author: creator }.into(),
+		);
+		assert_eq!(System::events().last().unwrap().topics, vec![content_hash]);
+	});
+}
+
+#[test]
+fn genesis_statements_should_be_anchored_in_genesis_spaces() {
+	let creator = DID_00;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[77u8; 32][..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	let mut t: sp_runtime::Storage =
+		frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_chain_space::GenesisConfig::<Test> { spaces: vec![(space_digest, creator.clone(), 5)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	crate::GenesisConfig::<Test> {
+		statements: vec![(space_digest, creator.clone(), statement_digest)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		let details = Statements::<Test>::get(&statement_id).expect("statement should exist");
+		assert_eq!(details.space, space_id);
+		assert_eq!(details.schema, None);
+		assert_eq!(Entries::<Test>::get(&statement_id, statement_digest), Some(creator));
+		assert_eq!(
+			IdentifierLookup::<Test>::get(statement_digest, &space_id),
+			Some(DigestCommitOf { identifier: statement_id.clone(), index: 0 })
+		);
+	});
+}
+
+#[test]
+fn scheduled_revocation_should_revoke_statement_at_block() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let other_statement = [78u8; 32];
+	let other_statement_digest =
+		<Test as frame_system::Config>::Hashing::hash(&other_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	let other_statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&other_statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]]
+			.concat()[..],
+	);
+	let other_statement_id: StatementIdOf =
+		generate_statement_id::<Test>(&other_statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			other_statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+
+		assert_err!(
+			Statement::schedule_revoke(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				1,
+				authorization_id.clone(),
+			),
+			Error::<Test>::InvalidRevocationBlock
+		);
+
+		assert_err!(
+			Statement::cancel_scheduled_revoke(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::RevocationNotScheduled
+		);
+
+		assert_ok!(Statement::schedule_revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			5,
+			authorization_id.clone(),
+		));
+		assert_ok!(Statement::schedule_revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			other_statement_id.clone(),
+			5,
+			authorization_id.clone(),
+		));
+		assert_eq!(RevocationSchedule::<Test>::get(&statement_id), Some(5));
+
+		assert_err!(
+			Statement::schedule_revoke(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				6,
+				authorization_id.clone(),
+			),
+			Error::<Test>::RevocationAlreadyScheduled
+		);
+
+		// Cancelled revocations are dropped from the block schedule
+		assert_ok!(Statement::cancel_scheduled_revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			other_statement_id.clone(),
+			authorization_id.clone(),
+		));
+		assert_eq!(ScheduledRevocations::<Test>::get(5).len(), 1);
+
+		Statement::on_initialize(4);
+		assert!(!RevocationList::<Test>::contains_key(&statement_id, statement_digest));
+
+		System::set_block_number(5);
+		Statement::on_initialize(5);
+		assert!(RevocationList::<Test>::contains_key(&statement_id, statement_digest));
+		assert!(!RevocationList::<Test>::contains_key(&other_statement_id, other_statement_digest));
+		assert!(!RevocationSchedule::<Test>::contains_key(&statement_id));
+		assert!(ScheduledRevocations::<Test>::get(5).is_empty());
+		System::assert_last_event(
+			Event::Revoke { identifier: statement_id.clone(), author: creator.clone() }.into(),
+		);
+
+		assert_err!(
+			Statement::schedule_revoke(
+				DoubleOrigin(author, creator).into(),
+				statement_id,
+				10,
+				authorization_id,
+			),
+			Error::<Test>::StatementRevoked
+		);
+	});
+}
+
+#[test]
+fn scheduling_revocations_beyond_the_block_limit_should_fail() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		for i in 0..=MaxScheduledRevocationsPerBlock::get() {
+			let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[i as u8; 32]);
+			let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+				&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]]
+					.concat()[..],
+			);
+			let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+			assert_ok!(Statement::register(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_digest,
+				authorization_id.clone(),
+				None,
+				ContentTypeOf::Generic
+			));
+
+			let result = Statement::schedule_revoke(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id,
+				5,
+				authorization_id.clone(),
+			);
+			if i < MaxScheduledRevocationsPerBlock::get() {
+				assert_ok!(result);
+			} else {
+				assert_err!(result, Error::<Test>::MaxScheduledRevocationsExceeded);
+			}
+		}
+	});
+}
+
+#[test]
+fn scheduled_revocations_should_be_skipped_once_the_authority_is_lost() {
+	let creator = DID_00;
+	let delegate = DID_01;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[91u8; 32]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let delegate_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id = generate_authorization_id::<Test>(&delegate_id_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate.clone(),
+			authorization_id.clone(),
+			None,
+		));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+
+		// The delegate schedules a revocation and is then removed
+		assert_ok!(Statement::schedule_revoke(
+			DoubleOrigin(author.clone(), delegate.clone()).into(),
+			statement_id.clone(),
+			5,
+			delegate_authorization_id.clone(),
+		));
+		assert_ok!(Space::remove_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate_authorization_id,
+			authorization_id,
+		));
+
+		System::set_block_number(5);
+		Statement::on_initialize(5);
+		assert!(!RevocationList::<Test>::contains_key(&statement_id, statement_digest));
+		assert!(!RevocationSchedule::<Test>::contains_key(&statement_id));
+		System::assert_last_event(
+			Event::ScheduledRevocationSkipped { identifier: statement_id, author: delegate }.into(),
+		);
+	});
+}
+
+#[test]
+fn scheduled_revocations_should_run_in_a_frozen_space() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[92u8; 32]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+
+		// The space is frozen before the revocation is due
+		assert_ok!(Statement::schedule_revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			5,
+			authorization_id.clone(),
+		));
+		assert_ok!(
+			Space::freeze(DoubleOrigin(author, creator).into(), space_id, authorization_id,)
+		);
+
+		System::set_block_number(5);
+		Statement::on_initialize(5);
+		assert!(RevocationList::<Test>::contains_key(&statement_id, statement_digest));
+		assert!(!RevocationSchedule::<Test>::contains_key(&statement_id));
+	});
+}
+
+#[test]
+fn anchor_state_should_follow_the_statement_lifecycle() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement = [88u8; 32];
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&new_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+		assert_eq!(
+			AnchorState::<Test>::get(statement_digest, &space_id),
+			Some(AnchorStateOf::<Test> {
+				identifier: statement_id.clone(),
+				schema: None,
+				revoked: false,
+				block: 1,
+			})
+		);
+
+		System::set_block_number(2);
+		assert_ok!(Statement::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			new_statement_digest,
+			0,
+			authorization_id.clone(),
+		));
+		let previous = AnchorState::<Test>::get(statement_digest, &space_id)
+			.expect("Previous digest should stay anchored.");
+		assert!(previous.revoked);
+		let latest = AnchorState::<Test>::get(new_statement_digest, &space_id)
+			.expect("New digest should be anchored.");
+		assert!(!latest.revoked);
+		assert_eq!(latest.block, 2);
+		assert_eq!(
+			Statement::anchors_of(&new_statement_digest),
+			vec![(space_id.clone(), latest.clone())]
+		);
+		assert!(Statement::anchors_of(&<Test as frame_system::Config>::Hashing::hash(b"unknown"))
+			.is_empty());
+
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+		));
+		assert!(AnchorState::<Test>::get(new_statement_digest, &space_id).unwrap().revoked);
+
+		assert_ok!(Statement::restore(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+		));
+		assert!(!AnchorState::<Test>::get(new_statement_digest, &space_id).unwrap().revoked);
+		assert_ok!(crate::try_state::do_try_state::<Test>());
+
+		assert_ok!(Statement::remove(
+			DoubleOrigin(author, creator).into(),
+			statement_id,
+			authorization_id,
+		));
+		assert!(!AnchorState::<Test>::contains_key(statement_digest, &space_id));
+		assert!(!AnchorState::<Test>::contains_key(new_statement_digest, &space_id));
+	});
+}
+
+#[test]
+fn migration_to_v2_should_populate_anchor_state() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author, creator).into(),
+			statement_id.clone(),
+			authorization_id,
+		));
+
+		// Simulate a chain anchored before `AnchorState` was introduced
+		let _ = AnchorState::<Test>::clear(u32::MAX, None);
+		crate::migrations::v4::Statements::<Test>::insert(
+			&statement_id,
+			crate::migrations::v4::OldStatementDetails {
+				digest: statement_digest,
+				space: space_id.clone(),
+				schema: None,
+			},
+		);
+		StorageVersion::new(1).put::<Statement>();
+
+		System::set_block_number(7);
+		crate::migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Statement>(), 2);
+		assert_eq!(
+			AnchorState::<Test>::get(statement_digest, &space_id),
+			Some(AnchorStateOf::<Test> {
+				identifier: statement_id,
+				schema: None,
+				revoked: true,
+				block: 7,
+			})
+		);
+	});
+}
+
+#[test]
+fn digest_lookups_should_keep_commit_history() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[77u8; 32][..]);
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&[88u8; 32][..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+		assert_ok!(Statement::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			new_statement_digest,
+			0,
+			authorization_id.clone(),
+		));
+
+		// The superseded digest still resolves to its own commit.
+		assert_eq!(CommitCount::<Test>::get(&statement_id), 2);
+		assert_eq!(
+			IdentifierLookup::<Test>::get(statement_digest, &space_id),
+			Some(DigestCommitOf { identifier: statement_id.clone(), index: 0 })
+		);
+		assert_eq!(
+			IdentifierLookup::<Test>::get(new_statement_digest, &space_id),
+			Some(DigestCommitOf { identifier: statement_id.clone(), index: 1 })
+		);
+
+		// A digest cannot be committed to the same space twice.
+		assert_err!(
+			Statement::update(
+				DoubleOrigin(author, creator).into(),
+				statement_id.clone(),
+				statement_digest,
+				1,
+				authorization_id,
+			),
+			Error::<Test>::StatementDigestAlreadyAnchored
+		);
+
+		// Simulate a chain where lookups held the bare identifier.
+		for digest in [statement_digest, new_statement_digest] {
+			frame_support::storage::unhashed::put(
+				&IdentifierLookup::<Test>::hashed_key_for(digest, &space_id),
+				&statement_id,
+			);
+		}
+		CommitCount::<Test>::remove(&statement_id);
+		crate::migrations::v4::Statements::<Test>::insert(
+			&statement_id,
+			crate::migrations::v4::OldStatementDetails {
+				digest: new_statement_digest,
+				space: space_id.clone(),
+				schema: None,
+			},
+		);
+		StorageVersion::new(2).put::<Statement>();
+
+		crate::migrations::v3::MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Statement>(), 3);
+		assert_eq!(CommitCount::<Test>::get(&statement_id), 2);
+		assert_eq!(
+			IdentifierLookup::<Test>::get(statement_digest, &space_id),
+			Some(DigestCommitOf { identifier: statement_id.clone(), index: 0 })
+		);
+		assert_eq!(
+			IdentifierLookup::<Test>::get(new_statement_digest, &space_id),
+			Some(DigestCommitOf { identifier: statement_id, index: 1 })
+		);
+	});
+}
+
+#[test]
+fn migrations_should_upgrade_storage_from_v1() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[77u8; 32][..]);
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&[88u8; 32][..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+		assert_ok!(Statement::update(
+			DoubleOrigin(author, creator).into(),
+			statement_id.clone(),
+			new_statement_digest,
+			0,
+			authorization_id,
+		));
+
+		// Simulate a chain at storage version 1: no `AnchorState` or
+		// `CommitCount`, bare identifiers in `IdentifierLookup` and
+		// statements without a content type.
+		let _ = AnchorState::<Test>::clear(u32::MAX, None);
+		CommitCount::<Test>::remove(&statement_id);
+		for digest in [statement_digest, new_statement_digest] {
+			frame_support::storage::unhashed::put(
+				&IdentifierLookup::<Test>::hashed_key_for(digest, &space_id),
+				&statement_id,
+			);
+		}
+		crate::migrations::v4::Statements::<Test>::insert(
+			&statement_id,
+			crate::migrations::v4::OldStatementDetails {
+				digest: new_statement_digest,
+				space: space_id.clone(),
+				schema: None,
+			},
+		);
+		StorageVersion::new(1).put::<Statement>();
+
+		System::set_block_number(7);
+		<(
+			crate::migrations::v2::MigrateV1ToV2<Test>,
+			crate::migrations::v3::MigrateV2ToV3<Test>,
+			crate::migrations::v4::MigrateV3ToV4<Test>,
+		) as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Statement>(), 4);
+		assert_eq!(
+			AnchorState::<Test>::get(new_statement_digest, &space_id),
+			Some(AnchorStateOf::<Test> {
+				identifier: statement_id.clone(),
+				schema: None,
+				revoked: false,
+				block: 7,
+			})
+		);
+		assert_eq!(CommitCount::<Test>::get(&statement_id), 2);
+		assert_eq!(
+			IdentifierLookup::<Test>::get(statement_digest, &space_id),
+			Some(DigestCommitOf { identifier: statement_id.clone(), index: 0 })
+		);
+		assert_eq!(
+			IdentifierLookup::<Test>::get(new_statement_digest, &space_id),
+			Some(DigestCommitOf { identifier: statement_id.clone(), index: 1 })
+		);
+		assert_eq!(
+			Statements::<Test>::get(&statement_id),
+			Some(StatementDetailsOf::<Test> {
+				digest: new_statement_digest,
+				space: space_id,
+				schema: None,
+				content_type: ContentTypeOf::Generic,
+			})
+		);
+	});
+}
+
+#[test]
+fn unique_digest_policy_should_reject_anchored_digests() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[77u8; 32][..]);
+	let other_digest = <Test as frame_system::Config>::Hashing::hash(&[88u8; 32][..]);
+	let pending_digest = <Test as frame_system::Config>::Hashing::hash(&[99u8; 32][..]);
+	let witnesses: WitnessListOf<Test> = BoundedVec::try_from(vec![DID_01]).unwrap();
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_schema = [11u8; 256].to_vec();
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&other_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema,
+			authorization_id.clone()
+		));
+		assert_ok!(Schema::set_digest_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_id.clone(),
+			pallet_schema::DigestPolicy::Unique,
+			authorization_id.clone()
+		));
+
+		// The digest is anchored without a schema first.
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+		assert_err!(
+			Statement::register(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_digest,
+				authorization_id.clone(),
+				Some(schema_id.clone()),
+				ContentTypeOf::Generic
+			),
+			Error::<Test>::DigestNotUnique
+		);
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			other_digest,
+			authorization_id.clone(),
+			Some(schema_id.clone()),
+			ContentTypeOf::Generic
+		));
+		assert_err!(
+			Statement::update(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id,
+				statement_digest,
+				0,
+				authorization_id.clone(),
+			),
+			Error::<Test>::DigestNotUnique
+		);
+
+		// Digests of statements awaiting witness approvals are reserved too.
+		assert_ok!(Statement::register_with_witnesses(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			pending_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic,
+			witnesses,
+			1
+		));
+		assert_err!(
+			Statement::register(
+				DoubleOrigin(author, creator).into(),
+				pending_digest,
+				authorization_id,
+				Some(schema_id),
+				ContentTypeOf::Generic
+			),
+			Error::<Test>::DigestNotUnique
+		);
+	});
+}
+
+#[test]
+fn statement_metadata_should_hold_deposits() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[77u8; 32][..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	let jurisdiction: MetadataKeyOf<Test> = b"jurisdiction".to_vec().try_into().unwrap();
+	let category: MetadataKeyOf<Test> = b"category".to_vec().try_into().unwrap();
+	let tier: MetadataKeyOf<Test> = b"tier".to_vec().try_into().unwrap();
+	let value = |raw: &[u8]| -> MetadataValueOf<Test> { raw.to_vec().try_into().unwrap() };
+
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&author, 1_000);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+
+		assert_ok!(Statement::set_metadata(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			jurisdiction.clone(),
+			value(b"IN"),
+			authorization_id.clone(),
+		));
+		// Base deposit of 10 plus one per byte of key and value.
+		assert_eq!(Balances::reserved_balance(&author), 24);
+		assert_eq!(
+			Metadata::<Test>::get(&statement_id, &jurisdiction).map(|entry| entry.value),
+			Some(value(b"IN"))
+		);
+
+		// Replacing a value adjusts the deposit to the new size.
+		assert_ok!(Statement::set_metadata(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			jurisdiction.clone(),
+			value(b"IN-KA"),
+			authorization_id.clone(),
+		));
+		assert_eq!(Balances::reserved_balance(&author), 27);
+		assert_eq!(MetadataCount::<Test>::get(&statement_id), 1);
+
+		assert_ok!(Statement::set_metadata(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			category.clone(),
+			value(b"degree"),
+			authorization_id.clone(),
+		));
+		assert_err!(
+			Statement::set_metadata(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				tier.clone(),
+				value(b"gold"),
+				authorization_id.clone(),
+			),
+			Error::<Test>::MetadataLimitExceeded
+		);
+
+		assert_ok!(Statement::clear_metadata(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			category.clone(),
+			authorization_id.clone(),
+		));
+		assert_eq!(Balances::reserved_balance(&author), 27);
+		assert_eq!(MetadataCount::<Test>::get(&statement_id), 1);
+		assert_err!(
+			Statement::clear_metadata(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				category,
+				authorization_id.clone(),
+			),
+			Error::<Test>::MetadataNotFound
+		);
+
+		// Removing the statement returns the remaining deposits.
+		assert_ok!(Statement::remove(
+			DoubleOrigin(author.clone(), creator).into(),
+			statement_id.clone(),
+			authorization_id,
+		));
+		assert_eq!(Balances::reserved_balance(&author), 0);
+		assert!(!Metadata::<Test>::contains_key(&statement_id, &jurisdiction));
+		assert!(!MetadataCount::<Test>::contains_key(&statement_id));
+	});
+}
+
+#[test]
+fn frozen_spaces_and_schemas_should_block_anchoring_but_not_revocation() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&statement[..]);
+	let new_statement = [88u8; 32];
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&new_statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_schema = [11u8; 256].to_vec();
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			Some(schema_id.clone()),
+			ContentTypeOf::Generic
+		));
+
+		assert_ok!(Schema::force_freeze(RawOrigin::Root.into(), schema_id.clone()));
+
+		assert_err!(
+			Statement::update(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				new_statement_digest,
+				0,
+				authorization_id.clone(),
+			),
+			Error::<Test>::SchemaFrozen
+		);
+
+		assert_ok!(Schema::force_unfreeze(RawOrigin::Root.into(), schema_id));
+		assert_ok!(Space::force_freeze(RawOrigin::Root.into(), space_id));
+
+		assert_err!(
+			Statement::update(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				new_statement_digest,
+				0,
+				authorization_id.clone(),
+			),
+			pallet_chain_space::Error::<Test>::Frozen
+		);
+
+		// Existing statements can still be revoked while the space is frozen
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author, creator).into(),
+			statement_id.clone(),
+			authorization_id,
+		));
+		assert!(
+			RevocationList::<Test>::get(statement_id, statement_digest)
+				.expect("Statement digest should be present on the revoked list.")
+				.revoked
+		);
+	});
+}
+
+#[test]
+fn registering_a_statement_should_hold_a_deposit_until_removal() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let unfunded = AccountId::new([9u8; 32]);
+	let capacity = 5u64;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[77u8; 32][..]);
+	let other_digest = <Test as frame_system::Config>::Hashing::hash(&[88u8; 32][..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		StatementDepositBase::set(10);
+		StatementDepositPerByte::set(1);
+		Balances::make_free_balance_be(&author, 1_000);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+
+		let details = StatementDetailsOf::<Test> {
+			digest: statement_digest,
+			space: space_id,
+			schema: None,
+			content_type: ContentTypeOf::Generic,
+		};
+		let deposit = 10 + details.encoded_size() as u64;
+		assert_eq!(Balances::reserved_balance(&author), deposit);
+		assert_eq!(
+			Deposits::<Test>::get(&statement_id),
+			Some(StatementDepositOf::<Test> { depositor: author.clone(), amount: deposit })
+		);
+
+		assert_err!(
+			Statement::register(
+				DoubleOrigin(unfunded, creator.clone()).into(),
+				other_digest,
+				authorization_id.clone(),
+				None,
+				ContentTypeOf::Generic
+			),
+			Error::<Test>::InsufficientDeposit
+		);
+
+		assert_ok!(Statement::remove(
+			DoubleOrigin(author.clone(), creator).into(),
+			statement_id.clone(),
+			authorization_id,
+		));
+		assert_eq!(Balances::reserved_balance(&author), 0);
+		assert!(!Deposits::<Test>::contains_key(&statement_id));
+	});
+}
+
+#[test]
+fn only_a_space_admin_should_remove_a_statement() {
+	let creator = DID_00;
+	let delegate = DID_01;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[77u8; 32][..]);
+	let delegate_digest = <Test as frame_system::Config>::Hashing::hash(&[88u8; 32][..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let delegate_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id = generate_authorization_id::<Test>(&delegate_id_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	let delegate_statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&delegate_digest.encode()[..], &space_id.encode()[..], &delegate.encode()[..]].concat()[..],
+	);
+	let delegate_statement_id: StatementIdOf =
+		generate_statement_id::<Test>(&delegate_statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id,
+			delegate.clone(),
+			authorization_id.clone(),
+			None,
+		));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), delegate.clone()).into(),
+			delegate_digest,
+			delegate_authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+
+		// Statements belong to the space, not to the delegate that anchored
+		// them, so a delegate cannot remove even its own statements
+		assert_err!(
+			Statement::remove(
+				DoubleOrigin(author.clone(), delegate.clone()).into(),
+				delegate_statement_id.clone(),
+				delegate_authorization_id,
+			),
+			pallet_chain_space::Error::<Test>::UnauthorizedOperation
+		);
+
+		// An admin can remove any statement of the space
+		assert_ok!(Statement::remove(
+			DoubleOrigin(author, creator).into(),
+			delegate_statement_id.clone(),
+			authorization_id,
+		));
+		assert!(!Statements::<Test>::contains_key(&delegate_statement_id));
+		assert!(Statements::<Test>::contains_key(&statement_id));
+	});
+}
+
+#[test]
+fn removing_a_statement_should_clear_its_attachments_and_release_deposits() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[77u8; 32][..]);
+	let presentation_digests: Vec<StatementDigestOf<Test>> = (1u8..=5)
+		.map(|i| <Test as frame_system::Config>::Hashing::hash(&[i; 32][..]))
+		.collect();
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	let jurisdiction: MetadataKeyOf<Test> = b"jurisdiction".to_vec().try_into().unwrap();
+
+	new_test_ext().execute_with(|| {
+		StatementDepositBase::set(10);
+		StatementDepositPerByte::set(1);
+		Balances::make_free_balance_be(&author, 1_000);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::Generic
+		));
+		for presentation_digest in &presentation_digests {
+			assert_ok!(Statement::add_presentation(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				*presentation_digest,
+				PresentationTypeOf::Other,
+				authorization_id.clone(),
+			));
+		}
+		assert_ok!(Statement::set_metadata(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			jurisdiction,
+			b"IN".to_vec().try_into().unwrap(),
+			authorization_id.clone(),
+		));
+		assert_ok!(Statement::schedule_revoke(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			5,
+			authorization_id.clone(),
+		));
+		assert!(Balances::reserved_balance(&author) > 0);
+
+		// One entry and five presentations exceed `MaxRemoveEntries`, so the
+		// first call only removes the presentations, but already releases
+		// the deposits and drops the scheduled revocation
+		assert_ok!(Statement::remove(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+		));
+		System::assert_last_event(
+			Event::PartialRemoval {
+				identifier: statement_id.clone(),
+				removed: 5,
+				author: creator.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(&author), 0);
+		assert!(!Deposits::<Test>::contains_key(&statement_id));
+		assert_eq!(Metadata::<Test>::iter_prefix(&statement_id).count(), 0);
+		assert_eq!(Presentations::<Test>::iter_prefix(&statement_id).count(), 0);
+		assert!(presentation_digests
+			.iter()
+			.all(|digest| !IdentifierLookup::<Test>::contains_key(digest, &space_id)));
+		assert!(!RevocationSchedule::<Test>::contains_key(&statement_id));
+		assert!(ScheduledRevocations::<Test>::get(5).is_empty());
+		assert!(Statements::<Test>::contains_key(&statement_id));
+
+		assert_ok!(Statement::remove(
+			DoubleOrigin(author, creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id,
+		));
+		System::assert_last_event(
+			Event::Remove { identifier: statement_id.clone(), author: creator }.into(),
+		);
+		assert!(!Statements::<Test>::contains_key(&statement_id));
+		assert!(!IdentifierLookup::<Test>::contains_key(statement_digest, &space_id));
+	});
+}
+
+#[test]
+fn anchored_digests_should_be_committed_in_attestation_roots() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let digests: Vec<StatementDigestOf<Test>> = (1u8..=3)
+		.map(|i| <Test as frame_system::Config>::Hashing::hash(&[i; 32][..]))
+		.collect();
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let leaves: Vec<AttestationHashOf> = digests
+		.iter()
+		.map(|digest| <Keccak256 as Hash>::hash(&digest.encode()))
+		.collect();
+
+	new_test_ext().execute_with(|| {
+		AttestationPeriod::set(2);
+		System::set_block_number(1);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		for digest in digests.iter() {
+			assert_ok!(Statement::register(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				*digest,
+				authorization_id.clone(),
+				None,
+				ContentTypeOf::Generic
+			));
+		}
+		assert_eq!(AttestationLeafCount::<Test>::get(), 3);
+
+		// Commitments are only made every `AttestationPeriod` blocks
+		Statement::on_initialize(3);
+		assert!(AttestationCommitments::<Test>::get(0).is_none());
+
+		// The first commitment is capped at `MaxAttestationLeaves`
+		Statement::on_initialize(4);
+		let first = AttestationCommitments::<Test>::get(0).unwrap();
+		assert_eq!((first.first_leaf, first.leaf_count), (0, 2));
+		assert_eq!(first.root, merkle::merkle_root::<Keccak256>(&leaves[..2]));
+		let proof = merkle::merkle_proof::<Keccak256>(&leaves[..2], 1).unwrap();
+		assert!(merkle::verify_proof::<Keccak256>(&first.root, leaves[1], &proof));
+		assert!(AttestationLeaves::<Test>::get(0).is_none());
+
+		Statement::on_initialize(6);
+		let second = AttestationCommitments::<Test>::get(1).unwrap();
+		assert_eq!((second.first_leaf, second.leaf_count), (2, 1));
+		assert_eq!(second.root, leaves[2]);
+		assert_eq!(CommittedLeafCount::<Test>::get(), 3);
+		assert_eq!(AttestationLeaves::<Test>::iter().count(), 0);
+
+		// Nothing is committed when no digests were anchored
+		Statement::on_initialize(8);
+		assert_eq!(NextAttestationCommitment::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn statement_activity_should_be_counted_in_totals_schemas_and_periods() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[77u8; 32][..]);
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&[88u8; 32][..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_schema = [11u8; 256].to_vec();
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		StatsPeriod::set(2);
+		System::set_block_number(1);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			Some(schema_id.clone()),
+			ContentTypeOf::Generic
+		));
+
+		System::set_block_number(3);
+		assert_ok!(Statement::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			new_statement_digest,
+			Statement::revision(&statement_id),
+			authorization_id.clone(),
+		));
+		// Expiries and status lists are not counted as updates
+		assert_ok!(Statement::set_expiry(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			Some(100),
+			authorization_id.clone(),
+		));
+		assert_ok!(Statement::create_status_list(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			8,
+			authorization_id.clone(),
+		));
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author, creator).into(),
+			statement_id,
+			authorization_id,
+		));
+
+		let all = AnchoringStats { created: 1, updated: 1, revoked: 1 };
+		assert_eq!(Statement::anchoring_stats(None), all);
+		assert_eq!(Statement::anchoring_stats(Some(&schema_id)), all);
+		assert_eq!(
+			Statement::period_stats(1),
+			Some(AnchoringStats { created: 1, ..Default::default() })
+		);
+		assert_eq!(
+			Statement::period_stats(2),
+			Some(AnchoringStats { updated: 1, revoked: 1, ..Default::default() })
+		);
+
+		// Only the last `StatsHistoryDepth` periods are kept
+		Statement::on_initialize(4);
+		assert_eq!(Statement::period_stats(1), None);
+		assert!(Statement::period_stats(3).is_some());
+		assert_eq!(Statement::anchoring_stats(None), all);
+	});
+}
+
+#[test]
+fn declared_content_type_should_be_stored_and_checked_by_the_anchoring_hook() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[77u8; 32][..]);
+	let new_statement_digest = <Test as frame_system::Config>::Hashing::hash(&[78u8; 32][..]);
+	let other_digest = <Test as frame_system::Config>::Hashing::hash(&[88u8; 32][..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None,
+			ContentTypeOf::JsonLd
+		));
+		assert_eq!(
+			Statements::<Test>::get(&statement_id).map(|details| details.content_type),
+			Some(ContentTypeOf::JsonLd)
+		);
+
+		// The hook is consulted for new digests with the declared type.
+		RejectedContentType::set(Some(ContentTypeOf::JsonLd));
+		assert_err!(
+			Statement::update(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				new_statement_digest,
+				0,
+				authorization_id.clone(),
+			),
+			Error::<Test>::ContentRejected
+		);
+		assert_err!(
+			Statement::register(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				other_digest,
+				authorization_id.clone(),
+				None,
+				ContentTypeOf::JsonLd
+			),
+			Error::<Test>::ContentRejected
+		);
+		assert_err!(
+			Statement::register_batch(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				vec![other_digest],
+				authorization_id.clone(),
+				None,
+				ContentTypeOf::JsonLd
+			),
+			Error::<Test>::BulkTransactionFailed
+		);
+
+		RejectedContentType::set(None);
+		assert_ok!(Statement::update(
+			DoubleOrigin(author, creator).into(),
+			statement_id.clone(),
+			new_statement_digest,
+			0,
+			authorization_id,
+		));
+		assert_eq!(
+			Statements::<Test>::get(&statement_id).map(|details| details.content_type),
+			Some(ContentTypeOf::JsonLd)
+		);
+	});
+}
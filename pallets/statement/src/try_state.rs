@@ -0,0 +1,175 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use cord_utilities::test_utils::log_and_return_error_message;
+use frame_support::ensure;
+use scale_info::prelude::format;
+use sp_runtime::TryRuntimeError;
+
+use crate::{
+	AnchorState, AttestationLeafCount, AttestationLeaves, CommittedLeafCount, Config, Deposits,
+	Entries, Metadata, MetadataCount, PendingWitnesses, RevocationList, StatementExpiry,
+	Statements, StatusListChunks, StatusLists, STATUS_LIST_CHUNK_BITS,
+};
+
+#[allow(dead_code)]
+pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
+	StatementExpiry::<T>::iter_keys().try_for_each(
+		|statement_id| -> Result<(), TryRuntimeError> {
+			ensure!(
+				Statements::<T>::contains_key(&statement_id),
+				log_and_return_error_message(format!(
+					"Expiry set for statement {:?} which is not anchored",
+					statement_id
+				))
+			);
+			Ok(())
+		},
+	)?;
+
+	PendingWitnesses::<T>::iter().try_for_each(
+		|(statement_id, pending)| -> Result<(), TryRuntimeError> {
+			ensure!(
+				Statements::<T>::contains_key(&statement_id),
+				log_and_return_error_message(format!(
+					"Witnesses pending for statement {:?} which is not anchored",
+					statement_id
+				))
+			);
+			ensure!(
+				pending.approvals.len() < pending.required as usize &&
+					pending.required as usize <= pending.witnesses.len(),
+				log_and_return_error_message(format!(
+					"Statement {:?} has {:?} of {:?} required approvals from {:?} witnesses",
+					statement_id,
+					pending.approvals.len(),
+					pending.required,
+					pending.witnesses.len()
+				))
+			);
+			Ok(())
+		},
+	)?;
+
+	Deposits::<T>::iter_keys().try_for_each(|statement_id| -> Result<(), TryRuntimeError> {
+		ensure!(
+			Statements::<T>::contains_key(&statement_id),
+			log_and_return_error_message(format!(
+				"Deposit held for statement {:?} which is not anchored",
+				statement_id
+			))
+		);
+		Ok(())
+	})?;
+
+	StatusLists::<T>::iter_keys().try_for_each(|statement_id| -> Result<(), TryRuntimeError> {
+		ensure!(
+			Statements::<T>::contains_key(&statement_id),
+			log_and_return_error_message(format!(
+				"Status list stored for statement {:?} which is not anchored",
+				statement_id
+			))
+		);
+		Ok(())
+	})?;
+
+	StatusListChunks::<T>::iter().try_for_each(
+		|(statement_id, chunk_index, chunk)| -> Result<(), TryRuntimeError> {
+			let length = StatusLists::<T>::get(&statement_id).ok_or_else(|| {
+				log_and_return_error_message(format!(
+					"Status list chunk {:?} stored for statement {:?} without a status list",
+					chunk_index, statement_id
+				))
+			})?;
+			ensure!(
+				chunk_index < length.div_ceil(STATUS_LIST_CHUNK_BITS),
+				log_and_return_error_message(format!(
+					"Status list chunk {:?} of statement {:?} is beyond its length {:?}",
+					chunk_index, statement_id, length
+				))
+			);
+			ensure!(
+				chunk.iter().any(|byte| *byte != 0),
+				log_and_return_error_message(format!(
+					"Empty status list chunk {:?} stored for statement {:?}",
+					chunk_index, statement_id
+				))
+			);
+			Ok(())
+		},
+	)?;
+
+	AnchorState::<T>::iter().try_for_each(
+		|(digest, space_id, state)| -> Result<(), TryRuntimeError> {
+			let details = Statements::<T>::get(&state.identifier).ok_or_else(|| {
+				log_and_return_error_message(format!(
+					"Anchor state of digest {:?} refers to statement {:?} which is not anchored",
+					digest, state.identifier
+				))
+			})?;
+			ensure!(
+				details.space == space_id && Entries::<T>::contains_key(&state.identifier, digest),
+				log_and_return_error_message(format!(
+					"Anchor state of digest {:?} in space {:?} does not match statement {:?}",
+					digest, space_id, state.identifier
+				))
+			);
+			ensure!(
+				state.revoked == RevocationList::<T>::contains_key(&state.identifier, digest),
+				log_and_return_error_message(format!(
+					"Anchor state of digest {:?} disagrees with the revocation list",
+					digest
+				))
+			);
+			Ok(())
+		},
+	)?;
+
+	let committed = CommittedLeafCount::<T>::get();
+	let recorded = AttestationLeafCount::<T>::get();
+	AttestationLeaves::<T>::iter_keys().try_for_each(|number| -> Result<(), TryRuntimeError> {
+		ensure!(
+			committed <= number && number < recorded,
+			log_and_return_error_message(format!(
+				"Attestation leaf {:?} is outside the pending range {:?}..{:?}",
+				number, committed, recorded
+			))
+		);
+		Ok(())
+	})?;
+
+	MetadataCount::<T>::iter().try_for_each(
+		|(statement_id, count)| -> Result<(), TryRuntimeError> {
+			ensure!(
+				Statements::<T>::contains_key(&statement_id),
+				log_and_return_error_message(format!(
+					"Metadata stored for statement {:?} which is not anchored",
+					statement_id
+				))
+			);
+			ensure!(
+				count as usize == Metadata::<T>::iter_prefix(&statement_id).count(),
+				log_and_return_error_message(format!(
+					"Metadata count of statement {:?} does not match its entries",
+					statement_id
+				))
+			);
+			Ok(())
+		},
+	)
+}
@@ -28,7 +28,8 @@
 //!
 //! The `StatementDetails` type is used to encapsulate the essential identifiers
 //! for a statement, including its content's hash, the space it belongs to, and
-//! an optional schema for data structure.
+//! an optional schema for data structure, along with the content type that
+//! tells consumers how to interpret the anchored digest.
 //!
 //! The `StatementEntryStatus` type records the revocation status of a
 //! statement, indicating whether it has been revoked by a particular account.
@@ -57,6 +58,9 @@ use sp_runtime::RuntimeDebug;
 ///   structure or expected format of the statement's data. This can be used for data validation or
 ///   to aid in the interpretation of the statement's data.
 ///
+/// - `content_type`: A `ContentTypeOf` declared when the statement is created. It tells downstream
+///   consumers what kind of document the digest was computed over.
+///
 /// ## Usage
 ///
 /// This struct is typically used when creating or querying statements, allowing
@@ -75,6 +79,36 @@ pub struct StatementDetails<StatementDigestOf, SchemaIdOf, SpaceIdOf> {
 	/// Optional identifier for the schema describing the statement's data
 	/// structure.
 	pub schema: Option<SchemaIdOf>,
+	/// Kind of content the digest was computed over.
+	pub content_type: ContentTypeOf,
+}
+
+/// Kind of content a statement digest is computed over, declared when the
+/// statement is created.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	Copy,
+	Default,
+	MaxEncodedLen,
+	RuntimeDebug,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	TypeInfo,
+)]
+pub enum ContentTypeOf {
+	/// A JSON-LD document, such as a verifiable credential.
+	JsonLd,
+	/// A CBOR encoded document.
+	Cbor,
+	/// The hash of a PDF document.
+	PdfHash,
+	/// Content of any other or unspecified kind.
+	#[default]
+	Generic,
 }
 
 /// `StatementEntryStatus` records the revocation status of a statement. It is a
@@ -160,6 +194,132 @@ pub struct StatementPresentationDetails<
 	pub space: SpaceIdOf,
 }
 
+/// A statement as seen by verifiers: the latest details together with its
+/// current status and the recorded activity timeline.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct StatementInfo<StatementIdOf, StatementDetailsOf, BlockNumber, EventEntryOf> {
+	/// The statement identifier.
+	pub identifier: StatementIdOf,
+	/// Details of the latest state of the statement.
+	pub details: StatementDetailsOf,
+	/// Indicates whether the latest state has been revoked.
+	pub revoked: StatusOf,
+	/// Block number after which the statement expires, if any.
+	pub expires_at: Option<BlockNumber>,
+	/// Indicates whether the statement still awaits witness approvals.
+	pub pending: StatusOf,
+	/// Revision of the statement, to be passed to the next `update`.
+	pub revision: u32,
+	/// Activity timeline of the statement, oldest first.
+	pub timeline: Vec<EventEntryOf>,
+}
+
+/// Verification state of an anchored statement digest.
+///
+/// Everything a verifier needs to confirm that a digest is anchored, not
+/// revoked and issued under a schema is kept in a single storage item, so
+/// that it can be checked with one storage proof.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct AnchorStateEntry<StatementIdOf, SchemaIdOf, BlockNumber> {
+	/// Identifier of the statement the digest belongs to.
+	pub identifier: StatementIdOf,
+	/// Schema referenced by the statement, if any.
+	pub schema: Option<SchemaIdOf>,
+	/// Indicates whether the digest has been revoked.
+	pub revoked: StatusOf,
+	/// Block at which the digest was anchored.
+	pub block: BlockNumber,
+}
+
+/// The statement a digest resolves to, and the commit of the statement that
+/// anchored it.
+///
+/// Commits are numbered from zero for the genesis digest of a statement and
+/// incremented on each update. Presentations carry the index of the state
+/// they were added against.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct DigestCommit<StatementIdOf> {
+	/// Identifier of the statement the digest belongs to.
+	pub identifier: StatementIdOf,
+	/// Index of the commit within the statement's history.
+	pub index: u32,
+}
+
+/// A metadata value attached to a statement, along with the deposit held
+/// for storing it.
+///
+/// The deposit is reserved from the account that set the value and is
+/// returned to it once the entry is cleared or the statement is removed.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct MetadataEntry<MetadataValueOf, AccountIdOf, BalanceOf> {
+	/// The value stored under the key.
+	pub value: MetadataValueOf,
+	/// The account the deposit was reserved from.
+	pub depositor: AccountIdOf,
+	/// The amount reserved for the entry.
+	pub deposit: BalanceOf,
+}
+
+/// Deposit held for a statement while it is stored on chain.
+///
+/// The deposit is reserved from the account that paid for the registration
+/// and is returned to it once the statement is removed.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct StatementDeposit<AccountIdOf, BalanceOf> {
+	/// The account the deposit was reserved from.
+	pub depositor: AccountIdOf,
+	/// The amount reserved for the statement.
+	pub amount: BalanceOf,
+}
+
+/// Witness requirement of a statement that is not yet authoritative.
+///
+/// The statement stays pending until `required` of the listed `witnesses`
+/// have approved it.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct StatementWitnesses<WitnessListOf> {
+	/// Number of approvals needed before the statement is anchored.
+	pub required: u32,
+	/// Identities allowed to approve the statement.
+	pub witnesses: WitnessListOf,
+	/// Witnesses that have approved the statement so far.
+	pub approvals: WitnessListOf,
+}
+
+/// A Merkle root committing to a range of anchored statement digests, for
+/// export to external chains.
+///
+/// Leaves are the Keccak-256 hashes of the anchored digests, numbered in
+/// anchoring order. This commitment covers leaves `first_leaf` to
+/// `first_leaf + leaf_count - 1`, hashed as sorted pairs.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct AttestationCommitment<AttestationHash, BlockNumber> {
+	/// Root of the tree over the committed leaves.
+	pub root: AttestationHash,
+	/// Number of the first leaf in the commitment.
+	pub first_leaf: u64,
+	/// Number of leaves in the commitment.
+	pub leaf_count: u32,
+	/// Block in which the commitment was made.
+	pub block: BlockNumber,
+}
+
+/// Running counters of statement activity.
+///
+/// Kept for the whole chain, per schema and per `StatsPeriod`, so network
+/// reports can be produced without replaying historical blocks.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo,
+)]
+pub struct AnchoringStats {
+	/// Number of statements registered.
+	pub created: u64,
+	/// Number of statement updates.
+	pub updated: u64,
+	/// Number of statement revocations.
+	pub revoked: u64,
+}
+
 /// Enum representing various file types that could be associated with a
 /// statement's presentation.
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
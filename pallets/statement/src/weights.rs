@@ -57,6 +57,16 @@ pub trait WeightInfo {
 	fn register_batch(l: u32, ) -> Weight;
 	fn add_presentation() -> Weight;
 	fn remove_presentation() -> Weight;
+	fn set_expiry() -> Weight;
+	fn register_with_witnesses(w: u32, ) -> Weight;
+	fn witness_approve() -> Weight;
+	fn create_status_list() -> Weight;
+	fn set_status_at() -> Weight;
+	fn remark(l: u32, ) -> Weight;
+	fn schedule_revoke() -> Weight;
+	fn cancel_scheduled_revoke() -> Weight;
+	fn set_metadata() -> Weight;
+	fn clear_metadata() -> Weight;
 }
 
 /// Weights for `pallet_statement` using the CORD node and recommended hardware.
@@ -230,6 +240,78 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn set_expiry() -> Weight {
+		Weight::from_parts(42_630_000, 3671)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn register_with_witnesses(w: u32, ) -> Weight {
+		Weight::from_parts(46_118_000, 3671)
+			.saturating_add(Weight::from_parts(512_000, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn witness_approve() -> Weight {
+		Weight::from_parts(25_027_000, 5139)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn create_status_list() -> Weight {
+		Weight::from_parts(39_377_000, 3671)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn set_status_at() -> Weight {
+		Weight::from_parts(32_641_000, 3671)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn remark(l: u32, ) -> Weight {
+		Weight::from_parts(23_540_000, 3671)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn schedule_revoke() -> Weight {
+		Weight::from_parts(40_270_000, 7571)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn cancel_scheduled_revoke() -> Weight {
+		Weight::from_parts(37_620_000, 7571)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn set_metadata() -> Weight {
+		Weight::from_parts(39_420_000, 3918)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn clear_metadata() -> Weight {
+		Weight::from_parts(34_990_000, 3918)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -402,4 +484,76 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn set_expiry() -> Weight {
+		Weight::from_parts(42_630_000, 3671)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn register_with_witnesses(w: u32, ) -> Weight {
+		Weight::from_parts(46_118_000, 3671)
+			.saturating_add(Weight::from_parts(512_000, 0).saturating_mul(w.into()))
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn witness_approve() -> Weight {
+		Weight::from_parts(25_027_000, 5139)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn create_status_list() -> Weight {
+		Weight::from_parts(39_377_000, 3671)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn set_status_at() -> Weight {
+		Weight::from_parts(32_641_000, 3671)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn remark(l: u32, ) -> Weight {
+		Weight::from_parts(23_540_000, 3671)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn schedule_revoke() -> Weight {
+		Weight::from_parts(40_270_000, 7571)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn cancel_scheduled_revoke() -> Weight {
+		Weight::from_parts(37_620_000, 7571)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn set_metadata() -> Weight {
+		Weight::from_parts(39_420_000, 3918)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn clear_metadata() -> Weight {
+		Weight::from_parts(34_990_000, 3918)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
@@ -0,0 +1,98 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::Encode;
+use cord_utilities::traits::GenerateBenchmarkOrigin;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::sp_runtime::traits::Hash;
+use frame_system::RawOrigin;
+use identifier::{IdentifierCreator, IdentifierType, Ss58Identifier};
+use pallet_chain_space::SpaceCodeOf;
+
+const SEED: u32 = 0;
+
+/// Generates a space ID from a digest.
+pub fn generate_space_id<T: Config>(digest: &SpaceCodeOf<T>) -> SpaceIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Space).unwrap()
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Creates and approves a space owned by `did`.
+fn create_space<T: Config>(caller: T::AccountId, did: T::SpaceCreatorId) -> SpaceIdOf
+where
+	<T as pallet_chain_space::Config>::EnsureOrigin:
+		GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::SpaceCreatorId>,
+{
+	let raw_space = [56u8; 256].to_vec();
+	let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+	let origin = <T as pallet_chain_space::Config>::EnsureOrigin::generate_origin(caller, did);
+	pallet_chain_space::Pallet::<T>::create(origin, space_digest)
+		.expect("Space creation should not fail.");
+	pallet_chain_space::Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), 3u64)
+		.expect("Approval should not fail.");
+
+	space_id
+}
+
+benchmarks! {
+	where_clause {
+		where
+		<T as pallet_chain_space::Config>::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::SpaceCreatorId>,
+	}
+	sponsor {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let space_id = create_space::<T>(caller.clone(), did);
+
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		let per_call_limit = T::Currency::minimum_balance();
+		T::Currency::make_free_balance_be(&caller, amount * 2u32.into());
+
+	}: _(RawOrigin::Signed(caller.clone()), space_id.clone(), amount, per_call_limit)
+	verify {
+		assert_last_event::<T>(Event::Sponsored { space: space_id, sponsor: caller, amount, per_call_limit }.into());
+	}
+
+	withdraw {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let space_id = create_space::<T>(caller.clone(), did);
+
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		let per_call_limit = T::Currency::minimum_balance();
+		T::Currency::make_free_balance_be(&caller, amount * 2u32.into());
+		Pallet::<T>::sponsor(RawOrigin::Signed(caller.clone()).into(), space_id.clone(), amount, per_call_limit)?;
+
+	}: _(RawOrigin::Signed(caller.clone()), space_id.clone())
+	verify {
+		assert_last_event::<T>(Event::Withdrawn { space: space_id, sponsor: caller, amount }.into());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,306 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Transaction Sponsor Pallet
+//!
+//! Lets a sponsor pay the transaction fees of calls made within a chain
+//! space, so that the people issuing, receiving or presenting credentials
+//! in that space do not need to hold tokens.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! A sponsor deposits funds against a space. The funds are held in an
+//! account derived from the pallet id and the space identifier, and the
+//! sponsor can withdraw whatever is left at any time.
+//!
+//! Fees are charged through [`SponsoredFeeAdapter`], which wraps the
+//! runtime's regular `OnChargeTransaction` implementation. For every
+//! transaction it asks the runtime's [`SponsoredSpace`] implementation
+//! whether the call is eligible and which space it belongs to. If that
+//! space is sponsored and the fee is within the per-call limit set by the
+//! sponsor, the sponsorship account pays. Otherwise, and whenever the
+//! sponsorship account cannot cover the fee, the signer pays as usual.
+//!
+//! The deposit acts as the total limit of a sponsorship.
+//!
+//! ## Interface
+//!
+//! - `sponsor`: Deposits funds against a space and sets the per-call fee limit.
+//! - `withdraw`: Returns the remaining funds to the sponsor and ends the sponsorship.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod payment;
+pub mod types;
+pub mod weights;
+
+pub use crate::{pallet::*, payment::SponsoredFeeAdapter, types::*, weights::WeightInfo};
+
+use frame_support::traits::Currency;
+use sp_runtime::traits::{AccountIdConversion, Saturating};
+
+/// Resolves the chain space whose sponsorship pays for a call.
+///
+/// Implemented by the runtime, which decides which calls are eligible and
+/// checks that the signer acts on behalf of the space.
+pub trait SponsoredSpace<AccountId, Call> {
+	/// Returns the space sponsoring `call` when submitted by `who`.
+	fn sponsored_space(who: &AccountId, call: &Call) -> Option<SpaceIdOf>;
+}
+
+impl<AccountId, Call> SponsoredSpace<AccountId, Call> for () {
+	fn sponsored_space(_who: &AccountId, _call: &Call) -> Option<SpaceIdOf> {
+		None
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{ExistenceRequirement, StorageVersion},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	pub use pallet_chain_space::SpaceIdOf;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Type of an account paying or sponsoring fees.
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	/// Type of the balance used for deposits and fees.
+	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+	/// Type of a sponsorship registered against a space.
+	pub type SponsorshipOf<T> = Sponsorship<AccountIdOf<T>, BalanceOf<T>>;
+	/// Type of a fee estimate returned by the runtime API.
+	pub type FeeEstimateOf<T> = FeeEstimate<AccountIdOf<T>, BalanceOf<T>, SpaceIdOf>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_chain_space::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The currency sponsorships are funded with.
+		type Currency: Currency<Self::AccountId>;
+		/// Identifier used to derive the account holding each sponsorship.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// Resolves the space a call is sponsored against.
+		type SponsoredSpace: SponsoredSpace<
+			Self::AccountId,
+			<Self as frame_system::Config>::RuntimeCall,
+		>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Sponsorships stored on chain.
+	/// It maps from a space identifier to its sponsor and per-call fee
+	/// limit. The funds are held in the account returned by
+	/// [`Pallet::sponsorship_account`].
+	#[pallet::storage]
+	pub type Sponsorships<T: Config> =
+		StorageMap<_, Blake2_128Concat, SpaceIdOf, SponsorshipOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Funds have been deposited against a space.
+		/// \[space identifier, sponsor, amount, per-call limit\]
+		Sponsored {
+			space: SpaceIdOf,
+			sponsor: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+			per_call_limit: BalanceOf<T>,
+		},
+		/// A sponsorship has ended and its remaining funds were returned.
+		/// \[space identifier, sponsor, amount\]
+		Withdrawn { space: SpaceIdOf, sponsor: AccountIdOf<T>, amount: BalanceOf<T> },
+		/// A sponsorship paid the fee of a transaction.
+		/// \[space identifier, signer, fee\]
+		FeePaid { space: SpaceIdOf, who: AccountIdOf<T>, fee: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Space identifier is not found
+		SpaceNotFound,
+		/// Space is not sponsored
+		SponsorshipNotFound,
+		/// Only the sponsor can manage a sponsorship
+		NotSponsor,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Deposits funds against a space and sets the largest fee the
+		/// sponsorship pays for a single call.
+		///
+		/// Calling this again tops up the deposit and replaces the per-call
+		/// limit.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the sponsor.
+		/// - `space_id`: The identifier of the space to sponsor.
+		/// - `amount`: The amount transferred into the sponsorship account.
+		/// - `per_call_limit`: The largest fee paid for a single call.
+		///
+		/// # Errors
+		/// - Returns `SpaceNotFound` if the space does not exist.
+		/// - Returns `NotSponsor` if the space is already sponsored by another account.
+		///
+		/// # Events
+		/// - Emits `Sponsored` on success.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::sponsor())]
+		pub fn sponsor(
+			origin: OriginFor<T>,
+			space_id: SpaceIdOf,
+			#[pallet::compact] amount: BalanceOf<T>,
+			#[pallet::compact] per_call_limit: BalanceOf<T>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			ensure!(
+				pallet_chain_space::Spaces::<T>::contains_key(&space_id),
+				Error::<T>::SpaceNotFound
+			);
+
+			if let Some(sponsorship) = <Sponsorships<T>>::get(&space_id) {
+				ensure!(sponsorship.sponsor == sponsor, Error::<T>::NotSponsor);
+			}
+
+			T::Currency::transfer(
+				&sponsor,
+				&Self::sponsorship_account(&space_id),
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			<Sponsorships<T>>::insert(
+				&space_id,
+				SponsorshipOf::<T> { sponsor: sponsor.clone(), per_call_limit },
+			);
+
+			Self::deposit_event(Event::Sponsored {
+				space: space_id,
+				sponsor,
+				amount,
+				per_call_limit,
+			});
+
+			Ok(())
+		}
+
+		/// Ends a sponsorship and returns the remaining funds to the
+		/// sponsor.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the sponsor.
+		/// - `space_id`: The identifier of the sponsored space.
+		///
+		/// # Errors
+		/// - Returns `SponsorshipNotFound` if the space is not sponsored.
+		/// - Returns `NotSponsor` if the caller is not the sponsor.
+		///
+		/// # Events
+		/// - Emits `Withdrawn` on success.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw())]
+		pub fn withdraw(origin: OriginFor<T>, space_id: SpaceIdOf) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			let sponsorship =
+				<Sponsorships<T>>::get(&space_id).ok_or(Error::<T>::SponsorshipNotFound)?;
+			ensure!(sponsorship.sponsor == sponsor, Error::<T>::NotSponsor);
+
+			let account = Self::sponsorship_account(&space_id);
+			let amount = T::Currency::free_balance(&account);
+			T::Currency::transfer(&account, &sponsor, amount, ExistenceRequirement::AllowDeath)?;
+
+			<Sponsorships<T>>::remove(&space_id);
+
+			Self::deposit_event(Event::Withdrawn { space: space_id, sponsor, amount });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns the account holding the funds deposited against a space.
+	pub fn sponsorship_account(space_id: &SpaceIdOf) -> AccountIdOf<T> {
+		T::PalletId::get().into_sub_account_truncating(space_id)
+	}
+
+	/// Returns the sponsored space and the account that should pay `fee`
+	/// for `call`, or `None` if the signer pays.
+	pub(crate) fn fee_payer(
+		who: &AccountIdOf<T>,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		fee: BalanceOf<T>,
+	) -> Option<(SpaceIdOf, AccountIdOf<T>)> {
+		let space_id = T::SponsoredSpace::sponsored_space(who, call)?;
+		let sponsorship = <Sponsorships<T>>::get(&space_id)?;
+		if fee > sponsorship.per_call_limit {
+			return None;
+		}
+		let payer = Self::sponsorship_account(&space_id);
+		Some((space_id, payer))
+	}
+
+	/// Returns the expected payer of `partial_fee` for `call` signed by
+	/// `who`.
+	///
+	/// A sponsorship is only reported if its account can cover the fee
+	/// without being reaped, mirroring the fallback in
+	/// [`SponsoredFeeAdapter`].
+	pub fn fee_estimate(
+		who: &AccountIdOf<T>,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		partial_fee: BalanceOf<T>,
+	) -> FeeEstimateOf<T> {
+		let sponsored = Self::fee_payer(who, call, partial_fee).filter(|(_, payer)| {
+			T::Currency::free_balance(payer).saturating_sub(T::Currency::minimum_balance()) >=
+				partial_fee
+		});
+
+		match sponsored {
+			Some((space_id, payer)) =>
+				FeeEstimate { partial_fee, payer, sponsored_space: Some(space_id) },
+			None => FeeEstimate { partial_fee, payer: who.clone(), sponsored_space: None },
+		}
+	}
+}
@@ -0,0 +1,98 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountIdOf, BalanceOf, Config, Event, Pallet, SpaceIdOf};
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf},
+	transaction_validity::TransactionValidityError,
+};
+use sp_std::marker::PhantomData;
+
+/// Charges the fee of a sponsored call to its space sponsorship, and any
+/// other call to the signer.
+///
+/// `OnCharge` performs the actual withdrawal, refund and fee distribution,
+/// so a sponsorship account is charged exactly like a signer would be. When
+/// the sponsorship account cannot cover the fee, the signer is charged
+/// instead.
+pub struct SponsoredFeeAdapter<T, OnCharge>(PhantomData<(T, OnCharge)>);
+
+impl<T, OnCharge> OnChargeTransaction<T> for SponsoredFeeAdapter<T, OnCharge>
+where
+	T: Config + pallet_transaction_payment::Config,
+	OnCharge: OnChargeTransaction<T, Balance = BalanceOf<T>>,
+{
+	type Balance = BalanceOf<T>;
+	type LiquidityInfo = (Option<(SpaceIdOf, AccountIdOf<T>)>, OnCharge::LiquidityInfo);
+
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+		dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		if let Some((space_id, payer)) = Pallet::<T>::fee_payer(who, call, fee) {
+			if let Ok(liquidity) = OnCharge::withdraw_fee(&payer, call, dispatch_info, fee, tip) {
+				return Ok((Some((space_id, payer)), liquidity));
+			}
+		}
+
+		OnCharge::withdraw_fee(who, call, dispatch_info, fee, tip)
+			.map(|liquidity| (None, liquidity))
+	}
+
+	fn correct_and_deposit_fee(
+		who: &T::AccountId,
+		dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		let (sponsored, liquidity) = already_withdrawn;
+
+		match sponsored {
+			Some((space_id, payer)) => {
+				OnCharge::correct_and_deposit_fee(
+					&payer,
+					dispatch_info,
+					post_info,
+					corrected_fee,
+					tip,
+					liquidity,
+				)?;
+				Pallet::<T>::deposit_event(Event::<T>::FeePaid {
+					space: space_id,
+					who: who.clone(),
+					fee: corrected_fee,
+				});
+				Ok(())
+			},
+			None => OnCharge::correct_and_deposit_fee(
+				who,
+				dispatch_info,
+				post_info,
+				corrected_fee,
+				tip,
+				liquidity,
+			),
+		}
+	}
+}
@@ -0,0 +1,170 @@
+use super::*;
+use crate::mock::*;
+use codec::Encode;
+use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
+use frame_support::{
+	assert_err, assert_ok,
+	dispatch::{DispatchInfo, PostDispatchInfo},
+};
+use frame_system::RawOrigin;
+use identifier::{IdentifierCreator, IdentifierType, Ss58Identifier};
+use pallet_chain_space::SpaceCodeOf;
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::{traits::Hash, AccountId32};
+
+/// Generates a space ID from a digest.
+pub fn generate_space_id<T: Config>(digest: &SpaceCodeOf<T>) -> SpaceIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Space).unwrap()
+}
+
+pub(crate) const DID_00: SubjectId = SubjectId(AccountId32::new([1u8; 32]));
+pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
+pub(crate) const ACCOUNT_01: AccountId = AccountId::new([2u8; 32]);
+pub(crate) const ACCOUNT_02: AccountId = AccountId::new([3u8; 32]);
+
+type FeeAdapter = <Test as pallet_transaction_payment::Config>::OnChargeTransaction;
+
+/// Creates and approves a space, returning its identifier.
+fn create_space() -> SpaceIdOf {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	assert_ok!(Space::create(DoubleOrigin(author, creator).into(), space_digest));
+	assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), 5u64));
+
+	space_id
+}
+
+#[test]
+fn sponsor_and_withdraw_should_move_funds() {
+	new_test_ext().execute_with(|| {
+		let space_id = create_space();
+		let sponsorship_account = TransactionSponsor::sponsorship_account(&space_id);
+		Balances::make_free_balance_be(&ACCOUNT_01, 1_000);
+
+		assert_ok!(TransactionSponsor::sponsor(
+			RuntimeOrigin::signed(ACCOUNT_01),
+			space_id.clone(),
+			500,
+			10,
+		));
+		assert_eq!(Balances::free_balance(&ACCOUNT_01), 500);
+		assert_eq!(Balances::free_balance(&sponsorship_account), 500);
+
+		assert_err!(
+			TransactionSponsor::sponsor(RuntimeOrigin::signed(ACCOUNT_02), space_id.clone(), 1, 1),
+			Error::<Test>::NotSponsor
+		);
+		assert_err!(
+			TransactionSponsor::withdraw(RuntimeOrigin::signed(ACCOUNT_02), space_id.clone()),
+			Error::<Test>::NotSponsor
+		);
+
+		assert_ok!(TransactionSponsor::withdraw(
+			RuntimeOrigin::signed(ACCOUNT_01),
+			space_id.clone()
+		));
+		assert_eq!(Balances::free_balance(&ACCOUNT_01), 1_000);
+		assert_eq!(Balances::free_balance(&sponsorship_account), 0);
+		assert!(Sponsorships::<Test>::get(&space_id).is_none());
+
+		assert_err!(
+			TransactionSponsor::withdraw(RuntimeOrigin::signed(ACCOUNT_01), space_id),
+			Error::<Test>::SponsorshipNotFound
+		);
+	});
+}
+
+#[test]
+fn sponsored_fees_should_be_charged_to_the_sponsorship() {
+	new_test_ext().execute_with(|| {
+		let space_id = create_space();
+		let sponsorship_account = TransactionSponsor::sponsorship_account(&space_id);
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let info = DispatchInfo::default();
+		Balances::make_free_balance_be(&ACCOUNT_01, 1_000);
+		Balances::make_free_balance_be(&ACCOUNT_02, 100);
+
+		assert_ok!(TransactionSponsor::sponsor(
+			RuntimeOrigin::signed(ACCOUNT_01),
+			space_id.clone(),
+			500,
+			10,
+		));
+		SponsoredSpaceId::set(Some(space_id.clone()));
+
+		let liquidity = FeeAdapter::withdraw_fee(&ACCOUNT_02, &call, &info, 8, 0).unwrap();
+		assert_eq!(Balances::free_balance(&sponsorship_account), 492);
+		assert_eq!(Balances::free_balance(&ACCOUNT_02), 100);
+
+		// The refund of an overestimated fee goes back to the sponsorship
+		assert_ok!(FeeAdapter::correct_and_deposit_fee(
+			&ACCOUNT_02,
+			&info,
+			&PostDispatchInfo::default(),
+			5,
+			0,
+			liquidity,
+		));
+		assert_eq!(Balances::free_balance(&sponsorship_account), 495);
+		System::assert_last_event(
+			Event::<Test>::FeePaid { space: space_id.clone(), who: ACCOUNT_02, fee: 5 }.into(),
+		);
+
+		// Fees above the per-call limit are paid by the signer
+		assert_ok!(FeeAdapter::withdraw_fee(&ACCOUNT_02, &call, &info, 20, 0));
+		assert_eq!(Balances::free_balance(&sponsorship_account), 495);
+		assert_eq!(Balances::free_balance(&ACCOUNT_02), 80);
+
+		// Calls that are not sponsored are paid by the signer
+		SponsoredSpaceId::set(None);
+		assert_ok!(FeeAdapter::withdraw_fee(&ACCOUNT_02, &call, &info, 5, 0));
+		assert_eq!(Balances::free_balance(&sponsorship_account), 495);
+		assert_eq!(Balances::free_balance(&ACCOUNT_02), 75);
+	});
+}
+
+#[test]
+fn fee_estimate_should_report_the_paying_account() {
+	new_test_ext().execute_with(|| {
+		let space_id = create_space();
+		let sponsorship_account = TransactionSponsor::sponsorship_account(&space_id);
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		Balances::make_free_balance_be(&ACCOUNT_01, 1_000);
+
+		assert_ok!(TransactionSponsor::sponsor(
+			RuntimeOrigin::signed(ACCOUNT_01),
+			space_id.clone(),
+			500,
+			10,
+		));
+		SponsoredSpaceId::set(Some(space_id.clone()));
+
+		assert_eq!(
+			TransactionSponsor::fee_estimate(&ACCOUNT_02, &call, 8),
+			FeeEstimate {
+				partial_fee: 8,
+				payer: sponsorship_account.clone(),
+				sponsored_space: Some(space_id.clone()),
+			}
+		);
+		assert_eq!(
+			TransactionSponsor::fee_estimate(&ACCOUNT_02, &call, 20),
+			FeeEstimate { partial_fee: 20, payer: ACCOUNT_02, sponsored_space: None }
+		);
+
+		// A sponsorship that cannot cover the fee is not reported
+		Balances::make_free_balance_be(&sponsorship_account, 5);
+		assert_eq!(
+			TransactionSponsor::fee_estimate(&ACCOUNT_02, &call, 8),
+			FeeEstimate { partial_fee: 8, payer: ACCOUNT_02, sponsored_space: None }
+		);
+	});
+}
@@ -0,0 +1,47 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// A fee sponsorship registered against a chain space.
+///
+/// ## Fields
+///
+/// - `sponsor`: The account that funded the sponsorship and can withdraw it.
+/// - `per_call_limit`: The largest fee the sponsorship pays for a single call.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct Sponsorship<AccountId, Balance> {
+	pub sponsor: AccountId,
+	pub per_call_limit: Balance,
+}
+
+/// The expected fee of an extrinsic and who would be charged for it.
+///
+/// ## Fields
+///
+/// - `partial_fee`: The inclusion fee of the extrinsic, excluding any tip.
+/// - `payer`: The account the fee would be withdrawn from.
+/// - `sponsored_space`: The space whose sponsorship covers the fee, if any.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct FeeEstimate<AccountId, Balance, SpaceId> {
+	pub partial_fee: Balance,
+	pub payer: AccountId,
+	pub sponsored_space: Option<SpaceId>,
+}
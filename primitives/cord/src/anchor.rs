@@ -0,0 +1,86 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Authorization of anchors.
+//!
+//! Pallets that anchor digests ask an [`AuthorizeAnchor`] implementation
+//! whether an anchor is allowed instead of querying the pallets that own
+//! schemas, spaces or identities directly. The runtime picks the
+//! implementation, so the policy can change without forking the anchoring
+//! pallet.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Reason an anchor was refused.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum AnchorRefusal {
+	/// The schema no longer accepts new anchors.
+	Deprecated,
+	/// The schema accepts neither new nor updated anchors.
+	Frozen,
+	/// The creator is not allowed to anchor in the space.
+	Unauthorized,
+}
+
+/// Decides whether a creator may anchor digests in a space, optionally
+/// under a schema.
+pub trait AuthorizeAnchor<Creator, SpaceId, SchemaId> {
+	/// Checks whether `creator` may anchor a new identifier.
+	fn authorize_register(
+		creator: &Creator,
+		space: &SpaceId,
+		schema: Option<&SchemaId>,
+	) -> Result<(), AnchorRefusal>;
+
+	/// Checks whether `creator` may anchor further digests for an existing
+	/// identifier.
+	fn authorize_update(
+		creator: &Creator,
+		space: &SpaceId,
+		schema: Option<&SchemaId>,
+	) -> Result<(), AnchorRefusal>;
+
+	/// Returns `true` if a digest anchored under `schema` must not be
+	/// anchored anywhere else.
+	fn requires_unique_digest(schema: &SchemaId) -> bool;
+}
+
+/// Allows every anchor and never requires unique digests.
+impl<Creator, SpaceId, SchemaId> AuthorizeAnchor<Creator, SpaceId, SchemaId> for () {
+	fn authorize_register(
+		_creator: &Creator,
+		_space: &SpaceId,
+		_schema: Option<&SchemaId>,
+	) -> Result<(), AnchorRefusal> {
+		Ok(())
+	}
+
+	fn authorize_update(
+		_creator: &Creator,
+		_space: &SpaceId,
+		_schema: Option<&SchemaId>,
+	) -> Result<(), AnchorRefusal> {
+		Ok(())
+	}
+
+	fn requires_unique_digest(_schema: &SchemaId) -> bool {
+		false
+	}
+}
@@ -0,0 +1,368 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Typed identifiers.
+//!
+//! Spaces, schemas and statements are addressed by SS58 style identifiers:
+//! the base58 encoding of a kind prefix, a payload and a two byte Blake2b
+//! checksum. The types in this module fix the kind at the type level, so a
+//! schema identifier cannot be used where a space identifier is expected,
+//! and parsing fails for identifiers of another kind or with a bad
+//! checksum.
+//!
+//! The SCALE encoding of a typed identifier is the bytes of its base58
+//! string, the same layout `Ss58Identifier` stores on chain. Under `std`
+//! identifiers serialize to and from that string.
+
+use codec::{Decode, Encode, EncodeLike, Error as CodecError, Input, MaxEncodedLen, Output};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sp_core::ConstU32;
+use sp_runtime::{BoundedVec, RuntimeDebug};
+use sp_std::{fmt, marker::PhantomData, str, vec, vec::Vec};
+
+/// Context prepended to the checksummed bytes of an identifier.
+const CHECKSUM_CONTEXT: &[u8] = b"CRDIDFR";
+/// Number of checksum bytes appended to an identifier.
+const CHECKSUM_LENGTH: usize = 2;
+
+/// The minimum length of an identifier payload.
+pub const MINIMUM_PAYLOAD_LENGTH: usize = 3;
+/// The maximum length of an identifier payload. Identifiers are derived
+/// from 32 byte hashes.
+pub const MAXIMUM_PAYLOAD_LENGTH: usize = 32;
+/// The maximum length of the base58 string of an identifier.
+pub const MAXIMUM_IDENTIFIER_LENGTH: u32 = 49;
+
+/// Base58 string of an identifier as stored on chain.
+type RawIdentifier = BoundedVec<u8, ConstU32<MAXIMUM_IDENTIFIER_LENGTH>>;
+
+/// Kind of a typed identifier.
+pub trait IdentifierKind {
+	/// SS58 style prefix identifiers of this kind are encoded with.
+	const PREFIX: u16;
+}
+
+/// Kind of space identifiers.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo)]
+pub struct SpaceKind;
+
+impl IdentifierKind for SpaceKind {
+	const PREFIX: u16 = 3390;
+}
+
+/// Kind of schema identifiers.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo)]
+pub struct SchemaKind;
+
+impl IdentifierKind for SchemaKind {
+	const PREFIX: u16 = 7366;
+}
+
+/// Kind of statement identifiers.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo)]
+pub struct StatementKind;
+
+impl IdentifierKind for StatementKind {
+	const PREFIX: u16 = 8902;
+}
+
+/// Identifier of a space.
+pub type SpaceId = TypedIdentifier<SpaceKind>;
+/// Identifier of a schema.
+pub type SchemaId = TypedIdentifier<SchemaKind>;
+/// Identifier of a statement.
+pub type StatementId = TypedIdentifier<StatementKind>;
+
+/// Reason a typed identifier could not be created or parsed.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum IdentifierParseError {
+	/// The payload or the identifier is too short or too long.
+	InvalidLength,
+	/// The identifier is not valid base58.
+	InvalidEncoding,
+	/// The prefix of the identifier is malformed.
+	InvalidPrefix,
+	/// The checksum does not match the prefix and payload.
+	InvalidChecksum,
+	/// The identifier is of another kind.
+	UnexpectedKind,
+}
+
+impl IdentifierParseError {
+	fn as_str(&self) -> &'static str {
+		match self {
+			Self::InvalidLength => "invalid identifier length",
+			Self::InvalidEncoding => "identifier is not valid base58",
+			Self::InvalidPrefix => "invalid identifier prefix",
+			Self::InvalidChecksum => "invalid identifier checksum",
+			Self::UnexpectedKind => "identifier is of another kind",
+		}
+	}
+}
+
+impl fmt::Display for IdentifierParseError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+/// An SS58 style identifier of kind `K`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo)]
+pub struct TypedIdentifier<K>(RawIdentifier, PhantomData<K>);
+
+impl<K: IdentifierKind> TypedIdentifier<K> {
+	/// Creates the identifier of `payload`, usually the hash the identifier
+	/// is derived from.
+	pub fn from_payload(payload: &[u8]) -> Result<Self, IdentifierParseError> {
+		if payload.len() < MINIMUM_PAYLOAD_LENGTH || payload.len() > MAXIMUM_PAYLOAD_LENGTH {
+			return Err(IdentifierParseError::InvalidLength)
+		}
+
+		let mut bytes = encode_prefix(K::PREFIX);
+		bytes.extend_from_slice(payload);
+		let checksum = checksum(&bytes);
+		bytes.extend_from_slice(&checksum);
+
+		let raw = Vec::<u8>::from(bs58::encode(bytes).into_string())
+			.try_into()
+			.map_err(|_| IdentifierParseError::InvalidLength)?;
+		Ok(Self(raw, PhantomData))
+	}
+
+	/// Parses the base58 string of an identifier, checking its kind and
+	/// checksum.
+	pub fn from_ss58(identifier: &str) -> Result<Self, IdentifierParseError> {
+		Self::payload_of(identifier)?;
+		let raw = identifier
+			.as_bytes()
+			.to_vec()
+			.try_into()
+			.map_err(|_| IdentifierParseError::InvalidLength)?;
+		Ok(Self(raw, PhantomData))
+	}
+
+	/// Returns the payload the identifier was created from.
+	pub fn payload(&self) -> Vec<u8> {
+		Self::payload_of(self.as_str()).unwrap_or_default()
+	}
+
+	/// Returns the base58 string of the identifier.
+	pub fn as_str(&self) -> &str {
+		// Identifiers are only built from valid base58 strings.
+		str::from_utf8(&self.0).unwrap_or_default()
+	}
+
+	/// Validates `identifier` as an identifier of kind `K` and returns its
+	/// payload.
+	fn payload_of(identifier: &str) -> Result<Vec<u8>, IdentifierParseError> {
+		if identifier.len() > MAXIMUM_IDENTIFIER_LENGTH as usize {
+			return Err(IdentifierParseError::InvalidLength)
+		}
+		let bytes = bs58::decode(identifier)
+			.into_vec()
+			.map_err(|_| IdentifierParseError::InvalidEncoding)?;
+
+		let (prefix, prefix_length) = decode_prefix(&bytes)?;
+		let payload_length = bytes
+			.len()
+			.checked_sub(prefix_length + CHECKSUM_LENGTH)
+			.ok_or(IdentifierParseError::InvalidLength)?;
+		if !(MINIMUM_PAYLOAD_LENGTH..=MAXIMUM_PAYLOAD_LENGTH).contains(&payload_length) {
+			return Err(IdentifierParseError::InvalidLength)
+		}
+
+		let (body, expected) = bytes.split_at(bytes.len() - CHECKSUM_LENGTH);
+		if checksum(body) != expected {
+			return Err(IdentifierParseError::InvalidChecksum)
+		}
+		if prefix != K::PREFIX {
+			return Err(IdentifierParseError::UnexpectedKind)
+		}
+
+		Ok(body[prefix_length..].to_vec())
+	}
+}
+
+impl<K: IdentifierKind> str::FromStr for TypedIdentifier<K> {
+	type Err = IdentifierParseError;
+
+	fn from_str(identifier: &str) -> Result<Self, Self::Err> {
+		Self::from_ss58(identifier)
+	}
+}
+
+impl<K: IdentifierKind> fmt::Display for TypedIdentifier<K> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl<K> AsRef<[u8]> for TypedIdentifier<K> {
+	fn as_ref(&self) -> &[u8] {
+		&self.0[..]
+	}
+}
+
+impl<K> Encode for TypedIdentifier<K> {
+	fn size_hint(&self) -> usize {
+		self.0.size_hint()
+	}
+
+	fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+		self.0.encode_to(dest)
+	}
+}
+
+impl<K> EncodeLike for TypedIdentifier<K> {}
+
+impl<K: IdentifierKind> Decode for TypedIdentifier<K> {
+	fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
+		let raw = RawIdentifier::decode(input)?;
+		let identifier = str::from_utf8(&raw).map_err(|_| "Identifier is not valid UTF-8")?;
+		Self::payload_of(identifier).map_err(|error| CodecError::from(error.as_str()))?;
+		Ok(Self(raw, PhantomData))
+	}
+}
+
+impl<K> MaxEncodedLen for TypedIdentifier<K> {
+	fn max_encoded_len() -> usize {
+		RawIdentifier::max_encoded_len()
+	}
+}
+
+#[cfg(feature = "std")]
+impl<K: IdentifierKind> Serialize for TypedIdentifier<K> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(self.as_str())
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'de, K: IdentifierKind> Deserialize<'de> for TypedIdentifier<K> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let identifier = String::deserialize(deserializer)?;
+		Self::from_ss58(&identifier).map_err(serde::de::Error::custom)
+	}
+}
+
+/// Returns the SS58 style encoding of an identifier prefix.
+fn encode_prefix(prefix: u16) -> Vec<u8> {
+	let prefix = prefix & 0b0011_1111_1111_1111;
+	match prefix {
+		0..=63 => vec![prefix as u8],
+		_ => {
+			// upper six bits of the lower byte in the first byte, lower two
+			// bits of the lower byte and the upper byte in the second
+			let first = ((prefix & 0b0000_0000_1111_1100) as u8) >> 2;
+			let second = ((prefix >> 8) as u8) | (((prefix & 0b0000_0000_0000_0011) as u8) << 6);
+			vec![first | 0b0100_0000, second]
+		},
+	}
+}
+
+/// Returns the prefix encoded at the start of `bytes` and its length.
+fn decode_prefix(bytes: &[u8]) -> Result<(u16, usize), IdentifierParseError> {
+	match bytes {
+		[first @ 0..=63, ..] => Ok((*first as u16, 1)),
+		[first @ 64..=127, second, ..] => {
+			let lower = ((first & 0b0011_1111) << 2) | (second >> 6);
+			let upper = second & 0b0011_1111;
+			Ok(((lower as u16) | ((upper as u16) << 8), 2))
+		},
+		_ => Err(IdentifierParseError::InvalidPrefix),
+	}
+}
+
+/// Returns the checksum of the prefix and payload of an identifier.
+fn checksum(body: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+	let mut context = blake2_rfc::blake2b::Blake2b::new(64);
+	context.update(CHECKSUM_CONTEXT);
+	context.update(body);
+	let hash = context.finalize();
+	[hash.as_bytes()[0], hash.as_bytes()[1]]
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Deterministic payloads of every valid length.
+	fn payloads() -> impl Iterator<Item = Vec<u8>> {
+		(MINIMUM_PAYLOAD_LENGTH..=MAXIMUM_PAYLOAD_LENGTH).flat_map(|length| {
+			(0u8..8).map(move |seed| sp_core::blake2_256(&[length as u8, seed])[..length].to_vec())
+		})
+	}
+
+	#[test]
+	fn identifiers_should_round_trip_through_strings_scale_and_json() {
+		for payload in payloads() {
+			let id = SchemaId::from_payload(&payload).unwrap();
+			assert_eq!(id.payload(), payload);
+			assert_eq!(id.as_str().parse::<SchemaId>(), Ok(id.clone()));
+
+			// The SCALE encoding matches the base58 bytes stored on chain.
+			assert_eq!(id.encode(), id.as_str().as_bytes().to_vec().encode());
+			assert_eq!(SchemaId::decode(&mut &id.encode()[..]).ok(), Some(id.clone()));
+
+			let json = serde_json::to_string(&id).unwrap();
+			assert_eq!(json, format!("\"{id}\""));
+			assert_eq!(serde_json::from_str::<SchemaId>(&json).unwrap(), id);
+		}
+	}
+
+	#[test]
+	fn identifiers_of_different_kinds_should_not_mix() {
+		for payload in payloads() {
+			let space = SpaceId::from_payload(&payload).unwrap();
+			let statement = StatementId::from_payload(&payload).unwrap();
+			assert_ne!(space.as_str(), statement.as_str());
+
+			assert_eq!(
+				SchemaId::from_ss58(space.as_str()),
+				Err(IdentifierParseError::UnexpectedKind)
+			);
+			assert_eq!(
+				SpaceId::from_ss58(statement.as_str()),
+				Err(IdentifierParseError::UnexpectedKind)
+			);
+			assert!(StatementId::decode(&mut &space.encode()[..]).is_err());
+			let json = serde_json::to_string(&statement).unwrap();
+			assert!(serde_json::from_str::<SpaceId>(&json).is_err());
+		}
+	}
+
+	#[test]
+	fn malformed_identifiers_should_be_rejected() {
+		for payload in payloads() {
+			let id = StatementId::from_payload(&payload).unwrap();
+			let mut corrupted = id.as_str().as_bytes().to_vec();
+			let last = corrupted.len() - 1;
+			corrupted[last] = if corrupted[last] == b'1' { b'2' } else { b'1' };
+			assert!(StatementId::from_ss58(str::from_utf8(&corrupted).unwrap()).is_err());
+		}
+
+		assert_eq!(StatementId::from_ss58("0OIl"), Err(IdentifierParseError::InvalidEncoding));
+		assert_eq!(StatementId::from_payload(&[1u8; 2]), Err(IdentifierParseError::InvalidLength));
+		assert_eq!(
+			StatementId::from_payload(&[1u8; MAXIMUM_PAYLOAD_LENGTH + 1]),
+			Err(IdentifierParseError::InvalidLength)
+		);
+	}
+}
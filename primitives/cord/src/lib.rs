@@ -26,6 +26,13 @@ use sp_runtime::{
 };
 use sp_std::vec::Vec;
 
+pub mod anchor;
+pub use anchor::{AnchorRefusal, AuthorizeAnchor};
+pub mod identifiers;
+pub use identifiers::{IdentifierParseError, SchemaId, SpaceId, StatementId};
+pub mod receipt;
+pub use receipt::{AnchoringReceipt, CommitType};
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
@@ -0,0 +1,105 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Anchoring receipts.
+//!
+//! A receipt is a compact proof that an identifier was anchored with a given
+//! digest in a given block. Issuers embed it into credentials so verifiers
+//! can locate the anchoring block without querying the issuer.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// The kind of commit an anchoring receipt attests.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum CommitType {
+	/// The first digest anchored for the identifier.
+	Genesis,
+	/// A digest anchored by a later update of the identifier.
+	Update,
+}
+
+/// Receipt of the latest digest anchored for an identifier.
+///
+/// The block hash is only known for recent blocks. It is `None` when the
+/// anchoring block is older than the chain keeps hashes for, in which case
+/// it can be looked up from the block number.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AnchoringReceipt<Identifier, Hash, BlockNumber> {
+	/// The anchored identifier.
+	pub identifier: Identifier,
+	/// The digest anchored by the commit.
+	pub digest: Hash,
+	/// Hash of the block the digest was anchored in, if known.
+	pub block_hash: Option<Hash>,
+	/// Number of the block the digest was anchored in.
+	pub block_number: BlockNumber,
+	/// The kind of commit that anchored the digest.
+	pub commit_type: CommitType,
+}
+
+impl<Identifier: Encode, Hash: Encode, BlockNumber: Encode>
+	AnchoringReceipt<Identifier, Hash, BlockNumber>
+{
+	/// Returns the SCALE encoding of the receipt. These are the bytes a
+	/// receipt is signed over.
+	pub fn to_scale(&self) -> Vec<u8> {
+		self.encode()
+	}
+}
+
+#[cfg(feature = "std")]
+impl<Identifier, Hash, BlockNumber> AnchoringReceipt<Identifier, Hash, BlockNumber>
+where
+	Identifier: AsRef<[u8]>,
+	Hash: Serialize,
+	BlockNumber: Serialize,
+{
+	/// Returns the receipt as compact JSON. Fields are written in
+	/// declaration order with camelCase names, the identifier as its
+	/// textual form and hashes as `0x`-prefixed hex, so equal receipts
+	/// always serialize to the same string.
+	pub fn to_json(&self) -> serde_json::Result<String> {
+		#[derive(Serialize)]
+		#[serde(rename_all = "camelCase")]
+		struct Json<'a, Hash, BlockNumber> {
+			identifier: &'a str,
+			digest: &'a Hash,
+			block_hash: &'a Option<Hash>,
+			block_number: &'a BlockNumber,
+			commit_type: CommitType,
+		}
+
+		let identifier = std::str::from_utf8(self.identifier.as_ref())
+			.map_err(<serde_json::Error as serde::ser::Error>::custom)?;
+
+		serde_json::to_string(&Json {
+			identifier,
+			digest: &self.digest,
+			block_hash: &self.block_hash,
+			block_number: &self.block_number,
+			commit_type: self.commit_type,
+		})
+	}
+}
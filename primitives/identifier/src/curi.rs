@@ -90,8 +90,8 @@ impl IdentifierType {
 			6480 => Some(IdentifierType::Entity),
 			8911 => Some(IdentifierType::Template),
 			2348 => Some(IdentifierType::Asset),
-			6077 => Some(IdentifierType::AssetInstance),
-			11380 => Some(IdentifierType::Rating),
+			6077 => Some(IdentifierType::Rating),
+			11380 => Some(IdentifierType::AssetInstance),
 			9274 => Some(IdentifierType::Registries),
 			9944 => Some(IdentifierType::Entries),
 			10001 => Some(IdentifierType::RegistryAuthorization),
@@ -30,11 +30,12 @@ use sp_runtime::BoundedVec;
 use sp_std::{prelude::Clone, str};
 pub mod types;
 pub use crate::types::*;
+use codec::Encode;
 use frame_support::traits::Get;
 use frame_system::pallet_prelude::BlockNumberFor;
 
 pub use crate::pallet::*;
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 
 #[cfg(any(feature = "mock", test))]
 pub mod mock;
@@ -42,6 +43,8 @@ pub mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -51,7 +54,7 @@ pub mod pallet {
 	};
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	/// Identifier
 	pub type IdentifierOf = Ss58Identifier;
@@ -64,7 +67,9 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
-		/// The maximum number of activity for a statement.
+		/// The maximum number of activity entries kept for an identifier.
+		/// Once reached, the oldest entry after the genesis record is pruned
+		/// to make room for the new one.
 		#[pallet::constant]
 		type MaxEventsHistory: Get<u32>;
 	}
@@ -94,6 +99,50 @@ pub mod pallet {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Returns the activity timeline recorded for an identifier, oldest
+	/// entry first.
+	pub fn timeline(id: &IdentifierOf, id_type: IdentifierTypeOf) -> Option<Vec<EventEntryOf>> {
+		Identifiers::<T>::get(id, id_type).map(|events| events.into_inner())
+	}
+}
+
+/// Prefix of the offchain index entries that map an identifier to the
+/// point in the chain where it was created.
+pub const OFFCHAIN_INDEX_PREFIX: &[u8] = b"cord::identifier::";
+
+/// Returns the offchain index key holding the genesis [`Timepoint`] of the
+/// identifier with the given SS58 encoding. Entries are only written when
+/// the node runs with `--enable-offchain-indexing`.
+pub fn offchain_index_key(id: &[u8]) -> Vec<u8> {
+	[OFFCHAIN_INDEX_PREFIX, id].concat()
+}
+
+/// Derives an identifier of type `id_type` from the SCALE encoded parts it
+/// is bound to. The parts are concatenated, hashed with `H` and the hash is
+/// SS58 encoded under the prefix of `id_type`.
+pub fn derive_identifier<H: sp_runtime::traits::Hash>(
+	id_type: IdentifierType,
+	parts: &[&[u8]],
+) -> Result<Ss58Identifier, IdentifierError> {
+	let id_digest = H::hash(&parts.concat()[..]);
+	Ss58Identifier::create_identifier(&id_digest.encode()[..], id_type)
+}
+
+/// Derives the identifier of a statement anchoring `digest` in `space` on
+/// behalf of `creator`. The runtime assigns statement identifiers with this
+/// function, so clients can compute them before submitting a statement.
+pub fn statement_identifier<H: sp_runtime::traits::Hash>(
+	digest: &H::Output,
+	space: &Ss58Identifier,
+	creator: &impl Encode,
+) -> Result<Ss58Identifier, IdentifierError> {
+	derive_identifier::<H>(
+		IdentifierType::Statement,
+		&[&digest.encode()[..], &space.encode()[..], &creator.encode()[..]],
+	)
+}
+
 pub trait IdentifierUpdate<I, IT, EE, E> {
 	fn update_timeline(id: &I, id_type: IT, entry: EE) -> Result<(), E>;
 }
@@ -106,6 +155,8 @@ impl<T: Config> IdentifierUpdate<IdentifierOf, IdentifierTypeOf, EventEntryOf, I
 		id_type: IdentifierTypeOf,
 		entry: EventEntryOf,
 	) -> Result<(), IdentifierError> {
+		let genesis = (entry.action == CallTypeOf::Genesis).then_some(entry.location);
+
 		Identifiers::<T>::try_mutate(id, id_type, |timeline| {
 			let events = timeline.get_or_insert_with(BoundedVec::default);
 
@@ -115,6 +166,12 @@ impl<T: Config> IdentifierUpdate<IdentifierOf, IdentifierTypeOf, EventEntryOf, I
 
 			events.try_push(entry).map_err(|_| IdentifierError::MaxEventsHistoryExceeded)
 		})
-		.map_err(|_| IdentifierError::MaxEventsHistoryExceeded) // Map DispatchError to your custom Error
+		.map_err(|_| IdentifierError::MaxEventsHistoryExceeded)?; // Map DispatchError to your custom Error
+
+		if let Some(location) = genesis {
+			sp_io::offchain_index::set(&offchain_index_key(id.as_ref()), &location.encode());
+		}
+
+		Ok(())
 	}
 }
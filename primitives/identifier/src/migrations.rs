@@ -0,0 +1,123 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the identifier pallet.
+
+use crate::{Config, EventEntryOf, IdentifierOf, IdentifierTypeOf, Identifiers, Pallet};
+use frame_support::{
+	migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::WeightMeter,
+	BoundedVec,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Migration from storage version 1 to 2, which bounded timelines by a
+/// finite `MaxEventsHistory`.
+pub mod v2 {
+	use super::*;
+
+	mod v1 {
+		use super::*;
+		use frame_support::{
+			pallet_prelude::OptionQuery, storage_alias, Blake2_128Concat, Twox64Concat,
+		};
+
+		/// Timelines as stored in version 1, without a bound.
+		#[storage_alias]
+		pub type Identifiers<T: Config> = StorageDoubleMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			IdentifierOf,
+			Twox64Concat,
+			IdentifierTypeOf,
+			Vec<EventEntryOf>,
+			OptionQuery,
+		>;
+	}
+
+	/// Trims every timeline to `MaxEventsHistory` entries, keeping the
+	/// genesis entry and the newest entries. Timelines longer than the
+	/// bound would otherwise fail to decode and be restarted by the next
+	/// update, losing their history. A bounded vector is encoded like a
+	/// plain vector, so timelines are read with the old, unbounded layout.
+	///
+	/// The number of timelines is not bounded, so this runs as a lazy
+	/// migration from `pallet_runtime_upgrade`: each step migrates as many
+	/// timelines as the weight allows, and the cursor is the key of the last
+	/// migrated one. Trimming is idempotent, so a restarted migration is
+	/// safe. The storage version is set to 2 once every timeline is done.
+	/// Until then, a timeline longer than the bound that has not been
+	/// reached yet reads as missing.
+	pub struct LazyMigrateV1ToV2<T>(PhantomData<T>);
+
+	impl<T: Config> SteppedMigration for LazyMigrateV1ToV2<T> {
+		type Cursor = (IdentifierOf, IdentifierTypeOf);
+		type Identifier = MigrationId<18>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *b"identifier-history", version_from: 1, version_to: 2 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if cursor.is_none() {
+				let required = T::DbWeight::get().reads(1);
+				if meter.try_consume(required).is_err() {
+					return Err(SteppedMigrationError::InsufficientWeight { required })
+				}
+				if Pallet::<T>::on_chain_storage_version() != StorageVersion::new(1) {
+					return Ok(None)
+				}
+			}
+
+			let required = T::DbWeight::get().reads_writes(1, 1);
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required })
+			}
+
+			let max = T::MaxEventsHistory::get() as usize;
+			while meter.try_consume(required).is_ok() {
+				let mut iter = match cursor {
+					Some((id, id_type)) => v1::Identifiers::<T>::iter_from(
+						v1::Identifiers::<T>::hashed_key_for(&id, id_type),
+					),
+					None => v1::Identifiers::<T>::iter(),
+				};
+				let Some((id, id_type, mut events)) = iter.next() else {
+					StorageVersion::new(2).put::<Pallet<T>>();
+					return Ok(None)
+				};
+
+				if events.len() > max {
+					let excess = events.len() - max;
+					// The genesis entry is kept at the front unless the
+					// bound leaves no room for the newest entry.
+					let start = if max > 1 { 1 } else { 0 };
+					events.drain(start..start + excess);
+				}
+				Identifiers::<T>::insert(&id, id_type, BoundedVec::truncate_from(events));
+				cursor = Some((id, id_type));
+			}
+
+			Ok(cursor)
+		}
+	}
+}
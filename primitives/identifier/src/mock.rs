@@ -30,12 +30,14 @@ pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
 
 frame_support::construct_runtime!(
 	pub enum Test {
-		System: frame_system
+		System: frame_system,
+		Identifier: crate,
 	}
 );
 
 parameter_types! {
 	pub const SS58Prefix: u8 = 29;
+	pub const MaxEventsHistory: u32 = 3u32;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -43,6 +45,10 @@ impl frame_system::Config for Test {
 	type Block = Block;
 }
 
+impl crate::Config for Test {
+	type MaxEventsHistory = MaxEventsHistory;
+}
+
 #[allow(dead_code)]
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let t: sp_runtime::Storage =
@@ -24,3 +24,179 @@ fn creating_a_invalid_identifier_length_should_fail() {
 		));
 	});
 }
+
+#[test]
+fn identifier_should_report_the_type_it_was_created_with() {
+	let data = [2u8; 30].to_vec();
+
+	for id_type in [
+		IdentifierType::Authorization,
+		IdentifierType::Space,
+		IdentifierType::Schema,
+		IdentifierType::Statement,
+		IdentifierType::Entity,
+		IdentifierType::Template,
+		IdentifierType::Asset,
+		IdentifierType::AssetInstance,
+		IdentifierType::Rating,
+		IdentifierType::Registries,
+		IdentifierType::Entries,
+		IdentifierType::RegistryAuthorization,
+		IdentifierType::SchemaAccounts,
+	] {
+		let id = Ss58Identifier::create_identifier(&data.encode()[..], id_type.clone()).unwrap();
+		assert_eq!(id.get_type(), Ok(id_type));
+	}
+}
+
+#[test]
+fn timeline_prunes_oldest_entry_but_keeps_genesis() {
+	let space = [2u8; 30].to_vec();
+	let entry = |action, height| EventEntryOf { action, location: Timepoint { height, index: 0 } };
+
+	new_test_ext().execute_with(|| {
+		let id = Ss58Identifier::create_identifier(&(space).encode()[..], IdentifierType::Space)
+			.unwrap();
+
+		assert_ok!(IdentifierTimeline::update_timeline::<Test>(
+			&id,
+			IdentifierTypeOf::ChainSpace,
+			entry(CallTypeOf::Genesis, 1)
+		));
+		for height in 2..=5 {
+			assert_ok!(IdentifierTimeline::update_timeline::<Test>(
+				&id,
+				IdentifierTypeOf::ChainSpace,
+				entry(CallTypeOf::Update, height)
+			));
+		}
+
+		let timeline = Identifiers::<Test>::get(&id, IdentifierTypeOf::ChainSpace).unwrap();
+		assert_eq!(timeline.len(), MaxEventsHistory::get() as usize);
+		assert_eq!(timeline[0], entry(CallTypeOf::Genesis, 1));
+		assert_eq!(timeline[1], entry(CallTypeOf::Update, 4));
+		assert_eq!(timeline[2], entry(CallTypeOf::Update, 5));
+	});
+}
+
+#[test]
+fn timeline_should_return_recorded_entries() {
+	let space = [3u8; 30].to_vec();
+	let entry = |action, height| EventEntryOf { action, location: Timepoint { height, index: 0 } };
+
+	new_test_ext().execute_with(|| {
+		let id = Ss58Identifier::create_identifier(&(space).encode()[..], IdentifierType::Space)
+			.unwrap();
+
+		assert_eq!(Identifier::timeline(&id, IdentifierTypeOf::ChainSpace), None);
+
+		assert_ok!(IdentifierTimeline::update_timeline::<Test>(
+			&id,
+			IdentifierTypeOf::ChainSpace,
+			entry(CallTypeOf::Genesis, 1)
+		));
+
+		assert_eq!(
+			Identifier::timeline(&id, IdentifierTypeOf::ChainSpace),
+			Some(vec![entry(CallTypeOf::Genesis, 1)])
+		);
+		assert_eq!(Identifier::timeline(&id, IdentifierTypeOf::Statement), None);
+	});
+}
+
+#[test]
+fn genesis_entry_should_be_written_to_the_offchain_index() {
+	let space = [4u8; 30].to_vec();
+	let id =
+		Ss58Identifier::create_identifier(&(space).encode()[..], IdentifierType::Space).unwrap();
+	let genesis = Timepoint { height: 1, index: 2 };
+
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		assert_ok!(IdentifierTimeline::update_timeline::<Test>(
+			&id,
+			IdentifierTypeOf::ChainSpace,
+			EventEntryOf { action: CallTypeOf::Genesis, location: genesis }
+		));
+		assert_ok!(IdentifierTimeline::update_timeline::<Test>(
+			&id,
+			IdentifierTypeOf::ChainSpace,
+			EventEntryOf {
+				action: CallTypeOf::Update,
+				location: Timepoint { height: 2, index: 0 }
+			}
+		));
+	});
+	ext.persist_offchain_overlay();
+
+	assert_eq!(ext.offchain_db().get(&offchain_index_key(id.as_ref())), Some(genesis.encode()));
+}
+
+#[test]
+fn statement_identifier_matches_the_documented_derivation() {
+	use sp_runtime::traits::{BlakeTwo256, Hash};
+
+	let digest = BlakeTwo256::hash(&[7u8; 32]);
+	let space =
+		Ss58Identifier::create_identifier(&[3u8; 30].encode()[..], IdentifierType::Space).unwrap();
+	let creator = [9u8; 32];
+
+	let id_digest = BlakeTwo256::hash(
+		&[&digest.encode()[..], &space.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let expected =
+		Ss58Identifier::create_identifier(&id_digest.encode()[..], IdentifierType::Statement)
+			.unwrap();
+
+	let derived = statement_identifier::<BlakeTwo256>(&digest, &space, &creator).unwrap();
+	assert_eq!(derived, expected);
+	assert_eq!(derived.get_type(), Ok(IdentifierType::Statement));
+	assert_ne!(statement_identifier::<BlakeTwo256>(&digest, &space, &[8u8; 32]).unwrap(), derived);
+}
+
+#[test]
+fn migration_should_trim_timelines_to_the_bound_keeping_genesis() {
+	use frame_support::{
+		migrations::SteppedMigration,
+		traits::{GetStorageVersion, StorageVersion},
+		weights::WeightMeter,
+	};
+
+	let space = [4u8; 30].to_vec();
+	let entry = |action, height| EventEntryOf { action, location: Timepoint { height, index: 0 } };
+
+	new_test_ext().execute_with(|| {
+		let id = Ss58Identifier::create_identifier(&(space).encode()[..], IdentifierType::Space)
+			.unwrap();
+
+		// A timeline written while the bound was larger.
+		let mut events = vec![entry(CallTypeOf::Genesis, 1)];
+		events.extend((2..=5).map(|height| entry(CallTypeOf::Update, height)));
+		frame_support::storage::unhashed::put(
+			&Identifiers::<Test>::hashed_key_for(&id, IdentifierTypeOf::ChainSpace),
+			&events,
+		);
+		assert!(Identifiers::<Test>::get(&id, IdentifierTypeOf::ChainSpace).is_none());
+
+		StorageVersion::new(1).put::<Pallet<Test>>();
+		let mut cursor = None;
+		loop {
+			cursor =
+				migrations::v2::LazyMigrateV1ToV2::<Test>::step(cursor, &mut WeightMeter::new())
+					.unwrap();
+			if cursor.is_none() {
+				break
+			}
+		}
+
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), StorageVersion::new(2));
+		assert_eq!(
+			Pallet::<Test>::timeline(&id, IdentifierTypeOf::ChainSpace),
+			Some(vec![
+				entry(CallTypeOf::Genesis, 1),
+				entry(CallTypeOf::Update, 4),
+				entry(CallTypeOf::Update, 5)
+			])
+		);
+	});
+}
@@ -68,6 +68,8 @@ pub enum CallTypeOf {
 	Credit,
 	Issue,
 	Reinstate,
+	Freeze,
+	Unfreeze,
 }
 /// Defining the identifier target types.
 #[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
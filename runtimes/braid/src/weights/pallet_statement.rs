@@ -225,4 +225,86 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn set_expiry() -> Weight {
+		Weight::from_parts(42_630_000, 0)
+			.saturating_add(Weight::from_parts(0, 3671))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn register_with_witnesses(w: u32, ) -> Weight {
+		Weight::from_parts(46_118_000, 0)
+			.saturating_add(Weight::from_parts(0, 3671))
+			.saturating_add(Weight::from_parts(512_000, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().reads(19))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn witness_approve() -> Weight {
+		Weight::from_parts(25_027_000, 0)
+			.saturating_add(Weight::from_parts(0, 5139))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn create_status_list() -> Weight {
+		Weight::from_parts(39_377_000, 0)
+			.saturating_add(Weight::from_parts(0, 3671))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn set_status_at() -> Weight {
+		Weight::from_parts(32_641_000, 0)
+			.saturating_add(Weight::from_parts(0, 3671))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn remark(l: u32, ) -> Weight {
+		Weight::from_parts(23_540_000, 0)
+			.saturating_add(Weight::from_parts(0, 3671))
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn schedule_revoke() -> Weight {
+		Weight::from_parts(40_270_000, 0)
+			.saturating_add(Weight::from_parts(0, 7571))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn cancel_scheduled_revoke() -> Weight {
+		Weight::from_parts(37_620_000, 0)
+			.saturating_add(Weight::from_parts(0, 7571))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn set_metadata() -> Weight {
+		Weight::from_parts(39_420_000, 0)
+			.saturating_add(Weight::from_parts(0, 3918))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn clear_metadata() -> Weight {
+		Weight::from_parts(34_990_000, 0)
+			.saturating_add(Weight::from_parts(0, 3918))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -0,0 +1,50 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_chain_space::{SpaceDelegateInfo, SpaceUsage};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(2)]
+	pub trait ChainSpaceApi<SpaceId, SpaceDelegateInfo, SpaceUsage> where
+		SpaceId: Codec,
+		SpaceDelegateInfo: Codec,
+		SpaceUsage: Codec,
+	{
+	/// Given a space identifier this returns every delegate of the space
+	/// with:
+	/// * the authorization identifier
+	/// * the granted permissions
+	/// * the delegator
+	/// * the last valid block (optional)
+	fn delegates(space: SpaceId) -> Vec<SpaceDelegateInfo>;
+
+	/// Given a space identifier this returns:
+	/// * the approved transaction capacity
+	/// * the capacity allocated to subspaces
+	/// * the number of transactions consumed
+	/// * the number of delegates
+	/// * whether the space is approved, archived and frozen
+	#[api_version(2)]
+	fn usage(space: SpaceId) -> Option<SpaceUsage>;
+	}
+}
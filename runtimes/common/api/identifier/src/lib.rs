@@ -0,0 +1,39 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use identifier::{EventEntryOf, IdentifierTypeOf};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait IdentifierApi<Identifier, IdentifierType, EventEntry> where
+		Identifier: Codec,
+		IdentifierType: Codec,
+		EventEntry: Codec,
+	{
+	/// Given an identifier and the kind of record it refers to, this
+	/// returns the recorded activity timeline, oldest entry first. Each
+	/// entry holds the call type and the block and extrinsic index it
+	/// was recorded at.
+	fn timeline(identifier: Identifier, id_type: IdentifierType) -> Option<Vec<EventEntry>>;
+	}
+}
@@ -0,0 +1,36 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+pub use pallet_proof_of_existence::Proof;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait ProofOfExistenceApi<Digest, Proof> where
+		Digest: Codec,
+		Proof: Codec,
+	{
+	/// Given a digest this returns the account that anchored it together
+	/// with the block number and timestamp of the anchoring, or `None` if
+	/// the digest has not been anchored.
+	fn proof(digest: Digest) -> Option<Proof>;
+	}
+}
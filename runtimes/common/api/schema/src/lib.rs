@@ -0,0 +1,60 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use cord_primitives::{BlockNumber, DidIdentifier, Hash};
+use sp_std::vec::Vec;
+
+pub use pallet_schema::{
+	DigestPolicy, FreezeAuthority, SchemaEntry, SchemaIdOf, SchemaInfo, SpaceIdOf,
+};
+
+/// Schema information returned by the CORD runtimes.
+pub type SchemaInfoOf = SchemaInfo<
+	SchemaIdOf,
+	SchemaEntry<Vec<u8>, Hash, DidIdentifier, SpaceIdOf>,
+	BlockNumber,
+	FreezeAuthority,
+	identifier::EventEntryOf,
+>;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait SchemaApi<SchemaId, SchemaHash, SchemaInfo> where
+		SchemaId: Codec,
+		SchemaHash: Codec,
+		SchemaInfo: Codec,
+	{
+	/// Given a schema identifier this returns:
+	/// * the schema entry with its hash, controller and space
+	/// * whether it was anchored from a preimage
+	/// * the deprecation block (optional)
+	/// * the freezing authority (optional)
+	/// * the digest policy
+	/// * the superseded and superseding versions (optional)
+	/// * the activity timeline
+	fn schema(identifier: SchemaId) -> Option<SchemaInfo>;
+
+	/// Given a schema hash this returns the same information as `schema`
+	/// for every schema anchored with it. The same hash may be anchored in
+	/// several spaces or by several creators.
+	fn schema_by_hash(hash: SchemaHash) -> Vec<SchemaInfo>;
+	}
+}
@@ -0,0 +1,114 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use cord_primitives::{AnchoringReceipt, BlockNumber, Hash};
+use sp_std::vec::Vec;
+
+pub use pallet_statement::{
+	AnchorStateEntry, AnchoringStats, ContentTypeOf, SchemaIdOf, SpaceIdOf, StatementDetails,
+	StatementIdOf, StatementInfo,
+};
+
+/// Statement information returned by the CORD runtimes.
+pub type StatementInfoOf = StatementInfo<
+	StatementIdOf,
+	StatementDetails<Hash, SchemaIdOf, SpaceIdOf>,
+	BlockNumber,
+	identifier::EventEntryOf,
+>;
+
+/// Anchor state of a digest returned by the CORD runtimes.
+pub type AnchorStateOf = AnchorStateEntry<StatementIdOf, SchemaIdOf, BlockNumber>;
+
+/// Anchoring receipt of a statement returned by the CORD runtimes.
+pub type ReceiptOf = AnchoringReceipt<StatementIdOf, Hash, BlockNumber>;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(5)]
+	pub trait StatementApi<StatementId, Digest, SpaceId, StatementInfo, AnchorState, Receipt> where
+		StatementId: Codec,
+		Digest: Codec,
+		SpaceId: Codec,
+		StatementInfo: Codec,
+		AnchorState: Codec,
+		Receipt: Codec,
+	{
+	/// Given a statement identifier this returns:
+	/// * the latest statement details
+	/// * the revocation status of the latest state
+	/// * the expiry block (optional)
+	/// * whether the statement is still awaiting witness approvals
+	/// * the revision expected by the next update
+	/// * the activity timeline
+	fn query(identifier: StatementId) -> Option<StatementInfo>;
+
+	/// Given a digest and the space it was anchored in, this resolves the
+	/// statement and returns the same information as `query`. The digest
+	/// may belong to any state of the statement or to one of its
+	/// presentations.
+	fn query_by_digest(digest: Digest, space: SpaceId) -> Option<StatementInfo>;
+
+	/// Given a statement identifier and an index into its status list, this
+	/// returns whether the index is revoked. Returns `None` if the statement
+	/// has no status list or the index is outside of it.
+	fn status_at(identifier: StatementId, index: u32) -> Option<bool>;
+
+	/// Given a statement identifier, a leaf hash and the sibling hashes from
+	/// the leaf up to the root, this checks that the leaf belongs to the
+	/// Merkle tree anchored as the latest digest of the statement.
+	fn verify_leaf(identifier: StatementId, leaf: Digest, proof: Vec<Digest>) -> bool;
+
+	/// Given a digest and the space it was anchored in, this returns the
+	/// statement identifier, schema, revocation status and anchoring block
+	/// of the digest. The result has a bounded size and is read from a
+	/// single storage item, which keeps `state_call` requests and their
+	/// proofs small for light clients.
+	#[api_version(2)]
+	fn anchor_state(digest: Digest, space: SpaceId) -> Option<AnchorState>;
+
+	/// Given a list of digests this returns, for each digest and in the same
+	/// order, the spaces it is anchored in together with the statement
+	/// identifier, schema, revocation status and anchoring block. A digest
+	/// which is not anchored anywhere yields an empty list.
+	#[api_version(3)]
+	fn verify_batch(digests: Vec<Digest>) -> Vec<Vec<(SpaceId, AnchorState)>>;
+
+	/// Given a statement identifier this returns the anchoring receipt of
+	/// its latest digest: the digest, the block it was anchored in and
+	/// whether it was anchored at registration or by an update. Returns
+	/// `None` for unknown statements and statements still awaiting witness
+	/// approvals.
+	#[api_version(4)]
+	fn receipt(identifier: StatementId) -> Option<Receipt>;
+
+	/// Returns the number of statements registered, updated and revoked
+	/// since genesis, or under a single schema if one is given.
+	#[api_version(5)]
+	fn anchoring_stats(schema: Option<SchemaIdOf>) -> AnchoringStats;
+
+	/// Given a block number this returns the number of statements
+	/// registered, updated and revoked in the `StatsPeriod` containing it.
+	/// Returns `None` if nothing was counted in the period or it is older
+	/// than the retained history.
+	#[api_version(5)]
+	fn period_stats(block: BlockNumber) -> Option<AnchoringStats>;
+	}
+}
@@ -0,0 +1,41 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_transaction_sponsor::{FeeEstimate, SpaceIdOf};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait TransactionSponsorApi<AccountId, Balance, SpaceId> where
+		AccountId: Codec,
+		Balance: Codec,
+		SpaceId: Codec,
+	{
+	/// Given a signed extrinsic and its encoded length this returns the
+	/// expected inclusion fee together with the account it would be charged
+	/// to, or `None` if the extrinsic is unsigned.
+	fn estimate_fee(
+		uxt: <Block as BlockT>::Extrinsic,
+		len: u32,
+	) -> Option<FeeEstimate<AccountId, Balance, SpaceId>>;
+	}
+}
@@ -23,11 +23,11 @@
 use cord_primitives::{AccountId, Balance, BlockNumber};
 use frame_support::{
 	parameter_types,
-	traits::{Currency, OnUnbalanced},
+	traits::{Currency, Imbalance, OnUnbalanced},
 };
 
 use frame_system::limits;
-use sp_runtime::{FixedPointNumber, Perbill, Perquintill};
+use sp_runtime::{FixedPointNumber, Perbill, Percent, Perquintill};
 use static_assertions::const_assert;
 
 pub use pallet_balances::Call as BalancesCall;
@@ -37,6 +37,9 @@ use sp_core::crypto::Ss58AddressFormat;
 pub use sp_runtime::traits::{Bounded, Get};
 use sp_std::marker::PhantomData;
 
+mod priority;
+pub use priority::{CallClass, CheckCallPriority, ClassifyCall};
+
 #[derive(Debug, Clone, Copy)]
 pub enum Ss58AddressFormatPrefix {
 	/// Default for Braid
@@ -133,6 +136,38 @@ where
 	}
 }
 
+/// Splits transaction fees between the treasury and the block author.
+///
+/// `TreasuryShare` of the fees is paid into the treasury and the remainder,
+/// together with any tip, goes to the block author.
+pub struct DealWithFees<R, TreasuryShare>(PhantomData<(R, TreasuryShare)>);
+
+impl<R, TreasuryShare> OnUnbalanced<NegativeImbalance<R>> for DealWithFees<R, TreasuryShare>
+where
+	R: pallet_balances::Config + pallet_treasury::Config + pallet_authorship::Config,
+	pallet_treasury::Pallet<R>: OnUnbalanced<NegativeImbalance<R>>,
+	<R as frame_system::Config>::AccountId: From<AccountId>,
+	<R as frame_system::Config>::AccountId: Into<AccountId>,
+	<R as pallet_balances::Config>::Balance: Into<u128>,
+	TreasuryShare: Get<Percent>,
+{
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance<R>>) {
+		use pallet_treasury::Pallet as Treasury;
+
+		if let Some(fees) = fees_then_tips.next() {
+			let treasury_share = TreasuryShare::get().deconstruct();
+			let (to_treasury, mut to_author) =
+				fees.ration(treasury_share.into(), (100 - treasury_share).into());
+			if let Some(tips) = fees_then_tips.next() {
+				tips.merge_into(&mut to_author);
+			}
+
+			<Treasury<R> as OnUnbalanced<_>>::on_unbalanced(to_treasury);
+			<EverythingToAuthor<R> as OnUnbalanced<_>>::on_unbalanced(to_author);
+		}
+	}
+}
+
 /// Implements the weight types for a runtime.
 /// It expects the passed runtime constants to contain a `weights` module.
 /// The generated weight types were formerly part of the common
@@ -0,0 +1,140 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction pool priority for CORD calls.
+//!
+//! `CheckCallPriority` lifts governance and membership calls above anchoring
+//! traffic in the transaction pool. Anchoring transactions are also given a
+//! shorter lifetime, so a flood of them drains from the pool instead of
+//! holding back network administration.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		TransactionLongevity, TransactionPriority, TransactionValidity, TransactionValidityError,
+		ValidTransaction,
+	},
+};
+use sp_std::marker::PhantomData;
+
+/// Pool class of a runtime call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallClass {
+	/// Council, committee, sudo and network parameter calls.
+	Governance,
+	/// Network and authority membership management.
+	Membership,
+	/// Bulk anchoring of spaces, schemas, statements and registries.
+	Anchoring,
+	/// Everything else.
+	Other,
+}
+
+impl CallClass {
+	/// Priority added on top of the fee based priority of the transaction.
+	pub fn priority(&self) -> TransactionPriority {
+		match self {
+			CallClass::Governance => TransactionPriority::max_value() / 2,
+			CallClass::Membership => TransactionPriority::max_value() / 4,
+			CallClass::Anchoring | CallClass::Other => 0,
+		}
+	}
+
+	/// Number of blocks the transaction stays valid in the pool. The
+	/// lowest longevity reported by any extension wins, so this only ever
+	/// shortens the mortality chosen by the sender.
+	pub fn longevity(&self) -> TransactionLongevity {
+		match self {
+			CallClass::Governance | CallClass::Membership | CallClass::Other =>
+				TransactionLongevity::max_value(),
+			CallClass::Anchoring => 64,
+		}
+	}
+}
+
+/// Maps a runtime call onto its pool class. Implemented by each runtime.
+pub trait ClassifyCall<Call> {
+	fn classify(call: &Call) -> CallClass;
+}
+
+/// Signed extension assigning pool priority and longevity by call class.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T, C))]
+pub struct CheckCallPriority<T, C>(PhantomData<(T, C)>);
+
+impl<T, C> sp_std::fmt::Debug for CheckCallPriority<T, C> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckCallPriority")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T, C> CheckCallPriority<T, C> {
+	/// Create new `SignedExtension` to prioritise calls by class.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T, C> SignedExtension for CheckCallPriority<T, C>
+where
+	T: frame_system::Config + Send + Sync,
+	C: ClassifyCall<T::RuntimeCall> + Clone + Eq + Send + Sync + 'static,
+{
+	type AccountId = T::AccountId;
+	type Call = T::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+	const IDENTIFIER: &'static str = "CheckCallPriority";
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		_who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		let class = C::classify(call);
+		Ok(ValidTransaction {
+			priority: class.priority(),
+			longevity: class.longevity(),
+			..Default::default()
+		})
+	}
+}
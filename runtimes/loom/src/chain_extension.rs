@@ -0,0 +1,80 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Chain extension giving ink! contracts read access to anchored statements.
+//!
+//! Every function takes a SCALE encoded statement identifier as input and
+//! returns a SCALE encoded result:
+//!
+//! | id | function      | output                    |
+//! |----|---------------|---------------------------|
+//! | 1  | `is_anchored` | `bool`                    |
+//! | 2  | `is_revoked`  | `Option<bool>`            |
+//! | 3  | `schema_of`   | `Option<SchemaIdOf>`      |
+//! | 4  | `space_of`    | `Option<SpaceIdOf>`       |
+//!
+//! A statement still awaiting witness approvals is not anchored yet.
+//! `is_revoked` reports on the latest state of the statement and returns
+//! `None` for unknown statements.
+
+use crate::Runtime;
+use codec::Encode;
+use frame_support::traits::Get;
+use pallet_contracts::chain_extension::{
+	ChainExtension, Environment, Ext, InitState, RetVal, SysConfig,
+};
+use pallet_statement::{PendingWitnesses, RevocationList, StatementIdOf, Statements};
+use sp_runtime::DispatchError;
+
+/// Chain extension exposing statement state to contracts.
+#[derive(Default)]
+pub struct CordExtension;
+
+impl ChainExtension<Runtime> for CordExtension {
+	fn call<E: Ext<T = Runtime>>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError> {
+		let func_id = env.func_id();
+		let mut env = env.buf_in_buf_out();
+
+		// Covers the statement, revocation and witness lookups.
+		env.charge_weight(<Runtime as SysConfig>::DbWeight::get().reads(3))?;
+		let in_len = env.in_len();
+		let statement_id: StatementIdOf = env.read_as_unbounded(in_len)?;
+		let details = Statements::<Runtime>::get(&statement_id);
+
+		let output = match func_id {
+			1 => details
+				.is_some_and(|_| !PendingWitnesses::<Runtime>::contains_key(&statement_id))
+				.encode(),
+			2 => details
+				.map(|details| {
+					RevocationList::<Runtime>::contains_key(&statement_id, details.digest)
+				})
+				.encode(),
+			3 => details.and_then(|details| details.schema).encode(),
+			4 => details.map(|details| details.space).encode(),
+			_ => return Err(DispatchError::Other("CordExtension: unknown function")),
+		};
+
+		env.write(&output, false, None)?;
+
+		Ok(RetVal::Converging(0))
+	}
+}
@@ -20,7 +20,7 @@
 // `construct_runtime!` does a lot of recursion and requires us to increase the limits.
 #![recursion_limit = "1024"]
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
 pub use cord_primitives::{AccountId, Signature};
@@ -41,7 +41,7 @@ use frame_support::{
 		fungible::{HoldConsideration, NativeFromLeft, NativeOrWithId, UnionOf},
 		tokens::imbalance::ResolveAssetTo,
 		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, Contains, EitherOfDiverse,
-		KeyOwnerProofSystem, LinearStoragePrice, PrivilegeCmp, VariantCountOf,
+		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, PrivilegeCmp, VariantCountOf,
 	},
 	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, ConstantMultiplier},
 	PalletId,
@@ -63,7 +63,7 @@ use sp_runtime::{
 		Extrinsic as ExtrinsicT, NumberFor, OpaqueKeys, SaturatedConversion, Verify,
 	},
 	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, Perbill, Percent, Permill,
+	ApplyExtrinsicResult, Perbill, Percent, Permill, RuntimeDebug,
 };
 use sp_staking::SessionIndex;
 use sp_std::{cmp::Ordering, prelude::*};
@@ -82,7 +82,7 @@ pub use sp_runtime::BuildStorage;
 /// Constant values used within the runtime.
 use cord_loom_runtime_constants::{currency::*, fee::WeightToFee, time::*};
 use cord_runtime_common as runtime_common;
-use runtime_common::{EverythingToTheTreasury, SlowAdjustingFeeUpdate};
+use runtime_common::{DealWithFees, SlowAdjustingFeeUpdate};
 
 // Weights used in the runtime.
 mod weights;
@@ -91,6 +91,7 @@ pub use authority_membership;
 pub use pallet_network_membership;
 pub mod benchmark;
 pub use benchmark::DummySignature;
+mod chain_extension;
 pub use pallet_assets_runtime_api as assets_api;
 use pallet_network_membership::RuntimeDispatchWeightInfo;
 
@@ -119,10 +120,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("loom"),
 	impl_name: create_runtime_str!("dhiway-cord"),
 	authoring_version: 0,
-	spec_version: 9400,
+	spec_version: 9500,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 2,
+	transaction_version: 3,
 	state_version: 1,
 };
 
@@ -144,11 +145,27 @@ pub fn native_version() -> NativeVersion {
 	NativeVersion { runtime_version: VERSION, can_author_with: Default::default() }
 }
 
-/// We currently allow all calls.
+/// Rejects the calls of call groups disabled through `NetworkParameters`.
+/// All other calls are allowed.
 pub struct BaseFilter;
 impl Contains<RuntimeCall> for BaseFilter {
-	fn contains(_c: &RuntimeCall) -> bool {
-		true
+	fn contains(c: &RuntimeCall) -> bool {
+		use pallet_config::CallGroup;
+
+		let group = match c {
+			RuntimeCall::Balances(
+				pallet_balances::Call::transfer_allow_death { .. } |
+				pallet_balances::Call::transfer_keep_alive { .. } |
+				pallet_balances::Call::transfer_all { .. },
+			) => CallGroup::BalanceTransfers,
+			RuntimeCall::Contracts(_) => CallGroup::Contracts,
+			RuntimeCall::Assets(_) |
+			RuntimeCall::PoolAssets(_) |
+			RuntimeCall::AssetConversion(_) => CallGroup::Assets,
+			_ => return true,
+		};
+
+		NetworkParameters::is_enabled(group)
 	}
 }
 
@@ -319,6 +336,9 @@ parameter_types! {
 	/// This value increases the priority of `Operational` transactions by adding
 	/// a "virtual tip" that's equal to the `OperationalFeeMultiplier * final_fee`.
 	pub const OperationalFeeMultiplier: u8 = 5;
+	/// Share of transaction fees paid into the treasury. The rest goes to
+	/// the block author.
+	pub const TreasuryFeeShare: Percent = Percent::from_percent(80);
 }
 
 // Can't use `FungibleAdapter` here until Treasury pallet migrates to fungibles
@@ -326,7 +346,10 @@ parameter_types! {
 #[allow(deprecated)]
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = CurrencyAdapter<Balances, EverythingToTheTreasury<Runtime>>;
+	type OnChargeTransaction = pallet_transaction_sponsor::SponsoredFeeAdapter<
+		Runtime,
+		CurrencyAdapter<Balances, DealWithFees<Runtime, TreasuryFeeShare>>,
+	>;
 	type OperationalFeeMultiplier = OperationalFeeMultiplier;
 	type WeightToFee = WeightToFee;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
@@ -606,7 +629,7 @@ impl pallet_treasury::Config for Runtime {
 	type SpendFunds = ();
 	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
 	type MaxApprovals = MaxApprovals;
-	type SpendOrigin = EnsureWithSuccess<EnsureRoot<AccountId>, AccountId, MaxBalance>;
+	type SpendOrigin = EnsureWithSuccess<MoreThanHalfCouncil, AccountId, MaxBalance>;
 	type AssetKind = ();
 	type Beneficiary = AccountId;
 	type BeneficiaryLookup = Indices;
@@ -699,6 +722,7 @@ where
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
 			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			CheckCallPriority::new(),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -750,6 +774,134 @@ impl pallet_multisig::Config for Runtime {
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	// One storage item; key size 32, value size 8.
+	pub const ProxyDepositBase: Balance = deposit(1, 8);
+	// Additional storage item size of 33 bytes.
+	pub const ProxyDepositFactor: Balance = deposit(0, 33);
+	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
+	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+}
+
+/// The type used to represent the kinds of proxying allowed.
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+	Default,
+)]
+pub enum ProxyType {
+	/// Allows all calls.
+	#[default]
+	Any,
+	/// Allows all calls except balance and asset transfers.
+	NonTransfer,
+	/// Allows governance calls.
+	Governance,
+	/// Allows DID-authorized chain space management calls.
+	SpaceAdmin,
+	/// Allows DID-authorized schema and statement calls.
+	Anchoring,
+	/// Allows rejecting announcements made by a proxy.
+	CancelProxy,
+}
+
+impl ProxyType {
+	/// Returns whether `c` is a DID-authorized call whose wrapped call, or
+	/// every call of a wrapped batch, satisfies `f`.
+	///
+	/// Calls wrapped by `submit_did_call` are dispatched with a DID origin
+	/// rather than the proxied one, so the proxy filter does not reach them
+	/// and they are checked here instead.
+	fn is_did_call(c: &RuntimeCall, f: fn(&RuntimeCall) -> bool) -> bool {
+		fn wrapped(c: &RuntimeCall, f: fn(&RuntimeCall) -> bool) -> bool {
+			match c {
+				RuntimeCall::Utility(pallet_utility::Call::batch { calls }) |
+				RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) |
+				RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) =>
+					calls.iter().all(|c| wrapped(c, f)),
+				c => f(c),
+			}
+		}
+
+		match c {
+			RuntimeCall::Did(pallet_did::Call::submit_did_call { did_call, .. }) =>
+				wrapped(&did_call.call, f),
+			_ => false,
+		}
+	}
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, c: &RuntimeCall) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::NonTransfer => !matches!(
+				c,
+				RuntimeCall::Balances(..) |
+					RuntimeCall::Assets(..) |
+					RuntimeCall::PoolAssets(..) |
+					RuntimeCall::AssetConversion(..)
+			),
+			ProxyType::Governance => matches!(
+				c,
+				RuntimeCall::Council(..) |
+					RuntimeCall::CouncilMembership(..) |
+					RuntimeCall::TechnicalCommittee(..) |
+					RuntimeCall::TechnicalMembership(..) |
+					RuntimeCall::Treasury(..) |
+					RuntimeCall::Utility(..)
+			),
+			ProxyType::SpaceAdmin =>
+				Self::is_did_call(c, |c| matches!(c, RuntimeCall::ChainSpace(..))) ||
+					matches!(c, RuntimeCall::Utility(..)),
+			ProxyType::Anchoring =>
+				Self::is_did_call(c, |c| {
+					matches!(c, RuntimeCall::Schema(..) | RuntimeCall::Statement(..))
+				}) || matches!(c, RuntimeCall::Utility(..)),
+			ProxyType::CancelProxy => matches!(
+				c,
+				RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. }) |
+					RuntimeCall::Utility(..) |
+					RuntimeCall::Multisig(..)
+			),
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(x, y) if x == y => true,
+			(ProxyType::Any, _) => true,
+			(_, ProxyType::Any) => false,
+			(ProxyType::NonTransfer, _) => true,
+			_ => false,
+		}
+	}
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyDepositBase;
+	type ProxyDepositFactor = ProxyDepositFactor;
+	type MaxProxies = ConstU32<32>;
+	type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
+	type MaxPending = ConstU32<32>;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = AnnouncementDepositBase;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+}
+
 parameter_types! {
 	pub const MaxProposalLength: u16 = 5;
 }
@@ -778,7 +930,7 @@ impl pallet_node_authorization::Config for Runtime {
 parameter_types! {
 	pub const MembershipPeriod: BlockNumber = YEAR;
 	pub const MaxMembersPerBlock: u32 = 1_000;
-	pub const MaxEventsHistory: u32 = u32::MAX;
+	pub const MaxEventsHistory: u32 = 1_000;
 }
 
 impl pallet_network_membership::Config for Runtime {
@@ -794,7 +946,9 @@ impl identifier::Config for Runtime {
 }
 
 impl pallet_runtime_upgrade::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type SetCodeOrigin = EnsureRootOrCommitteeApproval;
+	type LazyMigrations = migrations::Lazy;
 }
 
 parameter_types! {
@@ -848,7 +1002,7 @@ parameter_types! {
 }
 
 impl pallet_did_name::Config for Runtime {
-	type BanOrigin = EnsureRoot<AccountId>;
+	type BanOrigin = MoreThanHalfCouncil;
 	type EnsureOrigin = pallet_did::EnsureDidOrigin<DidIdentifier, AccountId>;
 	type OriginSuccess = pallet_did::DidRawOrigin<AccountId, DidIdentifier>;
 	type RuntimeEvent = RuntimeEvent;
@@ -862,6 +1016,9 @@ impl pallet_did_name::Config for Runtime {
 
 parameter_types! {
 	pub const MaxEncodedSchemaLength: u32 = 15_360;
+	pub const MaxPreimageSchemaLength: u32 = 1024 * 1024;
+	pub const SchemaDepositBase: Balance = deposit(1, 0);
+	pub const SchemaDepositPerByte: Balance = deposit(0, 1);
 }
 
 impl pallet_schema::Config for Runtime {
@@ -870,6 +1027,11 @@ impl pallet_schema::Config for Runtime {
 	type OriginSuccess = pallet_did::DidRawOrigin<AccountId, DidIdentifier>;
 	type RuntimeEvent = RuntimeEvent;
 	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
+	type MaxPreimageSchemaLength = MaxPreimageSchemaLength;
+	type Preimages = Preimage;
+	type Currency = Balances;
+	type SchemaDepositBase = SchemaDepositBase;
+	type SchemaDepositPerByte = SchemaDepositPerByte;
 	type WeightInfo = weights::pallet_schema::WeightInfo<Runtime>;
 }
 
@@ -881,6 +1043,12 @@ impl pallet_schema_accounts::Config for Runtime {
 
 parameter_types! {
 	pub const MaxSpaceDelegates: u32 = 10_000;
+	pub CapacityAlertThresholds: Vec<Percent> =
+		vec![Percent::from_percent(80), Percent::from_percent(95)];
+	pub const MaxSpaceAnchorsPerBlock: u32 = 2_000;
+	pub const MaxDelegateAnchorsPerBlock: u32 = 1_000;
+	pub const MaxExpiryScanPerBlock: u32 = 200;
+	pub const MaxDelegateReassignments: u32 = 1_000;
 }
 
 impl pallet_chain_space::Config for Runtime {
@@ -891,6 +1059,19 @@ impl pallet_chain_space::Config for Runtime {
 	type ChainSpaceOrigin = MoreThanHalfCouncil;
 	type NetworkPermission = NetworkParameters;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type CapacityAlertThresholds = CapacityAlertThresholds;
+	type MaxSpaceAnchorsPerBlock = pallet_config::ConfiguredLimit<
+		Runtime,
+		pallet_config::limits::SpaceAnchorsPerBlock,
+		MaxSpaceAnchorsPerBlock,
+	>;
+	type MaxDelegateAnchorsPerBlock = pallet_config::ConfiguredLimit<
+		Runtime,
+		pallet_config::limits::DelegateAnchorsPerBlock,
+		MaxDelegateAnchorsPerBlock,
+	>;
+	type MaxExpiryScanPerBlock = MaxExpiryScanPerBlock;
+	type MaxDelegateReassignments = MaxDelegateReassignments;
 	type WeightInfo = weights::pallet_chain_space::WeightInfo<Runtime>;
 }
 
@@ -911,6 +1092,21 @@ impl pallet_registries::Config for Runtime {
 parameter_types! {
 	pub const MaxDigestsPerBatch: u16 = 1_000;
 	pub const MaxRemoveEntries: u16 = 1_000;
+	pub const MaxStatementWitnesses: u32 = 25;
+	pub const MaxStatusListLength: u32 = 131_072;
+	pub const MaxRemarkLength: u32 = 1_024;
+	pub const MaxScheduledRevocationsPerBlock: u32 = 50;
+	pub const StatementDepositBase: Balance = deposit(1, 0);
+	pub const StatementDepositPerByte: Balance = deposit(0, 1);
+	pub const StatementMetadataDepositBase: Balance = deposit(1, 0);
+	pub const StatementMetadataDepositPerByte: Balance = deposit(0, 1);
+	pub const MaxStatementMetadataEntries: u32 = 16;
+	pub const MaxStatementMetadataKeyLength: u32 = 64;
+	pub const MaxStatementMetadataValueLength: u32 = 256;
+	pub const AttestationPeriod: BlockNumber = HOURS;
+	pub const MaxAttestationLeaves: u32 = 10_000;
+	pub const StatsPeriod: BlockNumber = EPOCH_DURATION_IN_SLOTS;
+	pub const StatsHistoryDepth: u32 = 540;
 }
 
 impl pallet_statement::Config for Runtime {
@@ -918,8 +1114,30 @@ impl pallet_statement::Config for Runtime {
 	type OriginSuccess = pallet_did::DidRawOrigin<AccountId, DidIdentifier>;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_statement::WeightInfo<Runtime>;
-	type MaxDigestsPerBatch = MaxDigestsPerBatch;
+	type MaxDigestsPerBatch = pallet_config::ConfiguredLimit<
+		Runtime,
+		pallet_config::limits::DigestsPerBatch,
+		MaxDigestsPerBatch,
+	>;
 	type MaxRemoveEntries = MaxRemoveEntries;
+	type MaxStatementWitnesses = MaxStatementWitnesses;
+	type MaxStatusListLength = MaxStatusListLength;
+	type MaxRemarkLength = MaxRemarkLength;
+	type MaxScheduledRevocationsPerBlock = MaxScheduledRevocationsPerBlock;
+	type Currency = Balances;
+	type StatementDepositBase = StatementDepositBase;
+	type StatementDepositPerByte = StatementDepositPerByte;
+	type MetadataDepositBase = StatementMetadataDepositBase;
+	type MetadataDepositPerByte = StatementMetadataDepositPerByte;
+	type MaxMetadataEntries = MaxStatementMetadataEntries;
+	type MaxMetadataKeyLength = MaxStatementMetadataKeyLength;
+	type MaxMetadataValueLength = MaxStatementMetadataValueLength;
+	type AttestationPeriod = AttestationPeriod;
+	type MaxAttestationLeaves = MaxAttestationLeaves;
+	type StatsPeriod = StatsPeriod;
+	type StatsHistoryDepth = StatsHistoryDepth;
+	type OnAnchored = ();
+	type AuthorizeAnchor = pallet_schema::SchemaAuthorization<Runtime>;
 }
 
 impl pallet_remark::Config for Runtime {
@@ -943,7 +1161,84 @@ impl pallet_network_score::Config for Runtime {
 	type WeightInfo = weights::pallet_network_score::WeightInfo<Runtime>;
 }
 
-impl pallet_config::Config for Runtime {}
+parameter_types! {
+	pub const MaxAnchorsPerBlock: u32 = 100;
+}
+
+impl pallet_proof_of_existence::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxAnchorsPerBlock = MaxAnchorsPerBlock;
+	type WeightInfo = weights::pallet_proof_of_existence::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const TransactionSponsorPalletId: PalletId = PalletId(*b"cord/spn");
+}
+
+/// Statement calls submitted through `submit_did_call` are paid by the
+/// sponsorship of the space they are authorized in.
+///
+/// The DID operation is verified up front, so a call that would fail the DID
+/// checks at dispatch is never charged to a sponsorship.
+pub struct SponsoredStatementCalls;
+impl pallet_transaction_sponsor::SponsoredSpace<AccountId, RuntimeCall>
+	for SponsoredStatementCalls
+{
+	fn sponsored_space(
+		who: &AccountId,
+		call: &RuntimeCall,
+	) -> Option<pallet_chain_space::SpaceIdOf> {
+		use pallet_did::DeriveDidCallAuthorizationVerificationKeyRelationship;
+
+		let RuntimeCall::Did(pallet_did::Call::submit_did_call { did_call, signature }) = call
+		else {
+			return None;
+		};
+		let authorization = match &did_call.call {
+			RuntimeCall::Statement(
+				pallet_statement::Call::register { authorization, .. } |
+				pallet_statement::Call::register_batch { authorization, .. } |
+				pallet_statement::Call::update { authorization, .. } |
+				pallet_statement::Call::revoke { authorization, .. } |
+				pallet_statement::Call::restore { authorization, .. } |
+				pallet_statement::Call::add_presentation { authorization, .. } |
+				pallet_statement::Call::remove_presentation { authorization, .. },
+			) => authorization,
+			_ => return None,
+		};
+
+		let delegation = pallet_chain_space::Authorizations::<Runtime>::get(authorization)?;
+		if &did_call.submitter != who || delegation.delegate != did_call.did {
+			return None;
+		}
+
+		let operation = pallet_did::DidAuthorizedCallOperationWithVerificationRelationship {
+			operation: (**did_call).clone(),
+			verification_key_relationship: did_call
+				.call
+				.derive_verification_key_relationship()
+				.ok()?,
+		};
+		pallet_did::Pallet::<Runtime>::verify_did_operation_signature(&operation, signature)
+			.ok()?;
+
+		Some(delegation.space_id)
+	}
+}
+
+impl pallet_transaction_sponsor::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type PalletId = TransactionSponsorPalletId;
+	type SponsoredSpace = SponsoredStatementCalls;
+	type WeightInfo = weights::pallet_transaction_sponsor::WeightInfo<Runtime>;
+}
+
+impl pallet_config::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type UpdateOrigin = MoreThanHalfCouncil;
+}
+
 impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
 impl pallet_root_testing::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -988,7 +1283,7 @@ impl pallet_contracts::Config for Runtime {
 	type CallStack = [pallet_contracts::Frame<Self>; 23];
 	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
 	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
-	type ChainExtension = ();
+	type ChainExtension = chain_extension::CordExtension;
 	type Schedule = Schedule;
 	type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
 	type MaxCodeLen = ConstU32<{ 128 * 1024 }>;
@@ -1115,6 +1410,9 @@ mod runtime {
 	#[runtime::pallet_index(38)]
 	pub type Contracts = pallet_contracts;
 
+	#[runtime::pallet_index(39)]
+	pub type Proxy = pallet_proxy;
+
 	#[runtime::pallet_index(50)]
 	pub type Identifier = identifier;
 
@@ -1124,12 +1422,14 @@ mod runtime {
 	#[runtime::pallet_index(52)]
 	pub type Did = pallet_did;
 
-	#[runtime::pallet_index(53)]
-	pub type Schema = pallet_schema;
-
+	// Declared ahead of `Schema` so that genesis spaces exist when genesis
+	// schemas are anchored.
 	#[runtime::pallet_index(54)]
 	pub type ChainSpace = pallet_chain_space;
 
+	#[runtime::pallet_index(53)]
+	pub type Schema = pallet_schema;
+
 	#[runtime::pallet_index(55)]
 	pub type Statement = pallet_statement;
 
@@ -1157,6 +1457,12 @@ mod runtime {
 	#[runtime::pallet_index(63)]
 	pub type SchemaAccounts = pallet_schema_accounts;
 
+	#[runtime::pallet_index(64)]
+	pub type ProofOfExistence = pallet_proof_of_existence;
+
+	#[runtime::pallet_index(65)]
+	pub type TransactionSponsor = pallet_transaction_sponsor;
+
 	#[runtime::pallet_index(254)]
 	pub type RootTesting = pallet_root_testing;
 
@@ -1217,9 +1523,18 @@ impl pallet_did::DeriveDidCallAuthorizationVerificationKeyRelationship for Runti
 			RuntimeCall::ChainSpace(pallet_chain_space::Call::add_delegator { .. }) => {
 				Ok(pallet_did::DidVerificationKeyRelationship::CapabilityDelegation)
 			},
+			RuntimeCall::ChainSpace(pallet_chain_space::Call::add_revoke_delegate { .. }) => {
+				Ok(pallet_did::DidVerificationKeyRelationship::CapabilityDelegation)
+			},
 			RuntimeCall::ChainSpace(pallet_chain_space::Call::remove_delegate { .. }) => {
 				Ok(pallet_did::DidVerificationKeyRelationship::CapabilityDelegation)
 			},
+			RuntimeCall::ChainSpace(pallet_chain_space::Call::transfer_ownership { .. }) => {
+				Ok(pallet_did::DidVerificationKeyRelationship::CapabilityDelegation)
+			},
+			RuntimeCall::ChainSpace(pallet_chain_space::Call::accept_ownership { .. }) => {
+				Ok(pallet_did::DidVerificationKeyRelationship::CapabilityDelegation)
+			},
 			RuntimeCall::ChainSpace(pallet_chain_space::Call::create { .. }) => {
 				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
 			},
@@ -1272,6 +1587,41 @@ pub type Block = generic::Block<Header, UncheckedExtrinsic>;
 pub type SignedBlock = generic::SignedBlock<Block>;
 /// `BlockId` type as expected by this runtime.
 pub type BlockId = generic::BlockId<Block>;
+/// Pool classes of the calls of this runtime, used by `CheckCallPriority`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CallClassifier;
+
+impl runtime_common::ClassifyCall<RuntimeCall> for CallClassifier {
+	fn classify(call: &RuntimeCall) -> runtime_common::CallClass {
+		use runtime_common::CallClass;
+		match call {
+			RuntimeCall::Council(..) |
+			RuntimeCall::CouncilMembership(..) |
+			RuntimeCall::TechnicalCommittee(..) |
+			RuntimeCall::TechnicalMembership(..) |
+			RuntimeCall::Sudo(..) |
+			RuntimeCall::RuntimeUpgrade(..) |
+			RuntimeCall::NodeAuthorization(..) |
+			RuntimeCall::NetworkParameters(..) => CallClass::Governance,
+			RuntimeCall::NetworkMembership(..) | RuntimeCall::AuthorityMembership(..) =>
+				CallClass::Membership,
+			RuntimeCall::ChainSpace(..) |
+			RuntimeCall::Schema(..) |
+			RuntimeCall::Statement(..) |
+			RuntimeCall::Registries(..) |
+			RuntimeCall::Entries(..) |
+			RuntimeCall::SchemaAccounts(..) |
+			RuntimeCall::ProofOfExistence(..) |
+			RuntimeCall::NetworkScore(..) |
+			RuntimeCall::Remark(..) => CallClass::Anchoring,
+			_ => CallClass::Other,
+		}
+	}
+}
+
+/// Prioritises governance and membership calls over anchoring traffic.
+pub type CheckCallPriority = runtime_common::CheckCallPriority<Runtime, CallClassifier>;
+
 /// The `SignedExtension` to the basic transaction logic.
 pub type SignedExtra = (
 	pallet_network_membership::CheckNetworkMembership<Runtime>,
@@ -1283,6 +1633,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	CheckCallPriority,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -1298,7 +1649,29 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
+
+/// All migrations that will run on the next runtime upgrade.
+///
+/// Pallet migrations should be versioned (`VersionedMigration`) so that each runs once and
+/// is checked by `try-runtime` before the upgrade is submitted.
+pub type Migrations = migrations::Unreleased;
+
+/// The runtime migrations per release.
+pub mod migrations {
+	/// Unreleased migrations. Add new ones here:
+	pub type Unreleased = (
+		pallet_statement::migrations::v2::MigrateV1ToV2<crate::Runtime>,
+		pallet_statement::migrations::v3::MigrateV2ToV3<crate::Runtime>,
+		pallet_statement::migrations::v4::MigrateV3ToV4<crate::Runtime>,
+		pallet_schema::migrations::v2::MigrateV1ToV2<crate::Runtime>,
+	);
+
+	/// Multi-block migrations, stepped through from `on_idle` by
+	/// `pallet_runtime_upgrade`. Add new ones here:
+	pub type Lazy = (identifier::migrations::v2::LazyMigrateV1ToV2<crate::Runtime>,);
+}
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 
@@ -1322,6 +1695,7 @@ mod benches {
 		[pallet_indices, Indices]
 		[pallet_membership, TechnicalMembership]
 		[pallet_multisig, Multisig]
+		[pallet_proxy, Proxy]
 		[pallet_preimage, Preimage]
 		[pallet_remark, Remark]
 		[pallet_scheduler, Scheduler]
@@ -1336,6 +1710,8 @@ mod benches {
 		[pallet_did_name, DidName]
 		[pallet_network_membership, NetworkMembership]
 		[pallet_network_score, NetworkScore]
+		[pallet_proof_of_existence, ProofOfExistence]
+		[pallet_transaction_sponsor, TransactionSponsor]
 		[pallet_sudo, Sudo]
 	);
 }
@@ -1639,6 +2015,131 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl cord_identifier_runtime_api::IdentifierApi<
+		Block,
+		identifier::IdentifierOf,
+		identifier::IdentifierTypeOf,
+		identifier::EventEntryOf
+	> for Runtime {
+		fn timeline(
+			identifier: identifier::IdentifierOf,
+			id_type: identifier::IdentifierTypeOf
+		) -> Option<Vec<identifier::EventEntryOf>> {
+			Identifier::timeline(&identifier, id_type)
+		}
+	}
+
+	impl pallet_chain_space_runtime_api::ChainSpaceApi<
+		Block,
+		pallet_chain_space::SpaceIdOf,
+		pallet_chain_space::SpaceDelegateInfoOf<Runtime>,
+		pallet_chain_space::SpaceUsageOf
+	> for Runtime {
+		fn delegates(space: pallet_chain_space::SpaceIdOf) -> Vec<pallet_chain_space::SpaceDelegateInfoOf<Runtime>> {
+			ChainSpace::space_delegates(&space)
+		}
+		fn usage(space: pallet_chain_space::SpaceIdOf) -> Option<pallet_chain_space::SpaceUsageOf> {
+			ChainSpace::space_usage(&space)
+		}
+	}
+
+	impl pallet_statement_runtime_api::StatementApi<
+		Block,
+		pallet_statement::StatementIdOf,
+		Hash,
+		pallet_statement::SpaceIdOf,
+		pallet_statement::StatementInfoOf<Runtime>,
+		pallet_statement::AnchorStateOf<Runtime>,
+		pallet_statement::ReceiptOf<Runtime>
+	> for Runtime {
+		fn query(identifier: pallet_statement::StatementIdOf) -> Option<pallet_statement::StatementInfoOf<Runtime>> {
+			Statement::statement_info(&identifier)
+		}
+		fn query_by_digest(
+			digest: Hash,
+			space: pallet_statement::SpaceIdOf
+		) -> Option<pallet_statement::StatementInfoOf<Runtime>> {
+			Statement::statement_info_by_digest(&digest, &space)
+		}
+		fn status_at(identifier: pallet_statement::StatementIdOf, index: u32) -> Option<bool> {
+			Statement::status_at(&identifier, index)
+		}
+		fn verify_leaf(
+			identifier: pallet_statement::StatementIdOf,
+			leaf: Hash,
+			proof: Vec<Hash>
+		) -> bool {
+			Statement::verify_leaf(&identifier, leaf, &proof)
+		}
+		fn anchor_state(
+			digest: Hash,
+			space: pallet_statement::SpaceIdOf
+		) -> Option<pallet_statement::AnchorStateOf<Runtime>> {
+			pallet_statement::AnchorState::<Runtime>::get(digest, space)
+		}
+		fn verify_batch(
+			digests: Vec<Hash>
+		) -> Vec<Vec<(pallet_statement::SpaceIdOf, pallet_statement::AnchorStateOf<Runtime>)>> {
+			digests.iter().map(Statement::anchors_of).collect()
+		}
+		fn receipt(
+			identifier: pallet_statement::StatementIdOf
+		) -> Option<pallet_statement::ReceiptOf<Runtime>> {
+			Statement::receipt(&identifier)
+		}
+		fn anchoring_stats(
+			schema: Option<pallet_statement::SchemaIdOf>
+		) -> pallet_statement::AnchoringStats {
+			Statement::anchoring_stats(schema.as_ref())
+		}
+		fn period_stats(block: BlockNumber) -> Option<pallet_statement::AnchoringStats> {
+			Statement::period_stats(block)
+		}
+	}
+
+	impl pallet_proof_of_existence_runtime_api::ProofOfExistenceApi<
+		Block,
+		pallet_proof_of_existence::DigestOf,
+		pallet_proof_of_existence::ProofOf<Runtime>
+	> for Runtime {
+		fn proof(digest: pallet_proof_of_existence::DigestOf) -> Option<pallet_proof_of_existence::ProofOf<Runtime>> {
+			ProofOfExistence::proof(&digest)
+		}
+	}
+
+	impl pallet_schema_runtime_api::SchemaApi<
+		Block,
+		pallet_schema::SchemaIdOf,
+		Hash,
+		pallet_schema::SchemaInfoOf<Runtime>
+	> for Runtime {
+		fn schema(identifier: pallet_schema::SchemaIdOf) -> Option<pallet_schema::SchemaInfoOf<Runtime>> {
+			Schema::schema_info(&identifier)
+		}
+		fn schema_by_hash(hash: Hash) -> Vec<pallet_schema::SchemaInfoOf<Runtime>> {
+			Schema::schema_info_by_hash(&hash)
+		}
+	}
+
+	impl pallet_transaction_sponsor_runtime_api::TransactionSponsorApi<
+		Block,
+		AccountId,
+		Balance,
+		pallet_transaction_sponsor::SpaceIdOf
+	> for Runtime {
+		fn estimate_fee(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+		) -> Option<pallet_transaction_sponsor::FeeEstimateOf<Runtime>> {
+			use sp_runtime::traits::StaticLookup;
+
+			let (address, _, _) = uxt.signature.as_ref()?;
+			let who = <Runtime as frame_system::Config>::Lookup::lookup(address.clone()).ok()?;
+			let partial_fee = TransactionPayment::query_info(uxt.clone(), len).partial_fee;
+			Some(TransactionSponsor::fee_estimate(&who, &uxt.function, partial_fee))
+		}
+	}
+
 	impl pallet_transaction_weight_runtime_api::TransactionWeightApi<Block> for Runtime {
 		fn query_weight_info(uxt: <Block as BlockT>::Extrinsic) -> RuntimeDispatchWeightInfo {
 			NetworkMembership::query_weight_info(uxt)
@@ -57,6 +57,8 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The read and write of `ChainSpace::AuthorizationExpiry` are added by hand
+	/// and have not been benchmarked yet.
 	fn add_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
@@ -64,8 +66,8 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 39_040_000 picoseconds.
 		Weight::from_parts(40_450_000, 0)
 			.saturating_add(Weight::from_parts(0, 323533))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -259,4 +261,80 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn add_revoke_delegate() -> Weight {
+		Weight::from_parts(40_140_000, 0)
+			.saturating_add(Weight::from_parts(0, 323533))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn transfer_ownership() -> Weight {
+		Weight::from_parts(15_730_000, 0)
+			.saturating_add(Weight::from_parts(0, 3671))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(43_210_000, 0)
+			.saturating_add(Weight::from_parts(0, 323533))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_transfer_ownership() -> Weight {
+		Weight::from_parts(40_620_000, 0)
+			.saturating_add(Weight::from_parts(0, 323533))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn freeze() -> Weight {
+		Weight::from_parts(28_040_000, 0)
+			.saturating_add(Weight::from_parts(0, 3671))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn unfreeze() -> Weight {
+		Weight::from_parts(28_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 3671))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_freeze() -> Weight {
+		Weight::from_parts(22_180_000, 0)
+			.saturating_add(Weight::from_parts(0, 3671))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_unfreeze() -> Weight {
+		Weight::from_parts(22_410_000, 0)
+			.saturating_add(Weight::from_parts(0, 3671))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn reassign_delegate(n: u32, ) -> Weight {
+		Weight::from_parts(10_540_000, 0)
+			.saturating_add(Weight::from_parts(0, 3649))
+			.saturating_add(Weight::from_parts(38_260_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 322543).saturating_mul(n.into()))
+	}
 }
@@ -33,6 +33,7 @@ pub mod pallet_multisig;
 pub mod pallet_network_membership;
 pub mod pallet_network_score;
 pub mod pallet_preimage;
+pub mod pallet_proof_of_existence;
 pub mod pallet_remark;
 pub mod pallet_scheduler;
 pub mod pallet_schema;
@@ -40,4 +41,5 @@ pub mod pallet_session;
 pub mod pallet_statement;
 pub mod pallet_sudo;
 pub mod pallet_timestamp;
+pub mod pallet_transaction_sponsor;
 pub mod pallet_utility;
@@ -70,4 +70,69 @@ impl<T: frame_system::Config> pallet_schema::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn version_of(l: u32, ) -> Weight {
+		Weight::from_parts(50_217_845, 0)
+			.saturating_add(Weight::from_parts(0, 37024))
+			.saturating_add(Weight::from_parts(3_241, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn deprecate() -> Weight {
+		Weight::from_parts(34_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 19007))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn create_from_preimage() -> Weight {
+		Weight::from_parts(43_180_000, 0)
+			.saturating_add(Weight::from_parts(0, 19007))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn set_digest_policy() -> Weight {
+		Weight::from_parts(33_010_000, 0)
+			.saturating_add(Weight::from_parts(0, 19007))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn freeze() -> Weight {
+		Weight::from_parts(32_270_000, 0)
+			.saturating_add(Weight::from_parts(0, 19007))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn unfreeze() -> Weight {
+		Weight::from_parts(32_530_000, 0)
+			.saturating_add(Weight::from_parts(0, 19007))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_freeze() -> Weight {
+		Weight::from_parts(25_390_000, 0)
+			.saturating_add(Weight::from_parts(0, 19007))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Not benchmarked yet: storage reads and writes are counted from the
+	/// call, execution time and proof size are estimates.
+	fn force_unfreeze() -> Weight {
+		Weight::from_parts(25_620_000, 0)
+			.saturating_add(Weight::from_parts(0, 19007))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
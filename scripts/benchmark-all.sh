@@ -0,0 +1,134 @@
+#!/usr/bin/env bash
+# This file is part of CORD – https://cord.network
+#
+# Copyright (C) Dhiway Networks Pvt. Ltd.
+# SPDX-License-Identifier: GPL-3.0-or-later
+#
+# CORD is free software: you can redistribute it and/or modify
+# it under the terms of the GNU General Public License as published by
+# the Free Software Foundation, either version 3 of the License, or
+# (at your option) any later version.
+#
+# CORD is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU General Public License for more details.
+#
+# You should have received a copy of the GNU General Public License
+# along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+# Regenerates every weight file in the tree: the runtime weights under
+# `runtimes/<runtime>/src/weights/` for each runtime, followed by the
+# default `weights.rs` shipped with each CORD pallet.
+# Should be run on a reference machine to gain accurate benchmarks.
+
+while getopts 'br:v' flag; do
+  case "${flag}" in
+  b)
+    # Skip build.
+    skip_build='true'
+    ;;
+  r)
+    # Only benchmark the given runtime.
+    only_runtime="${OPTARG}"
+    ;;
+  v)
+    # Echo all executed commands.
+    set -x
+    ;;
+  *)
+    # Exit early.
+    echo "Bad options. Check Script."
+    exit 1
+    ;;
+  esac
+done
+
+if [ "$skip_build" != true ]; then
+  echo "[+] Compiling CORD benchmarks..."
+  cargo build --profile=production --locked --features=runtime-benchmarks --bin cord
+fi
+
+# The executable to use.
+CORD=./target/production/cord
+
+RUNTIMES=("braid" "loom" "weave")
+
+# Define the error file.
+ERR_FILE="benchmarking_errors.txt"
+# Delete the error file before each run.
+rm -f $ERR_FILE
+
+for RUNTIME in "${RUNTIMES[@]}"; do
+  if [ ! -z "$only_runtime" ] && [ "$only_runtime" != "$RUNTIME" ]; then
+    echo "[+] Skipping ${RUNTIME} runtime..."
+    continue
+  fi
+
+  CHAIN="dev-node-${RUNTIME}"
+  WEIGHT_DIR="./runtimes/${RUNTIME}/src/weights"
+
+  # Load all pallet names registered with `define_benchmarks!` for this runtime.
+  PALLETS=($(
+    $CORD benchmark pallet --list --chain="$CHAIN" |
+      tail -n+2 |
+      cut -d',' -f1 |
+      sort |
+      uniq
+  ))
+
+  echo "[+] Benchmarking ${#PALLETS[@]} pallets for the ${RUNTIME} runtime"
+
+  for PALLET in "${PALLETS[@]}"; do
+    # translates e.g. "pallet_foo::bar" to "pallet_foo_bar"
+    WEIGHT_FILE="${WEIGHT_DIR}/${PALLET//::/_}.rs"
+    echo "[+] Benchmarking $PALLET with weight file $WEIGHT_FILE"
+
+    OUTPUT=$(
+      $CORD benchmark pallet \
+        --chain="$CHAIN" \
+        --steps=50 \
+        --repeat=20 \
+        --pallet="$PALLET" \
+        --extrinsic="*" \
+        --wasm-execution=compiled \
+        --heap-pages=4096 \
+        --header=./HEADER-GPL3 \
+        --output="$WEIGHT_FILE" 2>&1
+    )
+    if [ $? -ne 0 ]; then
+      echo "$OUTPUT" >>"$ERR_FILE"
+      echo "[-] Failed to benchmark $PALLET for ${RUNTIME}. Error written to $ERR_FILE; continuing..."
+    fi
+  done
+done
+
+# The pallet defaults are generated from the dev chain with the weight template.
+echo "[+] Benchmarking CORD pallet defaults..."
+PALLET_ERRORS=$(cat "$ERR_FILE" 2>/dev/null)
+./scripts/run_benches_for_pallets.sh -b
+# `run_benches_for_pallets.sh` truncates the error file; restore runtime failures.
+if [ ! -z "$PALLET_ERRORS" ]; then
+  echo "$PALLET_ERRORS" >>"$ERR_FILE"
+fi
+
+# Hand-written weights are marked as placeholders until they are regenerated.
+# Any marker left behind means a benchmark did not overwrite that file.
+PLACEHOLDERS=$(
+  grep -rliE "not (been )?benchmarked yet|Placeholder weights for" \
+    --include='*.rs' ./runtimes/*/src/weights ./pallets/*/src/weights.rs
+)
+if [ ! -z "$PLACEHOLDERS" ]; then
+  echo "[-] Placeholder weights are left in:"
+  echo "$PLACEHOLDERS"
+  echo "Placeholder weights left in: $PLACEHOLDERS" >>"$ERR_FILE"
+fi
+
+# Check if the error file exists.
+if [ -f "$ERR_FILE" ]; then
+  echo "[-] Some benchmarks failed. See: $ERR_FILE"
+  exit 1
+else
+  echo "[+] All benchmarks passed."
+  exit 0
+fi
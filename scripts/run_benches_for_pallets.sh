@@ -70,6 +70,8 @@ PALLETS=(
   "pallet_schema"
   "pallet_statement"
   "pallet_asset"
+  "pallet_proof_of_existence"
+  "pallet_transaction_sponsor"
 )
 
 echo "[+] Benchmarking ${#PALLETS[@]} pallets."
@@ -123,6 +125,12 @@ for PALLET in "${PALLETS[@]}"; do
   pallet_asset)
     FOLDER="asset"
     ;;
+  pallet_proof_of_existence)
+    FOLDER="proof-of-existence"
+    ;;
+  pallet_transaction_sponsor)
+    FOLDER="transaction-sponsor"
+    ;;
 
   *)
     # Exit early.
@@ -23,6 +23,7 @@
 pub mod deposit;
 pub use deposit::{free_deposit, reserve_deposit};
 
+pub mod merkle;
 #[cfg(any(feature = "runtime-benchmarks", feature = "mock"))]
 pub mod mock;
 pub mod signature;
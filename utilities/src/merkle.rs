@@ -0,0 +1,83 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Helpers to verify Merkle inclusion proofs against anchored roots.
+//!
+//! Trees are built from sorted pairs: each parent node is the hash of the
+//! concatenation of its two children, with the smaller child first. Proofs
+//! therefore only carry the sibling hashes from the leaf up to the root, and
+//! no position information is needed.
+
+use codec::Encode;
+use sp_runtime::traits::Hash;
+use sp_std::vec::Vec;
+
+/// Hashes two sibling nodes into their parent node.
+pub fn hash_pair<H: Hash>(a: &H::Output, b: &H::Output) -> H::Output {
+	let (first, second) = if a <= b { (a, b) } else { (b, a) };
+	H::hash(&[&first.encode()[..], &second.encode()[..]].concat()[..])
+}
+
+/// Checks that `leaf` is part of the tree with the given `root`, following
+/// the sibling hashes in `proof` from the bottom of the tree upwards.
+pub fn verify_proof<H: Hash>(root: &H::Output, leaf: H::Output, proof: &[H::Output]) -> bool {
+	let computed = proof.iter().fold(leaf, |node, sibling| hash_pair::<H>(&node, sibling));
+	computed == *root
+}
+
+/// Hashes one level of the tree into the level above it. An odd node at
+/// the end of a level is carried up unchanged.
+fn next_layer<H: Hash>(layer: &[H::Output]) -> Vec<H::Output> {
+	layer
+		.chunks(2)
+		.map(|pair| match pair {
+			[a, b] => hash_pair::<H>(a, b),
+			_ => pair[0].clone(),
+		})
+		.collect()
+}
+
+/// Computes the root of the tree built over `leaves`, in the given order.
+/// The root of an empty tree is the default hash.
+pub fn merkle_root<H: Hash>(leaves: &[H::Output]) -> H::Output {
+	let mut layer = leaves.to_vec();
+	while layer.len() > 1 {
+		layer = next_layer::<H>(&layer);
+	}
+	layer.pop().unwrap_or_default()
+}
+
+/// Builds the inclusion proof of the leaf at `index`, to be checked with
+/// [`verify_proof`]. Returns `None` if `index` is out of range.
+pub fn merkle_proof<H: Hash>(leaves: &[H::Output], index: usize) -> Option<Vec<H::Output>> {
+	if index >= leaves.len() {
+		return None
+	}
+
+	let mut proof = Vec::new();
+	let mut layer = leaves.to_vec();
+	let mut index = index;
+	while layer.len() > 1 {
+		if let Some(sibling) = layer.get(index ^ 1) {
+			proof.push(sibling.clone());
+		}
+		layer = next_layer::<H>(&layer);
+		index /= 2;
+	}
+	Some(proof)
+}